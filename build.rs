@@ -0,0 +1,89 @@
+// Copyright 2016 The CGMath Developers. For a full listing of the authors,
+// refer to the Cargo.toml file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Generates the `impl_swizzle_functions!` macro used by the `swizzle`
+//! feature, as `macro_rules!` cannot build the method names itself.
+
+use std::env;
+use std::fs::File;
+use std::io::Write;
+use std::path::Path;
+
+const FIELDS: [&'static str; 4] = ["x", "y", "z", "w"];
+
+/// All the sequences of `len` fields drawn from `fields`, in lexicographic
+/// order of the field positions.
+fn sequences(fields: &[&'static str], len: usize) -> Vec<Vec<&'static str>> {
+    if len == 0 {
+        return vec![vec![]];
+    }
+    let mut result = Vec::new();
+    for &field in fields {
+        for mut rest in sequences(fields, len - 1) {
+            rest.insert(0, field);
+            result.push(rest);
+        }
+    }
+    result
+}
+
+/// A macro arm implementing every swizzle of `fields` onto the `targets`,
+/// which are the types with 2, 3, ... components.
+fn gen_arm(fields: &[&'static str], targets: usize) -> String {
+    let types: Vec<String> = (0..targets).map(|i| format!("$T{}:ident", i + 2)).collect();
+    let mut arm = format!("    ($Type:ident; {}; {}) => {{\n", types.join(", "), fields.join(" "));
+    arm.push_str("        impl<S: BaseNum> $Type<S> {\n");
+    for len in 2..targets + 2 {
+        for seq in sequences(fields, len) {
+            let name = seq.concat();
+            let args: Vec<String> = seq.iter().map(|f| format!("self.{}", f)).collect();
+            arm.push_str(&format!("            /// Swizzle operator, returning `({})`.\n", seq.join(", ")));
+            arm.push_str("            #[inline]\n");
+            arm.push_str(&format!("            pub fn {}(&self) -> $T{}<S> {{ $T{}::new({}) }}\n",
+                                  name, len, len, args.join(", ")));
+        }
+    }
+    arm.push_str("        }\n");
+    arm.push_str("    };\n");
+    arm
+}
+
+fn main() {
+    // The output only depends on this file, so don't rerun on every change to
+    // the crate's sources.
+    println!("cargo:rerun-if-changed=build.rs");
+
+    // The macro is only included by the `swizzle` feature.
+    if env::var_os("CARGO_FEATURE_SWIZZLE").is_none() {
+        return;
+    }
+
+    let out_dir = env::var("OUT_DIR").unwrap();
+    let path = Path::new(&out_dir).join("swizzle_operator_macro.rs");
+    let mut file = File::create(&path).unwrap();
+
+    let mut code = String::new();
+    code.push_str("/// Generate the swizzle methods for a vector or point type, given the\n");
+    code.push_str("/// types to return for each number of components, and its field names.\n");
+    code.push_str("macro_rules! impl_swizzle_functions {\n");
+    for n in 1..FIELDS.len() + 1 {
+        // Points only go up to three dimensions.
+        code.push_str(&gen_arm(&FIELDS[..n], 2));
+        code.push_str(&gen_arm(&FIELDS[..n], 3));
+    }
+    code.push_str("}\n");
+
+    file.write_all(code.as_bytes()).unwrap();
+}
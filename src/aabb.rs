@@ -0,0 +1,214 @@
+// Copyright 2016 The CGMath Developers. For a full listing of the authors,
+// refer to the Cargo.toml file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Axis-aligned bounding boxes.
+//!
+//! A bounding box is stored as its minimum and maximum corners. The boxes are
+//! closed, so points on the boundary are contained in the box, and boxes that
+//! only touch still intersect.
+
+use num_traits::cast;
+
+use structure::*;
+
+use approx::ApproxEq;
+use num::{BaseFloat, BaseNum};
+use point::{Point2, Point3};
+use transform::Transform;
+use vector::{Vector2, Vector3};
+
+/// A two-dimensional axis-aligned bounding box.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "rustc-serialize", derive(RustcEncodable, RustcDecodable))]
+#[cfg_attr(feature = "eders", derive(Serialize, Deserialize))]
+pub struct Aabb2<S> {
+    /// The corner with the smallest coordinates.
+    pub min: Point2<S>,
+    /// The corner with the largest coordinates.
+    pub max: Point2<S>,
+}
+
+/// A three-dimensional axis-aligned bounding box.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "rustc-serialize", derive(RustcEncodable, RustcDecodable))]
+#[cfg_attr(feature = "eders", derive(Serialize, Deserialize))]
+pub struct Aabb3<S> {
+    /// The corner with the smallest coordinates.
+    pub min: Point3<S>,
+    /// The corner with the largest coordinates.
+    pub max: Point3<S>,
+}
+
+macro_rules! impl_aabb {
+    ($AabbN:ident, $PointN:ident { $($field:ident),+ }, $VectorN:ident) => {
+        impl<S: BaseNum> $AabbN<S> {
+            /// Construct the smallest box containing both `p1` and `p2`.
+            #[inline]
+            pub fn new(p1: $PointN<S>, p2: $PointN<S>) -> $AabbN<S> {
+                $AabbN {
+                    min: $PointN::new($(p1.$field.partial_min(p2.$field)),+),
+                    max: $PointN::new($(p1.$field.partial_max(p2.$field)),+),
+                }
+            }
+
+            /// Construct the smallest box containing all of the `points`, or
+            /// `None` if there are no points.
+            pub fn from_points(points: &[$PointN<S>]) -> Option<$AabbN<S>> {
+                points.split_first().map(|(&first, rest)| {
+                    rest.iter().fold($AabbN::new(first, first), |aabb, &p| aabb.grow(p))
+                })
+            }
+
+            /// The center of the box.
+            #[inline]
+            pub fn center(&self) -> $PointN<S> {
+                self.min.midpoint(self.max)
+            }
+
+            /// The size of the box along each axis.
+            #[inline]
+            pub fn size(&self) -> $VectorN<S> {
+                self.max - self.min
+            }
+
+            /// Half of the size of the box along each axis, which is the
+            /// displacement from the center to the maximum corner.
+            #[inline]
+            pub fn extents(&self) -> $VectorN<S> {
+                self.size() / cast(2).unwrap()
+            }
+
+            /// Whether `point` lies inside or on the boundary of the box.
+            #[inline]
+            pub fn contains(&self, point: $PointN<S>) -> bool {
+                $(self.min.$field <= point.$field && point.$field <= self.max.$field)&&+
+            }
+
+            /// Whether `other` lies entirely within the box.
+            #[inline]
+            pub fn contains_aabb(&self, other: &$AabbN<S>) -> bool {
+                self.contains(other.min) && self.contains(other.max)
+            }
+
+            /// Whether the box and `other` overlap or touch.
+            #[inline]
+            pub fn intersects(&self, other: &$AabbN<S>) -> bool {
+                $(self.min.$field <= other.max.$field && other.min.$field <= self.max.$field)&&+
+            }
+
+            /// The smallest box containing both this box and `other`.
+            #[inline]
+            pub fn union(&self, other: &$AabbN<S>) -> $AabbN<S> {
+                $AabbN {
+                    min: $PointN::new($(self.min.$field.partial_min(other.min.$field)),+),
+                    max: $PointN::new($(self.max.$field.partial_max(other.max.$field)),+),
+                }
+            }
+
+            /// The region shared by this box and `other`, or `None` if they
+            /// do not intersect.
+            #[inline]
+            pub fn intersection(&self, other: &$AabbN<S>) -> Option<$AabbN<S>> {
+                if self.intersects(other) {
+                    Some($AabbN {
+                        min: $PointN::new($(self.min.$field.partial_max(other.min.$field)),+),
+                        max: $PointN::new($(self.max.$field.partial_min(other.max.$field)),+),
+                    })
+                } else {
+                    None
+                }
+            }
+
+            /// The smallest box containing both this box and `point`.
+            #[inline]
+            pub fn grow(&self, point: $PointN<S>) -> $AabbN<S> {
+                $AabbN {
+                    min: $PointN::new($(self.min.$field.partial_min(point.$field)),+),
+                    max: $PointN::new($(self.max.$field.partial_max(point.$field)),+),
+                }
+            }
+
+            /// The smallest box containing every corner of this box after
+            /// applying `transform`.
+            pub fn transform<T: Transform<$PointN<S>>>(&self, transform: &T) -> $AabbN<S> {
+                let corners = self.to_corners();
+                let first = transform.transform_point(corners[0]);
+                corners[1..].iter().fold($AabbN::new(first, first), |aabb, &p| {
+                    aabb.grow(transform.transform_point(p))
+                })
+            }
+        }
+
+        impl<S: BaseFloat> ApproxEq for $AabbN<S> {
+            type Epsilon = S::Epsilon;
+
+            #[inline]
+            fn default_epsilon() -> S::Epsilon {
+                S::default_epsilon()
+            }
+
+            #[inline]
+            fn default_max_relative() -> S::Epsilon {
+                S::default_max_relative()
+            }
+
+            #[inline]
+            fn default_max_ulps() -> u32 {
+                S::default_max_ulps()
+            }
+
+            #[inline]
+            fn relative_eq(&self, other: &Self, epsilon: S::Epsilon, max_relative: S::Epsilon) -> bool {
+                $PointN::relative_eq(&self.min, &other.min, epsilon, max_relative) &&
+                $PointN::relative_eq(&self.max, &other.max, epsilon, max_relative)
+            }
+
+            #[inline]
+            fn ulps_eq(&self, other: &Self, epsilon: S::Epsilon, max_ulps: u32) -> bool {
+                $PointN::ulps_eq(&self.min, &other.min, epsilon, max_ulps) &&
+                $PointN::ulps_eq(&self.max, &other.max, epsilon, max_ulps)
+            }
+        }
+    }
+}
+
+impl_aabb!(Aabb2, Point2 { x, y }, Vector2);
+impl_aabb!(Aabb3, Point3 { x, y, z }, Vector3);
+
+impl<S: BaseNum> Aabb2<S> {
+    /// The four corners of the box.
+    pub fn to_corners(&self) -> [Point2<S>; 4] {
+        let (min, max) = (self.min, self.max);
+        [min,
+         Point2::new(max.x, min.y),
+         Point2::new(min.x, max.y),
+         max]
+    }
+}
+
+impl<S: BaseNum> Aabb3<S> {
+    /// The eight corners of the box.
+    pub fn to_corners(&self) -> [Point3<S>; 8] {
+        let (min, max) = (self.min, self.max);
+        [min,
+         Point3::new(max.x, min.y, min.z),
+         Point3::new(min.x, max.y, min.z),
+         Point3::new(max.x, max.y, min.z),
+         Point3::new(min.x, min.y, max.z),
+         Point3::new(max.x, min.y, max.z),
+         Point3::new(min.x, max.y, max.z),
+         max]
+    }
+}
@@ -0,0 +1,116 @@
+// Copyright 2016 The CGMath Developers. For a full listing of the authors,
+// refer to the Cargo.toml file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Arc-length parameterization of curves, for moving along them at a
+//! constant speed.
+
+use std::cmp;
+
+use num_traits::cast;
+
+use structure::*;
+
+use curve::{ControlPoint, Curve};
+use num::BaseFloat;
+
+/// The nodes and weights of five-point Gauss-Legendre quadrature on `[-1, 1]`.
+const GAUSS_LEGENDRE: [(f64, f64); 5] = [
+    (0.0, 0.5688888888888889),
+    (-0.5384693101056831, 0.4786286704993665),
+    (0.5384693101056831, 0.4786286704993665),
+    (-0.9061798459386640, 0.2369268850561891),
+    (0.9061798459386640, 0.2369268850561891),
+];
+
+/// The arc length of a curve, tabulated at equal steps of its parameter `t`,
+/// for converting between distances along the curve and values of `t`.
+///
+/// The length of each step is integrated with Gauss-Legendre quadrature, and
+/// distances between the steps are interpolated linearly.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "rustc-serialize", derive(RustcEncodable, RustcDecodable))]
+#[cfg_attr(feature = "eders", derive(Serialize, Deserialize))]
+pub struct ArcLengthTable<S> {
+    /// The distance along the curve at each step, starting at zero.
+    lengths: Vec<S>,
+}
+
+impl<S: BaseFloat> ArcLengthTable<S> {
+    /// Tabulate the arc length of `curve` over `intervals` equal steps of
+    /// `t`. At least one step is always taken.
+    pub fn new<C: Curve + ?Sized>(curve: &C, intervals: usize) -> ArcLengthTable<S> where
+        C::Point: ControlPoint<Scalar = S>,
+        <C::Point as ControlPoint>::Diff: InnerSpace,
+    {
+        let intervals = if intervals == 0 { 1 } else { intervals };
+        let step = S::one() / cast(intervals).unwrap();
+        let half_step = step / (S::one() + S::one());
+
+        let mut lengths = Vec::with_capacity(intervals + 1);
+        let mut total = S::zero();
+        lengths.push(total);
+        for i in 0..intervals {
+            let center = step * cast(i).unwrap() + half_step;
+            for &(node, weight) in GAUSS_LEGENDRE.iter() {
+                let t = center + half_step * cast(node).unwrap();
+                let speed = curve.derivative(t).magnitude();
+                total = total + speed * half_step * cast(weight).unwrap();
+            }
+            lengths.push(total);
+        }
+        ArcLengthTable { lengths: lengths }
+    }
+
+    /// The total length of the curve.
+    #[inline]
+    pub fn total_length(&self) -> S {
+        *self.lengths.last().unwrap()
+    }
+
+    /// The number of steps of `t` in the table.
+    #[inline]
+    pub fn intervals(&self) -> usize {
+        self.lengths.len() - 1
+    }
+
+    /// The distance along the curve at `t`.
+    pub fn distance_at(&self, t: S) -> S {
+        let position = t.max(S::zero()).min(S::one()) * cast(self.intervals()).unwrap();
+        let i = cmp::min(position.floor().to_usize().unwrap(), self.intervals() - 1);
+        let fraction = position - cast(i).unwrap();
+        self.lengths[i] + (self.lengths[i + 1] - self.lengths[i]) * fraction
+    }
+
+    /// The value of `t` at which the curve is `distance` along it. Distances
+    /// beyond either end of the curve are clamped to that end.
+    pub fn t_at_distance(&self, distance: S) -> S {
+        let distance = distance.max(S::zero()).min(self.total_length());
+
+        // Find the last step that starts at or before `distance`.
+        let (mut low, mut high) = (0, self.intervals());
+        while high - low > 1 {
+            let mid = (low + high) / 2;
+            if self.lengths[mid] <= distance { low = mid; } else { high = mid; }
+        }
+
+        let step_length = self.lengths[low + 1] - self.lengths[low];
+        let fraction = if step_length > S::zero() {
+            (distance - self.lengths[low]) / step_length
+        } else {
+            S::zero()
+        };
+        (fraction + cast(low).unwrap()) / cast(self.intervals()).unwrap()
+    }
+}
@@ -82,3 +82,45 @@ pub fn array3x3<T, A: Into<[[T; 3]; 3]>>(value: A) -> [[T; 3]; 3] {
 pub fn array4x4<T, A: Into<[[T; 4]; 4]>>(value: A) -> [[T; 4]; 4] {
     value.into()
 }
+
+/// Force a conversion into a 2x3-element array, made of 3 columns with 2
+/// elements each.
+#[inline]
+pub fn array2x3<T, A: Into<[[T; 2]; 3]>>(value: A) -> [[T; 2]; 3] {
+    value.into()
+}
+
+/// Force a conversion into a 2x4-element array, made of 4 columns with 2
+/// elements each.
+#[inline]
+pub fn array2x4<T, A: Into<[[T; 2]; 4]>>(value: A) -> [[T; 2]; 4] {
+    value.into()
+}
+
+/// Force a conversion into a 3x2-element array, made of 2 columns with 3
+/// elements each.
+#[inline]
+pub fn array3x2<T, A: Into<[[T; 3]; 2]>>(value: A) -> [[T; 3]; 2] {
+    value.into()
+}
+
+/// Force a conversion into a 3x4-element array, made of 4 columns with 3
+/// elements each.
+#[inline]
+pub fn array3x4<T, A: Into<[[T; 3]; 4]>>(value: A) -> [[T; 3]; 4] {
+    value.into()
+}
+
+/// Force a conversion into a 4x2-element array, made of 2 columns with 4
+/// elements each.
+#[inline]
+pub fn array4x2<T, A: Into<[[T; 4]; 2]>>(value: A) -> [[T; 4]; 2] {
+    value.into()
+}
+
+/// Force a conversion into a 4x3-element array, made of 3 columns with 4
+/// elements each.
+#[inline]
+pub fn array4x3<T, A: Into<[[T; 4]; 3]>>(value: A) -> [[T; 4]; 3] {
+    value.into()
+}
@@ -0,0 +1,609 @@
+// Copyright 2016 The CGMath Developers. For a full listing of the authors,
+// refer to the Cargo.toml file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Parametric curve segments: Bézier, Hermite, Catmull-Rom and uniform
+//! B-spline.
+//!
+//! Every segment is parameterized by `t` between `0` and `1`, and derivatives
+//! are taken with respect to `t`. Longer splines are built from consecutive
+//! segments.
+
+use std::cmp;
+use std::ops::*;
+
+use num_traits::{cast, Float};
+
+use structure::*;
+
+use arc_length::ArcLengthTable;
+use num::BaseFloat;
+
+/// A type that curves can be built from: a vector in any `VectorSpace`, or a
+/// point in any `EuclideanSpace`, with a floating point scalar.
+///
+/// Curves are evaluated as affine combinations of their control points,
+/// through the displacements between them, so a point never needs to be
+/// converted to a vector from the origin. This is implemented for every type
+/// with the required operators, and does not need to be implemented by hand.
+pub trait ControlPoint: Copy where
+    // FIXME: Ugly type signatures - blocked by rust-lang/rust#24092
+    Self: Add<<Self as ControlPoint>::Diff, Output = Self>,
+    Self: Sub<Self, Output = <Self as ControlPoint>::Diff>,
+{
+    /// The type of the coordinates.
+    type Scalar: BaseFloat;
+
+    /// The displacement between two control points, which is also the type
+    /// of the derivatives of a curve. This is the control point type itself
+    /// for vectors, and `EuclideanSpace::Diff` for points.
+    type Diff: VectorSpace<Scalar = Self::Scalar>;
+}
+
+impl<P, D> ControlPoint for P where
+    P: Copy + Add<D, Output = P> + Sub<P, Output = D>,
+    D: VectorSpace,
+    D::Scalar: BaseFloat,
+{
+    type Scalar = D::Scalar;
+    type Diff = D;
+}
+
+/// A control point with coordinates that can be bounded one axis at a time:
+/// a floating point scalar, or an `Array` of them, such as a vector or point.
+pub trait Coordinates: ControlPoint {
+    /// The number of coordinates.
+    fn dimensions() -> usize;
+
+    /// The coordinate along axis `i`.
+    fn coordinate(&self, i: usize) -> Self::Scalar;
+
+    /// A mutable reference to the coordinate along axis `i`.
+    fn coordinate_mut(&mut self, i: usize) -> &mut Self::Scalar;
+}
+
+impl<A> Coordinates for A where A: ControlPoint + Array<Element = <A as ControlPoint>::Scalar> {
+    #[inline] fn dimensions() -> usize { A::len() }
+    #[inline] fn coordinate(&self, i: usize) -> A::Scalar { self[i] }
+    #[inline] fn coordinate_mut(&mut self, i: usize) -> &mut A::Scalar { &mut self[i] }
+}
+
+macro_rules! impl_coordinates_scalar {
+    ($S:ident) => {
+        impl Coordinates for $S {
+            #[inline] fn dimensions() -> usize { 1 }
+            #[inline] fn coordinate(&self, _: usize) -> $S { *self }
+            #[inline] fn coordinate_mut(&mut self, _: usize) -> &mut $S { self }
+        }
+    }
+}
+
+impl_coordinates_scalar!(f32);
+impl_coordinates_scalar!(f64);
+
+/// The number of intervals in the arc-length tables used by `Curve::length`
+/// and `Curve::sample_evenly`.
+const ARC_LENGTH_INTERVALS: usize = 128;
+
+/// The maximum depth of subdivision used by `Curve::flatten`.
+const FLATTEN_DEPTH: usize = 16;
+
+/// A parametric curve, defined for `t` between `0` and `1`.
+///
+/// Measuring the length of a curve, or its distance from a polyline, requires
+/// the displacements between its points to have a magnitude, so those methods
+/// are only available when they are an `InnerSpace`.
+pub trait Curve {
+    /// The type of the points on the curve.
+    type Point: ControlPoint;
+
+    /// The point on the curve at `t`.
+    fn eval(&self, t: <Self::Point as ControlPoint>::Scalar) -> Self::Point;
+
+    /// The first derivative of the curve at `t`.
+    fn derivative(&self, t: <Self::Point as ControlPoint>::Scalar) -> <Self::Point as ControlPoint>::Diff;
+
+    /// A table of the arc length of the curve, integrated over `intervals`
+    /// equal steps of `t`.
+    #[inline]
+    fn arc_length_table(&self, intervals: usize) -> ArcLengthTable<<Self::Point as ControlPoint>::Scalar> where
+        <Self::Point as ControlPoint>::Diff: InnerSpace,
+    {
+        ArcLengthTable::new(self, intervals)
+    }
+
+    /// The total length of the curve.
+    #[inline]
+    fn length(&self) -> <Self::Point as ControlPoint>::Scalar where
+        <Self::Point as ControlPoint>::Diff: InnerSpace,
+    {
+        self.arc_length_table(ARC_LENGTH_INTERVALS).total_length()
+    }
+
+    /// `count` points along the curve, spaced at equal distances along it,
+    /// starting at `t = 0` and ending at `t = 1`.
+    fn sample_evenly(&self, count: usize) -> Vec<Self::Point> where
+        <Self::Point as ControlPoint>::Diff: InnerSpace,
+    {
+        if count < 2 {
+            return (0..count).map(|_| self.eval(Zero::zero())).collect();
+        }
+        let table = self.arc_length_table(cmp::max(ARC_LENGTH_INTERVALS, count));
+        let step = table.total_length() / cast(count - 1).unwrap();
+        (0..count).map(|i| {
+            let distance = if i == count - 1 { table.total_length() } else { step * cast(i).unwrap() };
+            self.eval(table.t_at_distance(distance))
+        }).collect()
+    }
+
+    /// Approximate the curve by a polyline, subdividing it until every
+    /// segment is within `tolerance` of the curve.
+    ///
+    /// The curve is tested at the quarter points of each segment, so features
+    /// smaller than a quarter of a segment may be missed.
+    fn flatten(&self, tolerance: <Self::Point as ControlPoint>::Scalar) -> Vec<Self::Point> where
+        <Self::Point as ControlPoint>::Diff: InnerSpace,
+    {
+        let (t0, t1) = (Zero::zero(), One::one());
+        let (p0, p1) = (self.eval(t0), self.eval(t1));
+        let mut points = vec![p0];
+        flatten_into(self, (t0, p0), (t1, p1), tolerance, 0, &mut points);
+        points
+    }
+}
+
+/// Append the polyline approximating the curve after `start`, up to and
+/// including `end`, to `points`.
+fn flatten_into<C: Curve + ?Sized>(curve: &C,
+                                   start: (<C::Point as ControlPoint>::Scalar, C::Point),
+                                   end: (<C::Point as ControlPoint>::Scalar, C::Point),
+                                   tolerance: <C::Point as ControlPoint>::Scalar,
+                                   depth: usize,
+                                   points: &mut Vec<C::Point>) where
+    <C::Point as ControlPoint>::Diff: InnerSpace,
+{
+    let half: <C::Point as ControlPoint>::Scalar = cast(0.5).unwrap();
+    let quarter: <C::Point as ControlPoint>::Scalar = cast(0.25).unwrap();
+    let mid = curve.eval(start.0 + (end.0 - start.0) * half);
+
+    let flat = depth >= FLATTEN_DEPTH || [quarter, half, half + quarter].iter().all(|&f| {
+        let on_curve = if f == half { mid } else { curve.eval(start.0 + (end.0 - start.0) * f) };
+        let on_chord = lerp(start.1, end.1, f);
+        (on_curve - on_chord).magnitude() <= tolerance
+    });
+    if flat {
+        points.push(end.1);
+    } else {
+        let mid = (start.0 + (end.0 - start.0) * half, mid);
+        flatten_into(curve, start, mid, tolerance, depth + 1, points);
+        flatten_into(curve, mid, end, tolerance, depth + 1, points);
+    }
+}
+
+macro_rules! impl_curve {
+    ($Curve:ident $(where $Bound:ident)*) => {
+        impl<P: ControlPoint> Curve for $Curve<P> $(where P::Diff: $Bound)* {
+            type Point = P;
+
+            #[inline]
+            fn eval(&self, t: P::Scalar) -> P { $Curve::eval(self, t) }
+
+            #[inline]
+            fn derivative(&self, t: P::Scalar) -> P::Diff { $Curve::derivative(self, t) }
+        }
+    }
+}
+
+impl_curve!(QuadraticBezier);
+impl_curve!(CubicBezier);
+impl_curve!(CubicHermite);
+impl_curve!(CatmullRom where InnerSpace);
+impl_curve!(UniformBSpline);
+
+/// A quadratic Bézier curve, from `p0` to `p2`, pulled towards `p1`.
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "rustc-serialize", derive(RustcEncodable, RustcDecodable))]
+#[cfg_attr(feature = "eders", derive(Serialize, Deserialize))]
+pub struct QuadraticBezier<P> {
+    pub p0: P,
+    pub p1: P,
+    pub p2: P,
+}
+
+impl<P: ControlPoint> QuadraticBezier<P> {
+    #[inline]
+    pub fn new(p0: P, p1: P, p2: P) -> QuadraticBezier<P> {
+        QuadraticBezier { p0: p0, p1: p1, p2: p2 }
+    }
+
+    /// The point on the curve at `t`.
+    pub fn eval(&self, t: P::Scalar) -> P {
+        lerp(lerp(self.p0, self.p1, t), lerp(self.p1, self.p2, t), t)
+    }
+
+    /// The first derivative of the curve at `t`.
+    pub fn derivative(&self, t: P::Scalar) -> P::Diff {
+        lerp(self.p1 - self.p0, self.p2 - self.p1, t) * two()
+    }
+
+    /// The second derivative of the curve, which is the same at every `t`.
+    pub fn second_derivative(&self, _t: P::Scalar) -> P::Diff {
+        (self.p2 - self.p1 - (self.p1 - self.p0)) * two()
+    }
+
+    /// Split the curve at `t`, with de Casteljau's algorithm, into the parts
+    /// before and after `t`.
+    pub fn split(&self, t: P::Scalar) -> (QuadraticBezier<P>, QuadraticBezier<P>) {
+        let (p01, p12) = (lerp(self.p0, self.p1, t), lerp(self.p1, self.p2, t));
+        let mid = lerp(p01, p12, t);
+        (QuadraticBezier::new(self.p0, p01, mid), QuadraticBezier::new(mid, p12, self.p2))
+    }
+
+    /// The smallest axis-aligned box containing the curve, as its minimum and
+    /// maximum corners.
+    ///
+    /// The corners are returned as control points, rather than as an `Aabb2`
+    /// or `Aabb3`, as curves can be built from scalars, vectors and points of
+    /// any dimension. Use `Aabb2::new` or `Aabb3::new` to build a box from
+    /// them.
+    pub fn bounding_box(&self) -> (P, P) where P: Coordinates {
+        let mut bounds = (self.p0, self.p0);
+        extend_bounds(&mut bounds, self.p2);
+        for i in 0..P::dimensions() {
+            // The derivative along each axis is linear, with at most one root.
+            let (c0, c1, c2) = (self.p0.coordinate(i), self.p1.coordinate(i), self.p2.coordinate(i));
+            let denominator = c0 - c1 - c1 + c2;
+            if !denominator.is_zero() {
+                let t = (c0 - c1) / denominator;
+                if t > P::Scalar::zero() && t < P::Scalar::one() {
+                    extend_bounds(&mut bounds, self.eval(t));
+                }
+            }
+        }
+        bounds
+    }
+}
+
+/// A cubic Bézier curve, from `p0` to `p3`, leaving `p0` towards `p1` and
+/// arriving at `p3` from the direction of `p2`.
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "rustc-serialize", derive(RustcEncodable, RustcDecodable))]
+#[cfg_attr(feature = "eders", derive(Serialize, Deserialize))]
+pub struct CubicBezier<P> {
+    pub p0: P,
+    pub p1: P,
+    pub p2: P,
+    pub p3: P,
+}
+
+impl<P: ControlPoint> CubicBezier<P> {
+    #[inline]
+    pub fn new(p0: P, p1: P, p2: P, p3: P) -> CubicBezier<P> {
+        CubicBezier { p0: p0, p1: p1, p2: p2, p3: p3 }
+    }
+
+    /// The point on the curve at `t`.
+    pub fn eval(&self, t: P::Scalar) -> P {
+        let (p01, p12, p23) = (lerp(self.p0, self.p1, t), lerp(self.p1, self.p2, t), lerp(self.p2, self.p3, t));
+        lerp(lerp(p01, p12, t), lerp(p12, p23, t), t)
+    }
+
+    /// The first derivative of the curve at `t`.
+    pub fn derivative(&self, t: P::Scalar) -> P::Diff {
+        let (e0, e1, e2) = (self.p1 - self.p0, self.p2 - self.p1, self.p3 - self.p2);
+        let three: P::Scalar = cast(3).unwrap();
+        lerp(lerp(e0, e1, t), lerp(e1, e2, t), t) * three
+    }
+
+    /// The second derivative of the curve at `t`.
+    pub fn second_derivative(&self, t: P::Scalar) -> P::Diff {
+        let (e0, e1, e2) = (self.p1 - self.p0, self.p2 - self.p1, self.p3 - self.p2);
+        let six: P::Scalar = cast(6).unwrap();
+        lerp(e1 - e0, e2 - e1, t) * six
+    }
+
+    /// Split the curve at `t`, with de Casteljau's algorithm, into the parts
+    /// before and after `t`.
+    pub fn split(&self, t: P::Scalar) -> (CubicBezier<P>, CubicBezier<P>) {
+        let (p01, p12, p23) = (lerp(self.p0, self.p1, t), lerp(self.p1, self.p2, t), lerp(self.p2, self.p3, t));
+        let (p012, p123) = (lerp(p01, p12, t), lerp(p12, p23, t));
+        let mid = lerp(p012, p123, t);
+        (CubicBezier::new(self.p0, p01, p012, mid), CubicBezier::new(mid, p123, p23, self.p3))
+    }
+
+    /// The smallest axis-aligned box containing the curve, as its minimum and
+    /// maximum corners.
+    ///
+    /// The corners are returned as control points, rather than as an `Aabb2`
+    /// or `Aabb3`, as curves can be built from scalars, vectors and points of
+    /// any dimension. Use `Aabb2::new` or `Aabb3::new` to build a box from
+    /// them.
+    pub fn bounding_box(&self) -> (P, P) where P: Coordinates {
+        let mut bounds = (self.p0, self.p0);
+        extend_bounds(&mut bounds, self.p3);
+        for i in 0..P::dimensions() {
+            // The derivative along each axis is the quadratic
+            // `a * t² + b * t + c`, scaled by three.
+            let (c0, c1, c2, c3) = (self.p0.coordinate(i), self.p1.coordinate(i),
+                                    self.p2.coordinate(i), self.p3.coordinate(i));
+            let three: P::Scalar = cast(3).unwrap();
+            let a = c3 - c0 + (c1 - c2) * three;
+            let b = (c0 - c1 - c1 + c2) * two();
+            let c = c1 - c0;
+            for &t in quadratic_roots(a, b, c).iter() {
+                if let Some(t) = t {
+                    if t > P::Scalar::zero() && t < P::Scalar::one() {
+                        extend_bounds(&mut bounds, self.eval(t));
+                    }
+                }
+            }
+        }
+        bounds
+    }
+}
+
+/// A cubic Hermite curve, from `p0` to `p1`, with the tangents `m0` and `m1`
+/// at either end.
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "rustc-serialize", derive(RustcEncodable, RustcDecodable))]
+#[cfg_attr(feature = "eders", derive(Serialize, Deserialize))]
+pub struct CubicHermite<P: ControlPoint> {
+    pub p0: P,
+    pub m0: P::Diff,
+    pub p1: P,
+    pub m1: P::Diff,
+}
+
+impl<P: ControlPoint> CubicHermite<P> {
+    #[inline]
+    pub fn new(p0: P, m0: P::Diff, p1: P, m1: P::Diff) -> CubicHermite<P> {
+        CubicHermite { p0: p0, m0: m0, p1: p1, m1: m1 }
+    }
+
+    /// The point on the curve at `t`.
+    #[inline]
+    pub fn eval(&self, t: P::Scalar) -> P {
+        CubicBezier::from(*self).eval(t)
+    }
+
+    /// The first derivative of the curve at `t`.
+    #[inline]
+    pub fn derivative(&self, t: P::Scalar) -> P::Diff {
+        CubicBezier::from(*self).derivative(t)
+    }
+
+    /// The second derivative of the curve at `t`.
+    #[inline]
+    pub fn second_derivative(&self, t: P::Scalar) -> P::Diff {
+        CubicBezier::from(*self).second_derivative(t)
+    }
+
+    /// Split the curve at `t` into the parts before and after `t`, each
+    /// reparameterized from `0` to `1`.
+    pub fn split(&self, t: P::Scalar) -> (CubicHermite<P>, CubicHermite<P>) {
+        let (before, after) = CubicBezier::from(*self).split(t);
+        (before.into(), after.into())
+    }
+
+    /// The smallest axis-aligned box containing the curve, as its minimum and
+    /// maximum corners. See `CubicBezier::bounding_box`.
+    #[inline]
+    pub fn bounding_box(&self) -> (P, P) where P: Coordinates {
+        CubicBezier::from(*self).bounding_box()
+    }
+}
+
+impl<P: ControlPoint> From<CubicHermite<P>> for CubicBezier<P> {
+    fn from(curve: CubicHermite<P>) -> CubicBezier<P> {
+        let three: P::Scalar = cast(3).unwrap();
+        CubicBezier::new(curve.p0, curve.p0 + curve.m0 / three, curve.p1 + curve.m1 / -three, curve.p1)
+    }
+}
+
+impl<P: ControlPoint> From<CubicBezier<P>> for CubicHermite<P> {
+    fn from(curve: CubicBezier<P>) -> CubicHermite<P> {
+        let three: P::Scalar = cast(3).unwrap();
+        CubicHermite::new(curve.p0, (curve.p1 - curve.p0) * three, curve.p3, (curve.p3 - curve.p2) * three)
+    }
+}
+
+/// How the knots of a Catmull-Rom curve are spaced.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "rustc-serialize", derive(RustcEncodable, RustcDecodable))]
+#[cfg_attr(feature = "eders", derive(Serialize, Deserialize))]
+pub enum Parameterization {
+    /// Equally spaced knots.
+    Uniform,
+    /// Knots spaced by the square root of the distance between control
+    /// points, which avoids cusps and self-intersections within a segment.
+    Centripetal,
+    /// Knots spaced by the distance between control points.
+    Chordal,
+}
+
+/// A segment of a Catmull-Rom spline, which passes through `p1` and `p2`,
+/// with tangents determined by the neighbouring points `p0` and `p3`.
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "rustc-serialize", derive(RustcEncodable, RustcDecodable))]
+#[cfg_attr(feature = "eders", derive(Serialize, Deserialize))]
+pub struct CatmullRom<P> {
+    pub p0: P,
+    pub p1: P,
+    pub p2: P,
+    pub p3: P,
+    pub parameterization: Parameterization,
+}
+
+impl<P: ControlPoint> CatmullRom<P> where P::Diff: InnerSpace {
+    #[inline]
+    pub fn new(p0: P, p1: P, p2: P, p3: P, parameterization: Parameterization) -> CatmullRom<P> {
+        CatmullRom { p0: p0, p1: p1, p2: p2, p3: p3, parameterization: parameterization }
+    }
+
+    /// The equivalent Hermite curve from `p1` to `p2`, following the
+    /// Barry-Goldman formulation.
+    pub fn to_hermite(&self) -> CubicHermite<P> {
+        let (e0, e1, e2) = (self.p1 - self.p0, self.p2 - self.p1, self.p3 - self.p2);
+        let interval = |e: P::Diff| {
+            let dt = match self.parameterization {
+                Parameterization::Uniform => P::Scalar::one(),
+                Parameterization::Centripetal => e.magnitude().sqrt(),
+                Parameterization::Chordal => e.magnitude(),
+            };
+            // Coincident control points would give a zero interval.
+            if dt.is_zero() { P::Scalar::one() } else { dt }
+        };
+        let (dt0, dt1, dt2) = (interval(e0), interval(e1), interval(e2));
+
+        let m1 = (e0 / dt0 - (e0 + e1) / (dt0 + dt1) + e1 / dt1) * dt1;
+        let m2 = (e1 / dt1 - (e1 + e2) / (dt1 + dt2) + e2 / dt2) * dt1;
+        CubicHermite::new(self.p1, m1, self.p2, m2)
+    }
+
+    /// The point on the curve at `t`.
+    #[inline]
+    pub fn eval(&self, t: P::Scalar) -> P {
+        self.to_hermite().eval(t)
+    }
+
+    /// The first derivative of the curve at `t`.
+    #[inline]
+    pub fn derivative(&self, t: P::Scalar) -> P::Diff {
+        self.to_hermite().derivative(t)
+    }
+
+    /// The second derivative of the curve at `t`.
+    #[inline]
+    pub fn second_derivative(&self, t: P::Scalar) -> P::Diff {
+        self.to_hermite().second_derivative(t)
+    }
+
+    /// Split the curve at `t` into the parts before and after `t`, as Hermite
+    /// curves, since the parts are not Catmull-Rom curves themselves.
+    #[inline]
+    pub fn split(&self, t: P::Scalar) -> (CubicHermite<P>, CubicHermite<P>) {
+        self.to_hermite().split(t)
+    }
+
+    /// The smallest axis-aligned box containing the curve, as its minimum and
+    /// maximum corners. See `CubicBezier::bounding_box`.
+    #[inline]
+    pub fn bounding_box(&self) -> (P, P) where P: Coordinates {
+        self.to_hermite().bounding_box()
+    }
+}
+
+/// A segment of a uniform cubic B-spline, with the control points `p0` to
+/// `p3`. The curve does not generally pass through its control points.
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "rustc-serialize", derive(RustcEncodable, RustcDecodable))]
+#[cfg_attr(feature = "eders", derive(Serialize, Deserialize))]
+pub struct UniformBSpline<P> {
+    pub p0: P,
+    pub p1: P,
+    pub p2: P,
+    pub p3: P,
+}
+
+impl<P: ControlPoint> UniformBSpline<P> {
+    #[inline]
+    pub fn new(p0: P, p1: P, p2: P, p3: P) -> UniformBSpline<P> {
+        UniformBSpline { p0: p0, p1: p1, p2: p2, p3: p3 }
+    }
+
+    /// The point on the curve at `t`.
+    #[inline]
+    pub fn eval(&self, t: P::Scalar) -> P {
+        CubicBezier::from(*self).eval(t)
+    }
+
+    /// The first derivative of the curve at `t`.
+    #[inline]
+    pub fn derivative(&self, t: P::Scalar) -> P::Diff {
+        CubicBezier::from(*self).derivative(t)
+    }
+
+    /// The second derivative of the curve at `t`.
+    #[inline]
+    pub fn second_derivative(&self, t: P::Scalar) -> P::Diff {
+        CubicBezier::from(*self).second_derivative(t)
+    }
+
+    /// Split the curve at `t` into the parts before and after `t`, as Bézier
+    /// curves, since the parts are not uniform B-splines themselves.
+    #[inline]
+    pub fn split(&self, t: P::Scalar) -> (CubicBezier<P>, CubicBezier<P>) {
+        CubicBezier::from(*self).split(t)
+    }
+
+    /// The smallest axis-aligned box containing the curve, as its minimum and
+    /// maximum corners. See `CubicBezier::bounding_box`.
+    #[inline]
+    pub fn bounding_box(&self) -> (P, P) where P: Coordinates {
+        CubicBezier::from(*self).bounding_box()
+    }
+}
+
+impl<P: ControlPoint> From<UniformBSpline<P>> for CubicBezier<P> {
+    fn from(curve: UniformBSpline<P>) -> CubicBezier<P> {
+        // Each Bézier control point is an affine combination of the B-spline
+        // control points, written relative to its nearest one.
+        let (e0, e1, e2) = (curve.p0 - curve.p1, curve.p2 - curve.p1, curve.p3 - curve.p2);
+        let three: P::Scalar = cast(3).unwrap();
+        let six: P::Scalar = cast(6).unwrap();
+        CubicBezier::new(curve.p1 + (e0 + e1) / six,
+                         curve.p1 + e1 / three,
+                         curve.p2 + e1 / -three,
+                         curve.p2 + (e2 - e1) / six)
+    }
+}
+
+#[inline]
+fn two<S: BaseFloat>() -> S {
+    S::one() + S::one()
+}
+
+/// The affine combination `a + (b - a) * t`, of either control points or the
+/// displacements between them.
+#[inline]
+fn lerp<P: ControlPoint>(a: P, b: P, t: P::Scalar) -> P {
+    a + (b - a) * t
+}
+
+/// Grow the box `bounds` to contain `point`.
+fn extend_bounds<P: Coordinates>(bounds: &mut (P, P), point: P) {
+    for i in 0..P::dimensions() {
+        let c = point.coordinate(i);
+        if c < bounds.0.coordinate(i) { *bounds.0.coordinate_mut(i) = c; }
+        if c > bounds.1.coordinate(i) { *bounds.1.coordinate_mut(i) = c; }
+    }
+}
+
+/// The real roots of `a * t² + b * t + c`, computed so as to avoid
+/// cancellation.
+fn quadratic_roots<S: BaseFloat>(a: S, b: S, c: S) -> [Option<S>; 2] {
+    if a.is_zero() {
+        return [if b.is_zero() { None } else { Some(-c / b) }, None];
+    }
+    let discriminant = b * b - a * c * two() * two();
+    if discriminant < S::zero() {
+        return [None, None];
+    }
+    let q = -(b + b.signum() * discriminant.sqrt()) / two();
+    if q.is_zero() {
+        [Some(S::zero()), None]
+    } else {
+        [Some(q / a), Some(c / q)]
+    }
+}
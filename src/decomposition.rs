@@ -0,0 +1,329 @@
+// Copyright 2016 The CGMath Developers. For a full listing of the authors,
+// refer to the Cargo.toml file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Decomposition of transformation matrices into their components, following
+//! Spencer W. Thomas, "Decomposing a Matrix into Simple Transformations", in
+//! Graphics Gems II, and re-orthonormalization of rotation matrices.
+
+use structure::*;
+
+use angle::Rad;
+use approx::ApproxEq;
+use matrix::{Matrix2, Matrix3, Matrix4};
+use num::BaseFloat;
+use quaternion::Quaternion;
+use rotation::Rotation3;
+use transform::{Decomposed, NonUniformDecomposed};
+use vector::{Vector3, Vector4};
+
+/// The components of a transformation matrix.
+///
+/// The matrix is rebuilt by scaling, then shearing, rotating and translating,
+/// and finally applying the perspective. A matrix with a negative determinant
+/// is represented by a negative `x` scale.
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "rustc-serialize", derive(RustcEncodable, RustcDecodable))]
+#[cfg_attr(feature = "eders", derive(Serialize, Deserialize))]
+pub struct MatrixDecomposition<S> {
+    pub translation: Vector3<S>,
+    pub rotation: Quaternion<S>,
+    pub scale: Vector3<S>,
+    /// The shear factors, of `x` by `y`, `x` by `z`, and `y` by `z`.
+    pub shear: Vector3<S>,
+    /// The bottom row of the matrix, after removing the other components. It
+    /// is `(0, 0, 0, 1)` for affine transforms.
+    pub perspective: Vector4<S>,
+}
+
+impl<S: BaseFloat> Matrix3<S> {
+    /// Decompose the matrix into a rotation, scale and shear. The translation
+    /// of the result is zero, and the perspective is `(0, 0, 0, 1)`.
+    ///
+    /// Returns `None` if the matrix is singular.
+    pub fn decompose(&self) -> Option<MatrixDecomposition<S>> {
+        let (mut x, mut y, mut z) = (self.x, self.y, self.z);
+
+        // Orthonormalize the columns, in the order x, y, z, collecting the
+        // scale and shear along the way.
+        let mut scale = Vector3::new(x.magnitude(), S::zero(), S::zero());
+        if scale.x.is_zero() { return None; }
+        x = x / scale.x;
+
+        let mut shear = Vector3::new(x.dot(y), S::zero(), S::zero());
+        y = y - x * shear.x;
+        scale.y = y.magnitude();
+        if scale.y.is_zero() { return None; }
+        y = y / scale.y;
+        shear.x = shear.x / scale.y;
+
+        shear.y = x.dot(z);
+        z = z - x * shear.y;
+        shear.z = y.dot(z);
+        z = z - y * shear.z;
+        scale.z = z.magnitude();
+        if scale.z.is_zero() { return None; }
+        z = z / scale.z;
+        shear.y = shear.y / scale.z;
+        shear.z = shear.z / scale.z;
+
+        // Flip the x axis of a left-handed basis, so that it is a rotation.
+        if x.dot(y.cross(z)) < S::zero() {
+            x = -x;
+            scale.x = -scale.x;
+            shear.x = -shear.x;
+            shear.y = -shear.y;
+        }
+
+        Some(MatrixDecomposition {
+            translation: Vector3::zero(),
+            rotation: Quaternion::from(Matrix3::from_cols(x, y, z)),
+            scale: scale,
+            shear: shear,
+            perspective: Vector4::unit_w(),
+        })
+    }
+}
+
+impl<S: BaseFloat> Matrix4<S> {
+    /// Decompose the matrix into a translation, rotation, scale, shear and
+    /// perspective. The matrix is first divided by its bottom right element,
+    /// so the decomposition is only equal to it up to a scalar factor.
+    ///
+    /// Returns `None` if the matrix is singular.
+    pub fn decompose(&self) -> Option<MatrixDecomposition<S>> {
+        if self[3][3].is_zero() {
+            return None;
+        }
+        let m = self / self[3][3];
+
+        // Remove the perspective by solving for the bottom row that turns the
+        // affine part of the matrix into the full matrix.
+        let mut affine = m;
+        affine.x.w = S::zero();
+        affine.y.w = S::zero();
+        affine.z.w = S::zero();
+        affine.w.w = S::one();
+        let perspective = if m.row(3) == Vector4::unit_w() {
+            Vector4::unit_w()
+        } else {
+            match affine.invert() {
+                Some(inverse) => inverse.transpose() * m.row(3),
+                None => return None,
+            }
+        };
+
+        let linear = Matrix3::from_cols(m.x.truncate(), m.y.truncate(), m.z.truncate());
+        linear.decompose().map(|parts| MatrixDecomposition {
+            translation: m.w.truncate(),
+            perspective: perspective,
+            ..parts
+        })
+    }
+}
+
+impl<S: BaseFloat> MatrixDecomposition<S> {
+    /// Convert into a `Decomposed` transform, which is only possible when
+    /// the scale is uniform, and there is no shear or perspective.
+    pub fn to_decomposed(&self) -> Option<Decomposed<Vector3<S>, Quaternion<S>>> {
+        let epsilon = S::default_epsilon().sqrt();
+        let scale = self.scale.x.abs();
+        let uniform = self.scale.y.relative_eq(&scale, epsilon, epsilon) &&
+                      self.scale.z.relative_eq(&scale, epsilon, epsilon);
+        let affine = self.shear.relative_eq(&Vector3::zero(), epsilon, epsilon) &&
+                     self.perspective.relative_eq(&Vector4::unit_w(), epsilon, epsilon);
+        if !uniform || !affine {
+            return None;
+        }
+
+        if self.scale.x < S::zero() {
+            // Mirroring the x axis is a half turn around it, followed by a
+            // point reflection.
+            Some(Decomposed {
+                scale: -scale,
+                rot: self.rotation * Quaternion::from_angle_x(Rad::turn_div_2()),
+                disp: self.translation,
+            })
+        } else {
+            Some(Decomposed {
+                scale: scale,
+                rot: self.rotation,
+                disp: self.translation,
+            })
+        }
+    }
+}
+
+impl<S: BaseFloat> From<MatrixDecomposition<S>> for Matrix4<S> {
+    fn from(parts: MatrixDecomposition<S>) -> Matrix4<S> {
+        let shear = Matrix3::new(S::one(), S::zero(), S::zero(),
+                                 parts.shear.x, S::one(), S::zero(),
+                                 parts.shear.y, parts.shear.z, S::one());
+        let linear = Matrix3::from(parts.rotation) * shear * Matrix3::from_diagonal(parts.scale);
+
+        let mut m = Matrix4::from(linear);
+        m.w = parts.translation.extend(S::one());
+        let row = m.transpose() * parts.perspective;
+        m.x.w = row.x;
+        m.y.w = row.y;
+        m.z.w = row.z;
+        m.w.w = row.w;
+        m
+    }
+}
+
+impl<S: BaseFloat> From<MatrixDecomposition<S>> for NonUniformDecomposed<S, Quaternion<S>> {
+    /// Convert into a `NonUniformDecomposed` transform, discarding the shear
+    /// and perspective.
+    fn from(parts: MatrixDecomposition<S>) -> NonUniformDecomposed<S, Quaternion<S>> {
+        NonUniformDecomposed {
+            scale: parts.scale,
+            rot: parts.rotation,
+            disp: parts.translation,
+        }
+    }
+}
+
+/// The maximum number of iterations used by `Matrix3::polar_decompose`.
+const POLAR_ITERATIONS: usize = 32;
+
+impl<S: BaseFloat> Matrix2<S> {
+    /// Orthonormalize the columns with the Gram-Schmidt process, keeping the
+    /// direction of the `x` column.
+    ///
+    /// Returns `None` if the columns are linearly dependent.
+    pub fn gram_schmidt(&self) -> Option<Matrix2<S>> {
+        let x = match normalize_checked(self.x) { Some(x) => x, None => return None };
+        let y = match normalize_checked(self.y - x * x.dot(self.y)) { Some(y) => y, None => return None };
+        Some(Matrix2::from_cols(x, y))
+    }
+
+    /// The polar decomposition of the matrix, into an orthogonal matrix `Q`
+    /// and a symmetric positive-definite matrix `P`, such that `self = Q * P`.
+    ///
+    /// `Q` is the orthogonal matrix closest to `self`. It is a reflection if
+    /// the determinant of `self` is negative.
+    ///
+    /// Returns `None` if the matrix is singular.
+    pub fn polar_decompose(&self) -> Option<(Matrix2<S>, Matrix2<S>)> {
+        let det = self.determinant();
+        if det.is_zero() {
+            return None;
+        }
+        // Adding or subtracting the cofactor matrix cancels the symmetric
+        // part, leaving a multiple of a rotation or reflection.
+        let cofactor = Matrix2::new(self.y.y, -self.y.x, -self.x.y, self.x.x);
+        let q = if det > S::zero() { self + cofactor } else { self - cofactor };
+        let q = q / q.x.magnitude();
+        Some((q, symmetric_part(q.transpose() * self)))
+    }
+}
+
+impl<S: BaseFloat> Matrix3<S> {
+    /// Orthonormalize the columns with the Gram-Schmidt process, keeping the
+    /// direction of the `x` column, and the plane of the `x` and `y` columns.
+    ///
+    /// This is cheaper than `polar_decompose`, but biased towards the `x`
+    /// column. Returns `None` if the columns are linearly dependent.
+    pub fn gram_schmidt(&self) -> Option<Matrix3<S>> {
+        let x = match normalize_checked(self.x) { Some(x) => x, None => return None };
+        let y = match normalize_checked(self.y - x * x.dot(self.y)) { Some(y) => y, None => return None };
+        let z = self.z - x * x.dot(self.z) - y * y.dot(self.z);
+        let z = match normalize_checked(z) { Some(z) => z, None => return None };
+        Some(Matrix3::from_cols(x, y, z))
+    }
+
+    /// The polar decomposition of the matrix, into an orthogonal matrix `Q`
+    /// and a symmetric positive-definite matrix `P`, such that `self = Q * P`.
+    ///
+    /// `Q` is the orthogonal matrix closest to `self`, and is found with the
+    /// scaled Newton iteration described by Higham in "Computing the Polar
+    /// Decomposition - with Applications". It is a reflection if the
+    /// determinant of `self` is negative.
+    ///
+    /// Returns `None` if the matrix is singular.
+    pub fn polar_decompose(&self) -> Option<(Matrix3<S>, Matrix3<S>)> {
+        let tolerance = S::default_epsilon().sqrt();
+        let mut q = *self;
+        for _ in 0..POLAR_ITERATIONS {
+            let next = match polar_step(q) { Some(next) => next, None => return None };
+            let delta = next - q;
+            let delta = (delta.x.magnitude2() + delta.y.magnitude2() + delta.z.magnitude2()).sqrt();
+            q = next;
+            if delta <= tolerance {
+                // The iteration converges quadratically, so one more step
+                // reaches full precision.
+                let q = match polar_step(q) { Some(q) => q, None => return None };
+                return Some((q, symmetric_part(q.transpose() * self)));
+            }
+        }
+        None
+    }
+}
+
+/// One step of the scaled Newton iteration, `(γq + q⁻ᵀ / γ) / 2`, where `γ`
+/// is chosen to bring the determinant of `q` closer to one.
+fn polar_step<S: BaseFloat>(q: Matrix3<S>) -> Option<Matrix3<S>> {
+    let inverse = match q.invert() { Some(inverse) => inverse, None => return None };
+    let gamma = q.determinant().abs().cbrt().recip();
+    let half = S::one() / (S::one() + S::one());
+    Some((q * gamma + inverse.transpose() / gamma) * half)
+}
+
+fn normalize_checked<V: InnerSpace>(v: V) -> Option<V> where V::Scalar: BaseFloat {
+    let magnitude = v.magnitude();
+    if magnitude.is_zero() { None } else { Some(v / magnitude) }
+}
+
+fn symmetric_part<M: SquareMatrix>(m: M) -> M where M::Scalar: BaseFloat {
+    let half = M::Scalar::one() / (M::Scalar::one() + M::Scalar::one());
+    (m + m.transpose()) * half
+}
+
+impl<S: BaseFloat> ApproxEq for MatrixDecomposition<S> {
+    type Epsilon = S::Epsilon;
+
+    #[inline]
+    fn default_epsilon() -> S::Epsilon {
+        S::default_epsilon()
+    }
+
+    #[inline]
+    fn default_max_relative() -> S::Epsilon {
+        S::default_max_relative()
+    }
+
+    #[inline]
+    fn default_max_ulps() -> u32 {
+        S::default_max_ulps()
+    }
+
+    #[inline]
+    fn relative_eq(&self, other: &Self, epsilon: S::Epsilon, max_relative: S::Epsilon) -> bool {
+        Vector3::relative_eq(&self.translation, &other.translation, epsilon, max_relative) &&
+        Quaternion::relative_eq(&self.rotation, &other.rotation, epsilon, max_relative) &&
+        Vector3::relative_eq(&self.scale, &other.scale, epsilon, max_relative) &&
+        Vector3::relative_eq(&self.shear, &other.shear, epsilon, max_relative) &&
+        Vector4::relative_eq(&self.perspective, &other.perspective, epsilon, max_relative)
+    }
+
+    #[inline]
+    fn ulps_eq(&self, other: &Self, epsilon: S::Epsilon, max_ulps: u32) -> bool {
+        Vector3::ulps_eq(&self.translation, &other.translation, epsilon, max_ulps) &&
+        Quaternion::ulps_eq(&self.rotation, &other.rotation, epsilon, max_ulps) &&
+        Vector3::ulps_eq(&self.scale, &other.scale, epsilon, max_ulps) &&
+        Vector3::ulps_eq(&self.shear, &other.shear, epsilon, max_ulps) &&
+        Vector4::ulps_eq(&self.perspective, &other.perspective, epsilon, max_ulps)
+    }
+}
@@ -0,0 +1,339 @@
+// Copyright 2016 The CGMath Developers. For a full listing of the authors,
+// refer to the Cargo.toml file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::ops::*;
+
+use num_traits::cast;
+
+use structure::*;
+
+use angle::Rad;
+use approx::ApproxEq;
+use matrix::{Matrix3, Matrix4};
+use num::BaseFloat;
+use point::Point3;
+use quaternion::Quaternion;
+use rotation::{Rotation, Rotation3};
+use transform::{Decomposed, Transform, Transform3};
+use vector::Vector3;
+
+/// A [dual quaternion](https://en.wikipedia.org/wiki/Dual_quaternion),
+/// `real + ε dual`, where `ε² = 0`.
+///
+/// A unit dual quaternion represents a rigid transformation: a rotation
+/// followed by a translation. Unlike `Decomposed`, dual quaternions can be
+/// blended without the rotation and translation drifting apart, which makes
+/// them useful for skinning.
+///
+/// This type is marked as `#[repr(C)]`.
+#[repr(C)]
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "rustc-serialize", derive(RustcEncodable, RustcDecodable))]
+#[cfg_attr(feature = "eders", derive(Serialize, Deserialize))]
+pub struct DualQuaternion<S> {
+    /// The real part, which holds the rotation.
+    pub real: Quaternion<S>,
+    /// The dual part, which holds the translation.
+    pub dual: Quaternion<S>,
+}
+
+impl<S: BaseFloat> DualQuaternion<S> {
+    /// Construct a new dual quaternion from its real and dual parts.
+    #[inline]
+    pub fn new(real: Quaternion<S>, dual: Quaternion<S>) -> DualQuaternion<S> {
+        DualQuaternion { real: real, dual: dual }
+    }
+
+    /// Construct a dual quaternion that only rotates.
+    #[inline]
+    pub fn from_rotation(rot: Quaternion<S>) -> DualQuaternion<S> {
+        DualQuaternion::new(rot, Quaternion::zero())
+    }
+
+    /// Construct a dual quaternion that only translates.
+    #[inline]
+    pub fn from_translation(disp: Vector3<S>) -> DualQuaternion<S> {
+        DualQuaternion::from_rotation_translation(Quaternion::one(), disp)
+    }
+
+    /// Construct a dual quaternion that applies the rotation `rot`, and then
+    /// the translation `disp`. `rot` should be normalized.
+    #[inline]
+    pub fn from_rotation_translation(rot: Quaternion<S>, disp: Vector3<S>) -> DualQuaternion<S> {
+        let half: S = cast(0.5f64).unwrap();
+        DualQuaternion::new(rot, Quaternion::from_sv(S::zero(), disp) * rot * half)
+    }
+
+    /// The rotation part of a normalized dual quaternion.
+    #[inline]
+    pub fn rotation(&self) -> Quaternion<S> {
+        self.real
+    }
+
+    /// The translation part of a normalized dual quaternion.
+    #[inline]
+    pub fn translation(&self) -> Vector3<S> {
+        let two: S = cast(2i8).unwrap();
+        (self.dual * self.real.conjugate()).v * two
+    }
+
+    /// The quaternion conjugate of both parts, `real* + ε dual*`.
+    ///
+    /// For a normalized dual quaternion this is the inverse transformation.
+    #[inline]
+    pub fn conjugate(self) -> DualQuaternion<S> {
+        DualQuaternion::new(self.real.conjugate(), self.dual.conjugate())
+    }
+
+    /// The dual number conjugate, `real - ε dual`.
+    #[inline]
+    pub fn dual_conjugate(self) -> DualQuaternion<S> {
+        DualQuaternion::new(self.real, -self.dual)
+    }
+
+    /// Both the quaternion and dual number conjugates, `real* - ε dual*`.
+    #[inline]
+    pub fn combined_conjugate(self) -> DualQuaternion<S> {
+        DualQuaternion::new(self.real.conjugate(), -self.dual.conjugate())
+    }
+
+    /// Scale the dual quaternion to unit length, and make the dual part
+    /// orthogonal to the real part, so that it represents a rigid
+    /// transformation.
+    pub fn normalize(self) -> DualQuaternion<S> {
+        let inv_magnitude = self.real.magnitude().recip();
+        let real = self.real * inv_magnitude;
+        let dual = self.dual * inv_magnitude;
+        DualQuaternion::new(real, dual - real * real.dot(dual))
+    }
+
+    /// Screw linear interpolation
+    ///
+    /// Return the interpolation between the dual quaternion and `other` that
+    /// moves along a single screw motion at a constant speed. Both dual
+    /// quaternions should be normalized first.
+    ///
+    /// - [Skinning with Dual Quaternions]
+    ///   (https://www.cs.utah.edu/~ladislav/kavan07skinning/kavan07skinning.pdf)
+    pub fn sclerp(self, other: DualQuaternion<S>, amount: S) -> DualQuaternion<S> {
+        let two: S = cast(2i8).unwrap();
+        let half: S = cast(0.5f64).unwrap();
+
+        // take the shortest path
+        let other = if self.real.dot(other.real) < S::zero() { -other } else { other };
+        let diff = self.conjugate() * other;
+
+        let (sin_half, cos_half) = (diff.real.v.magnitude(), diff.real.s);
+        if sin_half <= S::default_epsilon().sqrt() {
+            // the rotations are too close together to recover a screw axis
+            return (self * (S::one() - amount) + other * amount).normalize();
+        }
+
+        // screw parameters: the rotation angle and axis, the translation
+        // along the axis, and the moment of the axis
+        let angle = Rad::atan2(sin_half, cos_half) * two;
+        let axis = diff.real.v / sin_half;
+        let pitch = -two * diff.dual.s / sin_half;
+        let moment = (diff.dual.v - axis * (pitch * half * cos_half)) / sin_half;
+
+        let angle = angle * amount;
+        let pitch = pitch * amount;
+        let (sin_half, cos_half) = Rad::sin_cos(angle * half);
+
+        let step = DualQuaternion::new(
+            Quaternion::from_sv(cos_half, axis * sin_half),
+            Quaternion::from_sv(-pitch * half * sin_half, moment * sin_half + axis * (pitch * half * cos_half)),
+        );
+        self * step
+    }
+
+    /// Dual quaternion linear blending
+    ///
+    /// Return the normalized weighted sum of `dqs`, flipping any that lie in
+    /// the opposite hemisphere to the first so that the blend takes the
+    /// shortest path. Returns the identity if `dqs` is empty, or if the
+    /// weighted sum cannot be normalized because all of the weights are zero.
+    ///
+    /// - [Skinning with Dual Quaternions]
+    ///   (https://www.cs.utah.edu/~ladislav/kavan07skinning/kavan07skinning.pdf)
+    pub fn blend(dqs: &[DualQuaternion<S>], weights: &[S]) -> DualQuaternion<S> {
+        let identity = DualQuaternion::from_rotation(Quaternion::one());
+        match dqs.first() {
+            None => identity,
+            Some(pivot) => {
+                let zero = DualQuaternion::new(Quaternion::zero(), Quaternion::zero());
+                let sum = dqs.iter().zip(weights.iter()).fold(zero, |sum, (dq, &weight)| {
+                    if pivot.real.dot(dq.real) < S::zero() {
+                        sum - *dq * weight
+                    } else {
+                        sum + *dq * weight
+                    }
+                });
+                if sum.real.magnitude2().is_zero() { identity } else { sum.normalize() }
+            }
+        }
+    }
+}
+
+impl_operator!(<S: BaseFloat> Neg for DualQuaternion<S> {
+    fn neg(dq) -> DualQuaternion<S> {
+        DualQuaternion::new(-dq.real, -dq.dual)
+    }
+});
+
+impl_operator!(<S: BaseFloat> Mul<S> for DualQuaternion<S> {
+    fn mul(lhs, rhs) -> DualQuaternion<S> {
+        DualQuaternion::new(lhs.real * rhs, lhs.dual * rhs)
+    }
+});
+
+impl_operator!(<S: BaseFloat> Div<S> for DualQuaternion<S> {
+    fn div(lhs, rhs) -> DualQuaternion<S> {
+        DualQuaternion::new(lhs.real / rhs, lhs.dual / rhs)
+    }
+});
+
+impl_operator!(<S: BaseFloat> Add<DualQuaternion<S> > for DualQuaternion<S> {
+    fn add(lhs, rhs) -> DualQuaternion<S> {
+        DualQuaternion::new(lhs.real + rhs.real, lhs.dual + rhs.dual)
+    }
+});
+
+impl_operator!(<S: BaseFloat> Sub<DualQuaternion<S> > for DualQuaternion<S> {
+    fn sub(lhs, rhs) -> DualQuaternion<S> {
+        DualQuaternion::new(lhs.real - rhs.real, lhs.dual - rhs.dual)
+    }
+});
+
+impl_operator!(<S: BaseFloat> Mul<DualQuaternion<S> > for DualQuaternion<S> {
+    fn mul(lhs, rhs) -> DualQuaternion<S> {
+        DualQuaternion::new(lhs.real * rhs.real, lhs.real * rhs.dual + lhs.dual * rhs.real)
+    }
+});
+
+impl<S: BaseFloat> ApproxEq for DualQuaternion<S> {
+    type Epsilon = S::Epsilon;
+
+    #[inline]
+    fn default_epsilon() -> S::Epsilon {
+        S::default_epsilon()
+    }
+
+    #[inline]
+    fn default_max_relative() -> S::Epsilon {
+        S::default_max_relative()
+    }
+
+    #[inline]
+    fn default_max_ulps() -> u32 {
+        S::default_max_ulps()
+    }
+
+    #[inline]
+    fn relative_eq(&self, other: &Self, epsilon: S::Epsilon, max_relative: S::Epsilon) -> bool {
+        Quaternion::relative_eq(&self.real, &other.real, epsilon, max_relative) &&
+        Quaternion::relative_eq(&self.dual, &other.dual, epsilon, max_relative)
+    }
+
+    #[inline]
+    fn ulps_eq(&self, other: &Self, epsilon: S::Epsilon, max_ulps: u32) -> bool {
+        Quaternion::ulps_eq(&self.real, &other.real, epsilon, max_ulps) &&
+        Quaternion::ulps_eq(&self.dual, &other.dual, epsilon, max_ulps)
+    }
+}
+
+impl<S: BaseFloat> Transform<Point3<S>> for DualQuaternion<S> {
+    #[inline]
+    fn one() -> DualQuaternion<S> {
+        DualQuaternion::from_rotation(Quaternion::one())
+    }
+
+    #[inline]
+    fn look_at(eye: Point3<S>, center: Point3<S>, up: Vector3<S>) -> DualQuaternion<S> {
+        let rot = Quaternion::look_at(center - eye, up);
+        let disp = rot.rotate_vector(Point3::origin() - eye);
+        DualQuaternion::from_rotation_translation(rot, disp)
+    }
+
+    #[inline]
+    fn transform_vector(&self, vec: Vector3<S>) -> Vector3<S> {
+        self.real.rotate_vector(vec)
+    }
+
+    #[inline]
+    fn transform_point(&self, point: Point3<S>) -> Point3<S> {
+        self.real.rotate_point(point) + self.translation()
+    }
+
+    #[inline]
+    fn concat(&self, other: &DualQuaternion<S>) -> DualQuaternion<S> {
+        self * other
+    }
+
+    fn inverse_transform(&self) -> Option<DualQuaternion<S>> {
+        if ulps_eq!(self.real, &Quaternion::zero()) {
+            None
+        } else {
+            Some(self.normalize().conjugate())
+        }
+    }
+}
+
+impl<S: BaseFloat> Transform3<S> for DualQuaternion<S> {
+    #[inline]
+    fn look_at_lh(eye: Point3<S>, center: Point3<S>, up: Vector3<S>) -> DualQuaternion<S> {
+        let rot = Quaternion::look_at_lh(center - eye, up);
+        DualQuaternion::from_rotation_translation(rot, rot.rotate_vector(Point3::origin() - eye))
+    }
+
+    #[inline]
+    fn look_at_rh(eye: Point3<S>, center: Point3<S>, up: Vector3<S>) -> DualQuaternion<S> {
+        let rot = Quaternion::look_at_rh(center - eye, up);
+        DualQuaternion::from_rotation_translation(rot, rot.rotate_vector(Point3::origin() - eye))
+    }
+}
+
+impl<S: BaseFloat> From<Decomposed<Vector3<S>, Quaternion<S>>> for DualQuaternion<S> {
+    /// Convert a decomposed transform into a dual quaternion. Dual quaternions
+    /// can only represent rigid transformations, so the scale is ignored.
+    fn from(dec: Decomposed<Vector3<S>, Quaternion<S>>) -> DualQuaternion<S> {
+        DualQuaternion::from_rotation_translation(dec.rot, dec.disp)
+    }
+}
+
+impl<S: BaseFloat> From<DualQuaternion<S>> for Decomposed<Vector3<S>, Quaternion<S>> {
+    fn from(dq: DualQuaternion<S>) -> Decomposed<Vector3<S>, Quaternion<S>> {
+        Decomposed {
+            scale: S::one(),
+            rot: dq.rotation(),
+            disp: dq.translation(),
+        }
+    }
+}
+
+impl<S: BaseFloat> From<DualQuaternion<S>> for Matrix4<S> {
+    fn from(dq: DualQuaternion<S>) -> Matrix4<S> {
+        Decomposed::from(dq).into()
+    }
+}
+
+impl<S: BaseFloat> From<Matrix4<S>> for DualQuaternion<S> {
+    /// Convert a rigid transformation matrix into a dual quaternion. The
+    /// upper-left 3 x 3 part of the matrix should be a rotation.
+    fn from(mat: Matrix4<S>) -> DualQuaternion<S> {
+        let rot = Matrix3::from_cols(mat.x.truncate(), mat.y.truncate(), mat.z.truncate());
+        DualQuaternion::from_rotation_translation(rot.into(), mat.w.truncate())
+    }
+}
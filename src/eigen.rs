@@ -0,0 +1,128 @@
+// Copyright 2016 The CGMath Developers. For a full listing of the authors,
+// refer to the Cargo.toml file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Eigen-decomposition of symmetric matrices.
+
+use structure::*;
+
+use angle::Rad;
+use matrix::{Matrix2, Matrix3};
+use num::BaseFloat;
+use rotation::{basis3_from_rotation_matrix, Basis2, Basis3, Rotation2};
+use vector::{Vector2, Vector3};
+
+/// The maximum number of sweeps used by `Matrix3::symmetric_eigen`.
+const JACOBI_SWEEPS: usize = 32;
+
+impl<S: BaseFloat> Matrix2<S> {
+    /// The eigenvalues and eigenvectors of a symmetric matrix.
+    ///
+    /// The eigenvalues are sorted from largest to smallest, and the columns
+    /// of the returned rotation are the corresponding unit eigenvectors, so
+    /// that `self = rot * Matrix2::from_diagonal(values) * rot⁻¹`.
+    ///
+    /// Only the symmetric part of the matrix, `(self + selfᵀ) / 2`, is used.
+    pub fn symmetric_eigen(&self) -> (Vector2<S>, Basis2<S>) {
+        let two = S::one() + S::one();
+        let (a, d) = (self.x.x, self.y.y);
+        let b = (self.x.y + self.y.x) / two;
+
+        let mean = (a + d) / two;
+        let radius = ((a - d) / two).hypot(b);
+        let angle = Rad((b * two).atan2(a - d) / two);
+        (Vector2::new(mean + radius, mean - radius), Basis2::from_angle(angle))
+    }
+}
+
+impl<S: BaseFloat> Matrix3<S> {
+    /// The eigenvalues and eigenvectors of a symmetric matrix, found with the
+    /// cyclic Jacobi method.
+    ///
+    /// The eigenvalues are sorted from largest to smallest, and the columns
+    /// of the returned rotation are the corresponding unit eigenvectors, so
+    /// that `self = rot * Matrix3::from_diagonal(values) * rot⁻¹`.
+    ///
+    /// Only the symmetric part of the matrix, `(self + selfᵀ) / 2`, is used.
+    pub fn symmetric_eigen(&self) -> (Vector3<S>, Basis3<S>) {
+        let two = S::one() + S::one();
+        let mut a = (*self + self.transpose()) / two;
+        let mut v = Matrix3::identity();
+
+        let epsilon = S::default_epsilon();
+        let norm2 = a.x.magnitude2() + a.y.magnitude2() + a.z.magnitude2();
+        for _ in 0..JACOBI_SWEEPS {
+            let off2 = a[0][1] * a[0][1] + a[0][2] * a[0][2] + a[1][2] * a[1][2];
+            if off2 <= epsilon * epsilon * norm2 {
+                break;
+            }
+            for &(p, q) in &[(0, 1), (0, 2), (1, 2)] {
+                jacobi_rotate(&mut a, &mut v, p, q);
+            }
+        }
+
+        // Sort the eigenvalues, moving the eigenvectors along with them.
+        let mut values = a.diagonal();
+        for i in 0..2 {
+            for j in 0..2 - i {
+                if values[j] < values[j + 1] {
+                    values.swap_elements(j, j + 1);
+                    v.swap_columns(j, j + 1);
+                }
+            }
+        }
+
+        // The eigenvectors are only defined up to sign, so make them a
+        // right-handed basis. `v` is a product of rotations and column swaps,
+        // so it is then a rotation.
+        if v.determinant() < S::zero() {
+            v.z = -v.z;
+        }
+        (values, basis3_from_rotation_matrix(v))
+    }
+}
+
+/// Apply the Jacobi rotation that zeroes `a[p][q]`, accumulating the rotation
+/// into the columns of `v`. See "Numerical Recipes", section 11.1.
+fn jacobi_rotate<S: BaseFloat>(a: &mut Matrix3<S>, v: &mut Matrix3<S>, p: usize, q: usize) {
+    let apq = a[p][q];
+    if apq.is_zero() {
+        return;
+    }
+    let r = 3 - p - q;
+
+    let two = S::one() + S::one();
+    let theta = (a[q][q] - a[p][p]) / (two * apq);
+    let t = theta.signum() / (theta.abs() + (theta * theta + S::one()).sqrt());
+    let c = (t * t + S::one()).sqrt().recip();
+    let s = t * c;
+    let tau = s / (S::one() + c);
+
+    a[p][p] = a[p][p] - t * apq;
+    a[q][q] = a[q][q] + t * apq;
+    a[p][q] = S::zero();
+    a[q][p] = S::zero();
+
+    let (arp, arq) = (a[r][p], a[r][q]);
+    a[r][p] = arp - s * (arq + tau * arp);
+    a[r][q] = arq + s * (arp - tau * arq);
+    a[p][r] = a[r][p];
+    a[q][r] = a[r][q];
+
+    for k in 0..3 {
+        let (vkp, vkq) = (v[p][k], v[q][k]);
+        v[p][k] = vkp - s * (vkq + tau * vkp);
+        v[q][k] = vkq + s * (vkp - tau * vkq);
+    }
+}
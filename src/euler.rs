@@ -20,6 +20,7 @@ use structure::*;
 
 use angle::Rad;
 use approx::ApproxEq;
+use matrix::{Matrix3, Matrix4};
 use quaternion::Quaternion;
 use num::BaseFloat;
 
@@ -40,6 +41,9 @@ use num::BaseFloat;
 ///
 /// # Defining rotations using Euler angles
 ///
+/// For other axis orders, or for extrinsic rotations, use
+/// [`EulerSequence`](struct.EulerSequence.html) instead.
+///
 /// Note that while [Euler angles] are intuitive to define, they are prone to
 /// [gimbal lock] and are challenging to interpolate between. Instead we
 /// recommend that you convert them to a more robust representation, such as a
@@ -180,3 +184,247 @@ impl<A: Angle + Rand> Rand for Euler<A> {
         Euler { x: rng.gen(), y: rng.gen(), z: rng.gen() }
     }
 }
+
+/// The sequence of axes that a set of Euler angles rotate around.
+///
+/// The first six orders use all three axes, and describe [Tait–Bryan angles].
+/// The last six repeat the first axis, and describe proper [Euler angles].
+///
+/// [Tait–Bryan angles]: https://en.wikipedia.org/wiki/Euler_angles#Tait.E2.80.93Bryan_angles
+/// [Euler angles]: https://en.wikipedia.org/wiki/Euler_angles#Proper_Euler_angles
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "rustc-serialize", derive(RustcEncodable, RustcDecodable))]
+#[cfg_attr(feature = "eders", derive(Serialize, Deserialize))]
+pub enum EulerOrder {
+    XYZ, XZY, YXZ, YZX, ZXY, ZYX,
+    XYX, XZX, YXY, YZY, ZXZ, ZYZ,
+}
+
+impl EulerOrder {
+    /// The indices of the three rotation axes, in the order that they are
+    /// listed, where `0`, `1` and `2` stand for the _x_, _y_ and _z_ axes.
+    pub fn axes(self) -> [usize; 3] {
+        match self {
+            EulerOrder::XYZ => [0, 1, 2],
+            EulerOrder::XZY => [0, 2, 1],
+            EulerOrder::YXZ => [1, 0, 2],
+            EulerOrder::YZX => [1, 2, 0],
+            EulerOrder::ZXY => [2, 0, 1],
+            EulerOrder::ZYX => [2, 1, 0],
+            EulerOrder::XYX => [0, 1, 0],
+            EulerOrder::XZX => [0, 2, 0],
+            EulerOrder::YXY => [1, 0, 1],
+            EulerOrder::YZY => [1, 2, 1],
+            EulerOrder::ZXZ => [2, 0, 2],
+            EulerOrder::ZYZ => [2, 1, 2],
+        }
+    }
+
+    /// Whether the order rotates about three distinct axes.
+    pub fn is_tait_bryan(self) -> bool {
+        let axes = self.axes();
+        axes[0] != axes[2]
+    }
+
+    /// The same axes, listed in the opposite order.
+    ///
+    /// An extrinsic rotation sequence is equivalent to the intrinsic rotation
+    /// sequence with the reversed order, applied with the angles swapped.
+    pub fn reverse(self) -> EulerOrder {
+        match self {
+            EulerOrder::XYZ => EulerOrder::ZYX,
+            EulerOrder::XZY => EulerOrder::YZX,
+            EulerOrder::YXZ => EulerOrder::ZXY,
+            EulerOrder::YZX => EulerOrder::XZY,
+            EulerOrder::ZXY => EulerOrder::YXZ,
+            EulerOrder::ZYX => EulerOrder::XYZ,
+            order => order,
+        }
+    }
+}
+
+/// Whether the axes of a set of Euler angles move with the rotated body.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "rustc-serialize", derive(RustcEncodable, RustcDecodable))]
+#[cfg_attr(feature = "eders", derive(Serialize, Deserialize))]
+pub enum EulerFrame {
+    /// Each rotation is around an axis of the frame produced by the previous
+    /// rotations.
+    Intrinsic,
+    /// Each rotation is around an axis of the fixed, original frame.
+    Extrinsic,
+}
+
+/// A set of [Euler angles] with a configurable axis order and reference
+/// frame.
+///
+/// `first`, `second` and `third` are the angles applied around the axes of
+/// `order`, in that order. For example, the following applies an intrinsic
+/// rotation first around the _z_ axis, then the _y_ axis, and then the _x_
+/// axis:
+///
+/// ```
+/// use cgmath::{Deg, EulerFrame, EulerOrder, EulerSequence, Quaternion};
+///
+/// let rotation = Quaternion::from(EulerSequence {
+///     order: EulerOrder::ZYX,
+///     frame: EulerFrame::Intrinsic,
+///     first: Deg(30.0),
+///     second: Deg(45.0),
+///     third: Deg(10.0),
+/// });
+/// ```
+///
+/// The intrinsic XYZ sequence is equivalent to [`Euler`](struct.Euler.html).
+///
+/// # Ranges
+///
+/// When converting from another rotation type, `first` and `third` are in
+/// `[-pi, pi]`. `second` is in `[-pi/2, pi/2]` for Tait–Bryan orders, and in
+/// `[0, pi]` for proper Euler orders. In gimbal lock the third angle is set
+/// to zero.
+///
+/// [Euler angles]: https://en.wikipedia.org/wiki/Euler_angles
+#[derive(Copy, Clone, Debug)]
+#[derive(PartialEq, Eq)]
+#[cfg_attr(feature = "rustc-serialize", derive(RustcEncodable, RustcDecodable))]
+#[cfg_attr(feature = "eders", derive(Serialize, Deserialize))]
+pub struct EulerSequence<A: Angle> {
+    /// The axes to rotate around.
+    pub order: EulerOrder,
+    /// The frame that the axes are taken from.
+    pub frame: EulerFrame,
+    /// The angle to apply around the first axis.
+    pub first: A,
+    /// The angle to apply around the second axis.
+    pub second: A,
+    /// The angle to apply around the third axis.
+    pub third: A,
+}
+
+impl<A: Angle> EulerSequence<A> {
+    /// Construct a sequence of intrinsic rotations around the axes of `order`.
+    pub fn intrinsic(order: EulerOrder, first: A, second: A, third: A) -> EulerSequence<A> {
+        EulerSequence { order: order, frame: EulerFrame::Intrinsic, first: first, second: second, third: third }
+    }
+
+    /// Construct a sequence of extrinsic rotations around the axes of `order`.
+    pub fn extrinsic(order: EulerOrder, first: A, second: A, third: A) -> EulerSequence<A> {
+        EulerSequence { order: order, frame: EulerFrame::Extrinsic, first: first, second: second, third: third }
+    }
+
+    /// Express the same rotation as a sequence in the other frame.
+    pub fn to_frame(self, frame: EulerFrame) -> EulerSequence<A> {
+        if frame == self.frame {
+            self
+        } else {
+            EulerSequence {
+                order: self.order.reverse(),
+                frame: frame,
+                first: self.third,
+                second: self.second,
+                third: self.first,
+            }
+        }
+    }
+}
+
+impl<S: BaseFloat> EulerSequence<Rad<S>> {
+    /// Find the angles that reproduce the rotation, using the given order and
+    /// frame. This accepts `Matrix3`, `Basis3` and `Quaternion` rotations.
+    pub fn from_rotation<R: Into<Matrix3<S>>>(rotation: R, order: EulerOrder, frame: EulerFrame)
+                                              -> EulerSequence<Rad<S>> {
+        let intrinsic_order = match frame {
+            EulerFrame::Intrinsic => order,
+            EulerFrame::Extrinsic => order.reverse(),
+        };
+        // In gimbal lock, zero the angle that ends up being called `third`.
+        let (a, b, c) = intrinsic_angles(rotation.into(), intrinsic_order, frame == EulerFrame::Extrinsic);
+        EulerSequence::intrinsic(intrinsic_order, a, b, c).to_frame(frame)
+    }
+
+    /// Find the angles that reproduce the rotation part of a `Matrix4`, using
+    /// the given order and frame.
+    pub fn from_matrix4(m: Matrix4<S>, order: EulerOrder, frame: EulerFrame) -> EulerSequence<Rad<S>> {
+        let rotation = Matrix3::from_cols(m.x.truncate(), m.y.truncate(), m.z.truncate());
+        EulerSequence::from_rotation(rotation, order, frame)
+    }
+}
+
+/// Extract the angles `(a, b, c)` such that `m = R_i(a) * R_j(b) * R_k(c)`,
+/// where `[i, j, k]` are the axes of `order`. In gimbal lock only the sum or
+/// difference of `a` and `c` is known, so one of them is set to zero.
+fn intrinsic_angles<S: BaseFloat>(m: Matrix3<S>, order: EulerOrder, lock_first: bool)
+                                  -> (Rad<S>, Rad<S>, Rad<S>) {
+    let axes = order.axes();
+    // The axis that is not the first or second one, and the sign of the
+    // permutation `[i, j, k]`, which flips the off-diagonal terms.
+    let (i, j, k) = (axes[0], axes[1], 3 - axes[0] - axes[1]);
+    let s = if (j + 3 - i) % 3 == 1 { S::one() } else { -S::one() };
+    // `m[col][row]`
+    let at = |row: usize, col: usize| m[col][row];
+    let gimbal_epsilon = S::default_epsilon().sqrt();
+
+    if order.is_tait_bryan() {
+        let cos_b = (at(i, i) * at(i, i) + at(i, j) * at(i, j)).sqrt();
+        let b = Rad::atan2(s * at(i, k), cos_b);
+        if cos_b > gimbal_epsilon {
+            (Rad::atan2(-s * at(j, k), at(k, k)), b, Rad::atan2(-s * at(i, j), at(i, i)))
+        } else if lock_first {
+            (Rad::zero(), b, Rad::atan2(s * at(j, i), at(j, j)))
+        } else {
+            (Rad::atan2(s * at(k, j), at(j, j)), b, Rad::zero())
+        }
+    } else {
+        let sin_b = (at(i, j) * at(i, j) + at(i, k) * at(i, k)).sqrt();
+        let b = Rad::atan2(sin_b, at(i, i));
+        if sin_b > gimbal_epsilon {
+            (Rad::atan2(at(j, i), -s * at(k, i)), b, Rad::atan2(at(i, j), s * at(i, k)))
+        } else if lock_first {
+            (Rad::zero(), b, Rad::atan2(-s * at(j, k), at(j, j)))
+        } else {
+            (Rad::atan2(s * at(k, j), at(j, j)), b, Rad::zero())
+        }
+    }
+}
+
+impl<A: Angle> From<Euler<A>> for EulerSequence<A> {
+    fn from(src: Euler<A>) -> EulerSequence<A> {
+        EulerSequence::intrinsic(EulerOrder::XYZ, src.x, src.y, src.z)
+    }
+}
+
+impl<A: Angle> ApproxEq for EulerSequence<A> {
+    type Epsilon = A::Epsilon;
+
+    #[inline]
+    fn default_epsilon() -> A::Epsilon {
+        A::default_epsilon()
+    }
+
+    #[inline]
+    fn default_max_relative() -> A::Epsilon {
+        A::default_max_relative()
+    }
+
+    #[inline]
+    fn default_max_ulps() -> u32 {
+        A::default_max_ulps()
+    }
+
+    #[inline]
+    fn relative_eq(&self, other: &Self, epsilon: A::Epsilon, max_relative: A::Epsilon) -> bool {
+        self.order == other.order && self.frame == other.frame &&
+        A::relative_eq(&self.first, &other.first, epsilon, max_relative) &&
+        A::relative_eq(&self.second, &other.second, epsilon, max_relative) &&
+        A::relative_eq(&self.third, &other.third, epsilon, max_relative)
+    }
+
+    #[inline]
+    fn ulps_eq(&self, other: &Self, epsilon: A::Epsilon, max_ulps: u32) -> bool {
+        self.order == other.order && self.frame == other.frame &&
+        A::ulps_eq(&self.first, &other.first, epsilon, max_ulps) &&
+        A::ulps_eq(&self.second, &other.second, epsilon, max_ulps) &&
+        A::ulps_eq(&self.third, &other.third, epsilon, max_ulps)
+    }
+}
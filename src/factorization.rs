@@ -0,0 +1,312 @@
+// Copyright 2016 The CGMath Developers. For a full listing of the authors,
+// refer to the Cargo.toml file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! LU, QR and Cholesky factorizations of square matrices, for solving linear
+//! systems without forming an explicit inverse.
+//!
+//! Matrices are indexed as `m[column][row]` throughout, so the element in row
+//! `i` and column `j` is `m[j][i]`.
+
+use structure::*;
+
+use matrix::{Matrix2, Matrix3, Matrix4};
+use num::BaseFloat;
+use svd::sigma_tolerance;
+use vector::{Vector2, Vector3, Vector4};
+
+/// An LU factorization with partial pivoting, `p * a = l * u`, where `p` is
+/// a permutation matrix, `l` is lower triangular with a unit diagonal, and
+/// `u` is upper triangular.
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "rustc-serialize", derive(RustcEncodable, RustcDecodable))]
+#[cfg_attr(feature = "eders", derive(Serialize, Deserialize))]
+pub struct Lu<M> {
+    pub l: M,
+    pub u: M,
+    pub p: M,
+}
+
+/// A QR factorization, `a = q * r`, where `q` is orthogonal and `r` is upper
+/// triangular.
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "rustc-serialize", derive(RustcEncodable, RustcDecodable))]
+#[cfg_attr(feature = "eders", derive(Serialize, Deserialize))]
+pub struct Qr<M> {
+    pub q: M,
+    pub r: M,
+}
+
+/// A Cholesky factorization of a symmetric positive-definite matrix,
+/// `a = l * lᵀ`, where `l` is lower triangular with a positive diagonal.
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "rustc-serialize", derive(RustcEncodable, RustcDecodable))]
+#[cfg_attr(feature = "eders", derive(Serialize, Deserialize))]
+pub struct Cholesky<M> {
+    pub l: M,
+}
+
+/// The largest magnitude of any element of `m`.
+fn max_abs<S, M>(m: &M, n: usize) -> S where
+    S: BaseFloat,
+    M: SquareMatrix + VectorSpace<Scalar = S>,
+{
+    let mut max = S::zero();
+    for j in 0..n {
+        for i in 0..n {
+            max = max.max(m[j][i].abs());
+        }
+    }
+    max
+}
+
+/// Whether the triangular factor `t` is singular to working precision, that
+/// is, whether an element of its diagonal is negligible next to its largest
+/// element.
+fn is_singular<S, M>(t: &M, n: usize) -> bool where
+    S: BaseFloat,
+    M: SquareMatrix + VectorSpace<Scalar = S>,
+{
+    let tolerance = sigma_tolerance(max_abs(t, n), n);
+    (0..n).any(|i| relative_eq!(t[i][i], &S::zero(), epsilon = tolerance))
+}
+
+fn lu<S, M>(m: M, n: usize) -> Lu<M> where
+    S: BaseFloat,
+    M: SquareMatrix + VectorSpace<Scalar = S>,
+{
+    let tolerance = sigma_tolerance(max_abs(&m, n), n);
+    let mut a = m;
+    let mut p = M::identity();
+    for k in 0..n {
+        // Use the largest remaining element of the column as the pivot.
+        let mut pivot = k;
+        for i in k + 1..n {
+            if a[k][i].abs() > a[k][pivot].abs() {
+                pivot = i;
+            }
+        }
+        if pivot != k {
+            a.swap_rows(k, pivot);
+            p.swap_rows(k, pivot);
+        }
+
+        let d = a[k][k];
+        if relative_eq!(d, &S::zero(), epsilon = tolerance) {
+            continue;
+        }
+        for i in k + 1..n {
+            a[k][i] = a[k][i] / d;
+            for j in k + 1..n {
+                a[j][i] = a[j][i] - a[k][i] * a[j][k];
+            }
+        }
+    }
+
+    // Split the multipliers below the diagonal from the upper triangle.
+    let mut l = M::identity();
+    let mut u = M::zero();
+    for j in 0..n {
+        for i in 0..n {
+            if i > j { l[j][i] = a[j][i]; } else { u[j][i] = a[j][i]; }
+        }
+    }
+    Lu { l: l, u: u, p: p }
+}
+
+fn qr<S, M>(m: M, n: usize) -> Qr<M> where
+    S: BaseFloat,
+    M: SquareMatrix + VectorSpace<Scalar = S>,
+    M::ColumnRow: InnerSpace<Scalar = S>,
+{
+    let two = S::one() + S::one();
+    let tolerance = sigma_tolerance(max_abs(&m, n), n);
+    let mut r = m;
+    let mut q = M::identity();
+    for k in 0..n - 1 {
+        // The Householder reflection that maps the column below the diagonal
+        // onto the diagonal, choosing the sign that avoids cancellation.
+        let mut v = M::ColumnRow::zero();
+        for i in k..n {
+            v[i] = r[k][i];
+        }
+        let norm = v.magnitude();
+        if relative_eq!(norm, &S::zero(), epsilon = tolerance) {
+            continue;
+        }
+        v[k] = if v[k] > S::zero() { v[k] + norm } else { v[k] - norm };
+        let scale = two / v.magnitude2();
+
+        for j in 0..n {
+            let f = v.dot(r[j]) * scale;
+            r[j] = r[j] - v * f;
+        }
+        let qv = q * v;
+        for j in 0..n {
+            q[j] = q[j] - qv * (v[j] * scale);
+        }
+    }
+
+    for j in 0..n {
+        for i in j + 1..n {
+            r[j][i] = S::zero();
+        }
+    }
+    Qr { q: q, r: r }
+}
+
+fn cholesky<S, M>(m: M, n: usize) -> Option<Cholesky<M>> where
+    S: BaseFloat,
+    M: SquareMatrix + VectorSpace<Scalar = S>,
+{
+    let mut l = M::zero();
+    for j in 0..n {
+        let mut d = m[j][j];
+        for k in 0..j {
+            d = d - l[k][j] * l[k][j];
+        }
+        if !(d > S::zero()) {
+            return None;
+        }
+        l[j][j] = d.sqrt();
+
+        for i in j + 1..n {
+            let mut x = m[j][i];
+            for k in 0..j {
+                x = x - l[k][i] * l[k][j];
+            }
+            l[j][i] = x / l[j][j];
+        }
+    }
+    Some(Cholesky { l: l })
+}
+
+/// Solve `l * x = b` for a lower triangular `l`, dividing by the diagonal
+/// unless `unit_diagonal` is set.
+fn forward_substitute<S, M>(l: &M, b: M::ColumnRow, n: usize, unit_diagonal: bool) -> Option<M::ColumnRow> where
+    S: BaseFloat,
+    M: SquareMatrix + VectorSpace<Scalar = S>,
+{
+    let mut x = b;
+    for i in 0..n {
+        for j in 0..i {
+            x[i] = x[i] - l[j][i] * x[j];
+        }
+        if !unit_diagonal {
+            if l[i][i].is_zero() {
+                return None;
+            }
+            x[i] = x[i] / l[i][i];
+        }
+    }
+    Some(x)
+}
+
+/// Solve `u * x = b` for an upper triangular `u`.
+fn back_substitute<S, M>(u: &M, b: M::ColumnRow, n: usize) -> Option<M::ColumnRow> where
+    S: BaseFloat,
+    M: SquareMatrix + VectorSpace<Scalar = S>,
+{
+    let mut x = b;
+    for i in (0..n).rev() {
+        for j in i + 1..n {
+            x[i] = x[i] - u[j][i] * x[j];
+        }
+        if u[i][i].is_zero() {
+            return None;
+        }
+        x[i] = x[i] / u[i][i];
+    }
+    Some(x)
+}
+
+macro_rules! impl_factorizations {
+    ($MatrixN:ident, $VectorN:ident, $n:expr) => {
+        impl<S: BaseFloat> $MatrixN<S> {
+            /// The LU factorization of the matrix, with partial pivoting.
+            #[inline]
+            pub fn lu(&self) -> Lu<$MatrixN<S>> {
+                lu(*self, $n)
+            }
+
+            /// The QR factorization of the matrix, computed with Householder
+            /// reflections.
+            #[inline]
+            pub fn qr(&self) -> Qr<$MatrixN<S>> {
+                qr(*self, $n)
+            }
+
+            /// The Cholesky factorization of the matrix, which must be
+            /// symmetric. Only the lower triangle of the matrix is read.
+            ///
+            /// Returns `None` if the matrix is not positive-definite.
+            #[inline]
+            pub fn cholesky(&self) -> Option<Cholesky<$MatrixN<S>>> {
+                cholesky(*self, $n)
+            }
+        }
+
+        impl<S: BaseFloat> Lu<$MatrixN<S>> {
+            /// Solve `a * x = b` for `x`.
+            ///
+            /// Returns `None` if the factorized matrix is singular, or so
+            /// nearly singular that the solution would be meaningless.
+            pub fn solve(&self, b: $VectorN<S>) -> Option<$VectorN<S>> {
+                if is_singular(&self.u, $n) {
+                    return None;
+                }
+                let y = forward_substitute(&self.l, self.p * b, $n, true).unwrap();
+                back_substitute(&self.u, y, $n)
+            }
+
+            /// The determinant of the factorized matrix.
+            #[inline]
+            pub fn determinant(&self) -> S {
+                self.p.determinant() * self.u.diagonal().product()
+            }
+        }
+
+        impl<S: BaseFloat> Qr<$MatrixN<S>> {
+            /// Solve `a * x = b` for `x`.
+            ///
+            /// Returns `None` if the factorized matrix is singular, or so
+            /// nearly singular that the solution would be meaningless.
+            pub fn solve(&self, b: $VectorN<S>) -> Option<$VectorN<S>> {
+                if is_singular(&self.r, $n) {
+                    return None;
+                }
+                back_substitute(&self.r, self.q.transpose() * b, $n)
+            }
+        }
+
+        impl<S: BaseFloat> Cholesky<$MatrixN<S>> {
+            /// Solve `a * x = b` for `x`.
+            ///
+            /// Returns `None` if the factorized matrix is so nearly singular
+            /// that the solution would be meaningless, or if `l` has been
+            /// modified to have a zero on its diagonal.
+            pub fn solve(&self, b: $VectorN<S>) -> Option<$VectorN<S>> {
+                if is_singular(&self.l, $n) {
+                    return None;
+                }
+                let y = match forward_substitute(&self.l, b, $n, false) { Some(y) => y, None => return None };
+                back_substitute(&self.l.transpose(), y, $n)
+            }
+        }
+    }
+}
+
+impl_factorizations!(Matrix2, Vector2, 2);
+impl_factorizations!(Matrix3, Vector3, 3);
+impl_factorizations!(Matrix4, Vector4, 4);
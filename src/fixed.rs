@@ -0,0 +1,796 @@
+// Copyright 2016 The CGMath Developers. For a full listing of the authors,
+// refer to the Cargo.toml file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Deterministic fixed-point scalars.
+//!
+//! Every operation, including the trigonometric and exponential functions, is
+//! computed with integer arithmetic, so results are bit-identical on every
+//! platform. This module is only compiled with the `fixed-point` feature,
+//! which requires Rust 1.38 or later.
+//!
+//! The transcendental functions are evaluated in an intermediate format with
+//! 60 fractional bits, held in an `i128`, and rounded to the scalar's
+//! precision at the end.
+
+use approx::ApproxEq;
+use num_traits::{Float, FloatErrorKind, Num, NumCast, One, ParseFloatError, ToPrimitive, Zero};
+use std::any;
+use std::cmp;
+use std::fmt;
+use std::mem;
+use std::num::FpCategory;
+use std::ops::*;
+
+use num::{BaseFloat, BaseNum, PartialOrd};
+
+/// The number of fractional bits in the intermediate format.
+const Q: u32 = 60;
+const ONE: i128 = 1 << Q;
+
+/// π/2, split into a rounded value and the rounding error in units of
+/// `2^-120`, for accurate reduction of large angles.
+const FRAC_PI_2: i128 = 1811004864519280711;
+const FRAC_PI_2_LO: i128 = -465686308333701062;
+const PI: i128 = 3622009729038561421;
+const LN_2: i128 = 799144290325165979;
+const LN_10: i128 = 2654699869899991814;
+const DEG_PER_RAD: i128 = 66057536323845027596;
+const RAD_PER_DEG: i128 = 20122276272436452;
+
+/// Larger than either scalar type can hold, standing in for infinity.
+const HUGE: i128 = 1 << 100;
+
+/// Division rounding towards negative infinity, for a positive divisor.
+fn div_floor(a: i128, b: i128) -> i128 {
+    let q = a / b;
+    if a % b < 0 { q - 1 } else { q }
+}
+
+/// The product of two intermediate values, rounded to nearest. Products
+/// that overflow saturate to `HUGE`.
+fn mul(a: i128, b: i128) -> i128 {
+    match a.checked_mul(b).and_then(|p| p.checked_add(1 << (Q - 1))) {
+        Some(p) => p >> Q,
+        None => if (a < 0) == (b < 0) { HUGE } else { -HUGE },
+    }
+}
+
+/// The quotient of two intermediate values. `a` must be less than `2^66` in
+/// magnitude.
+fn div(a: i128, b: i128) -> i128 {
+    (a << Q) / b
+}
+
+/// `a / b` for `0 <= a <= b`, for operands of any size.
+fn ratio(a: i128, b: i128) -> i128 {
+    let shift = (128 - b.leading_zeros()).saturating_sub(66);
+    div(a >> shift, b >> shift)
+}
+
+/// The raw representation of `n` and its number of fractional bits, if it is
+/// a `Fixed32` or `Fixed64`, so that it can be cast exactly rather than
+/// through an `f64`, which cannot hold every `Fixed64`.
+fn fixed_bits<T>(n: &T) -> Option<(i128, u32)> {
+    let name = any::type_name::<T>();
+    if mem::size_of::<T>() == mem::size_of::<Fixed32>() && name == any::type_name::<Fixed32>() {
+        // `T` is `Fixed32`, so this only changes the name of the type.
+        let n: Fixed32 = unsafe { mem::transmute_copy(n) };
+        Some((n.0 as i128, Fixed32::FRAC))
+    } else if mem::size_of::<T>() == mem::size_of::<Fixed64>() && name == any::type_name::<Fixed64>() {
+        let n: Fixed64 = unsafe { mem::transmute_copy(n) };
+        Some((n.0 as i128, Fixed64::FRAC))
+    } else {
+        None
+    }
+}
+
+/// The integer square root, rounded to nearest.
+fn isqrt(v: u128) -> u128 {
+    let mut rem = v;
+    let mut root = 0;
+    let mut bit = 1 << 126;
+    while bit > v {
+        bit >>= 2;
+    }
+    while bit != 0 {
+        if rem >= root + bit {
+            rem -= root + bit;
+            root = (root >> 1) + bit;
+        } else {
+            root >>= 1;
+        }
+        bit >>= 2;
+    }
+    if rem > root { root + 1 } else { root }
+}
+
+/// The square root of a non-negative intermediate value below `2^68`.
+fn sqrt(a: i128) -> i128 {
+    isqrt((a as u128) << Q) as i128
+}
+
+fn sin_cos(x: i128) -> (i128, i128) {
+    // Reduce to `[-π/4, π/4]`, keeping the quadrant
+    let k = div_floor(x + FRAC_PI_2 / 2, FRAC_PI_2);
+    let r = x - k * FRAC_PI_2 - ((k * FRAC_PI_2_LO + (1 << (Q - 1))) >> Q);
+
+    let r2 = mul(r, r);
+    let (mut s, mut c) = (r, ONE);
+    let (mut ts, mut tc) = (r, ONE);
+    let mut n = 1;
+    while ts != 0 || tc != 0 {
+        tc = -mul(tc, r2) / ((2 * n - 1) * (2 * n));
+        ts = -mul(ts, r2) / ((2 * n) * (2 * n + 1));
+        s += ts;
+        c += tc;
+        n += 1;
+    }
+
+    match k & 3 {
+        0 => (s, c),
+        1 => (c, -s),
+        2 => (-s, -c),
+        _ => (-c, s),
+    }
+}
+
+fn atan(x: i128) -> i128 {
+    if x < 0 {
+        return -atan(-x);
+    }
+    if x > ONE {
+        return FRAC_PI_2 - atan(div(ONE, x));
+    }
+
+    // Halve the angle twice, with `tan(a / 2) = t / (1 + sqrt(1 + t^2))`, so
+    // that the series converges quickly
+    let mut t = x;
+    for _ in 0..2 {
+        t = div(t, ONE + sqrt(ONE + mul(t, t)));
+    }
+
+    let t2 = mul(t, t);
+    let (mut sum, mut term) = (t, t);
+    let mut n = 3;
+    while term != 0 {
+        term = -mul(term, t2);
+        sum += term / n;
+        n += 2;
+    }
+    4 * sum
+}
+
+fn atan2(y: i128, x: i128) -> i128 {
+    let (ay, ax) = (y.abs(), x.abs());
+    if ax == 0 && ay == 0 {
+        return 0;
+    }
+
+    let a = if ay <= ax {
+        atan(ratio(ay, ax))
+    } else {
+        FRAC_PI_2 - atan(ratio(ax, ay))
+    };
+    let a = if x < 0 { PI - a } else { a };
+    if y < 0 { -a } else { a }
+}
+
+fn exp(x: i128) -> i128 {
+    // `e^44` is beyond the range of either scalar, and `e^-44` rounds to zero
+    if x > 44 * ONE {
+        return HUGE;
+    }
+    if x < -44 * ONE {
+        return 0;
+    }
+
+    let k = div_floor(x + LN_2 / 2, LN_2);
+    let r = x - k * LN_2;
+
+    let (mut sum, mut term) = (ONE, ONE);
+    let mut n = 1;
+    while term != 0 {
+        term = mul(term, r) / n;
+        sum += term;
+        n += 1;
+    }
+
+    if k >= 0 {
+        sum << k
+    } else {
+        (sum + (1 << (-k - 1))) >> -k
+    }
+}
+
+/// The natural logarithm of a positive intermediate value.
+fn ln(x: i128) -> i128 {
+    // Split into `m * 2^k`, with `m` in `[1, 2)`
+    let k = 127 - x.leading_zeros() as i128 - Q as i128;
+    let m = if k >= 0 { x >> k } else { x << -k };
+
+    // ln(m) = 2 atanh((m - 1) / (m + 1))
+    let z = div(m - ONE, m + ONE);
+    let z2 = mul(z, z);
+    let (mut sum, mut term) = (z, z);
+    let mut n = 3;
+    while term != 0 {
+        term = mul(term, z2);
+        sum += term / n;
+        n += 2;
+    }
+    k * LN_2 + 2 * sum
+}
+
+macro_rules! impl_fixed {
+    ($Fixed:ident, $S:ident, $Wide:ident, $frac:expr, $q:expr) => {
+        #[doc = $q]
+        ///
+        /// Arithmetic saturates at the bounds of the type, which stand in for
+        /// the infinities. There is no `NaN`: operations that would produce
+        /// one, such as `0 / 0` or the square root of a negative number,
+        /// return zero instead.
+        #[derive(Copy, Clone, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+        #[cfg_attr(feature = "rustc-serialize", derive(RustcEncodable, RustcDecodable))]
+        #[cfg_attr(feature = "eders", derive(Serialize, Deserialize))]
+        pub struct $Fixed($S);
+
+        impl $Fixed {
+            const FRAC: u32 = $frac;
+            const ONE: $S = 1 << $frac;
+
+            /// Create a value from its raw representation, in units of the
+            /// smallest positive value.
+            #[inline]
+            pub const fn from_bits(bits: $S) -> $Fixed {
+                $Fixed(bits)
+            }
+
+            /// The raw representation of this value.
+            #[inline]
+            pub const fn to_bits(self) -> $S {
+                self.0
+            }
+
+            #[inline]
+            fn saturate(v: $Wide) -> $Fixed {
+                if v > $S::max_value() as $Wide {
+                    $Fixed($S::max_value())
+                } else if v < $S::min_value() as $Wide {
+                    $Fixed($S::min_value())
+                } else {
+                    $Fixed(v as $S)
+                }
+            }
+
+            #[inline]
+            fn to_q(self) -> i128 {
+                (self.0 as i128) << (Q - Self::FRAC)
+            }
+
+            fn from_q(v: i128) -> $Fixed {
+                let shift = Q - Self::FRAC;
+                let v = (v + (1 << (shift - 1))) >> shift;
+                if v > $S::max_value() as i128 {
+                    $Fixed($S::max_value())
+                } else if v < $S::min_value() as i128 {
+                    $Fixed($S::min_value())
+                } else {
+                    $Fixed(v as $S)
+                }
+            }
+
+            /// This value in the intermediate format, or `None` if it is
+            /// outside `[-1, 1]`.
+            fn unit_q(self) -> Option<i128> {
+                let x = self.to_q();
+                if x.abs() > ONE { None } else { Some(x) }
+            }
+        }
+
+        impl fmt::Debug for $Fixed {
+            fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                fmt::Debug::fmt(&self.to_f64().unwrap(), f)
+            }
+        }
+
+        impl fmt::Display for $Fixed {
+            fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                fmt::Display::fmt(&self.to_f64().unwrap(), f)
+            }
+        }
+
+        impl Neg for $Fixed {
+            type Output = $Fixed;
+
+            #[inline]
+            fn neg(self) -> $Fixed {
+                $Fixed(self.0.checked_neg().unwrap_or($S::max_value()))
+            }
+        }
+
+        impl Add for $Fixed {
+            type Output = $Fixed;
+
+            #[inline]
+            fn add(self, other: $Fixed) -> $Fixed {
+                $Fixed(self.0.saturating_add(other.0))
+            }
+        }
+
+        impl Sub for $Fixed {
+            type Output = $Fixed;
+
+            #[inline]
+            fn sub(self, other: $Fixed) -> $Fixed {
+                $Fixed(self.0.saturating_sub(other.0))
+            }
+        }
+
+        impl Mul for $Fixed {
+            type Output = $Fixed;
+
+            /// The product, rounded to nearest.
+            #[inline]
+            fn mul(self, other: $Fixed) -> $Fixed {
+                let p = self.0 as $Wide * other.0 as $Wide;
+                $Fixed::saturate((p + (1 << ($frac - 1))) >> $frac)
+            }
+        }
+
+        impl Div for $Fixed {
+            type Output = $Fixed;
+
+            /// The quotient, rounded to nearest. Dividing a non-zero value by
+            /// zero saturates.
+            fn div(self, other: $Fixed) -> $Fixed {
+                if other.0 == 0 {
+                    return match self.0.cmp(&0) {
+                        cmp::Ordering::Greater => $Fixed($S::max_value()),
+                        cmp::Ordering::Less => $Fixed($S::min_value()),
+                        cmp::Ordering::Equal => $Fixed(0),
+                    };
+                }
+
+                let (a, b) = ((self.0 as $Wide) << $frac, other.0 as $Wide);
+                let (q, r) = (a / b, a % b);
+                let q = if 2 * r.abs() >= b.abs() {
+                    if (a < 0) == (b < 0) { q + 1 } else { q - 1 }
+                } else {
+                    q
+                };
+                $Fixed::saturate(q)
+            }
+        }
+
+        impl Rem for $Fixed {
+            type Output = $Fixed;
+
+            #[inline]
+            fn rem(self, other: $Fixed) -> $Fixed {
+                if other.0 == 0 { $Fixed(0) } else { $Fixed(self.0.wrapping_rem(other.0)) }
+            }
+        }
+
+        impl AddAssign for $Fixed {
+            #[inline]
+            fn add_assign(&mut self, other: $Fixed) { *self = *self + other; }
+        }
+
+        impl SubAssign for $Fixed {
+            #[inline]
+            fn sub_assign(&mut self, other: $Fixed) { *self = *self - other; }
+        }
+
+        impl MulAssign for $Fixed {
+            #[inline]
+            fn mul_assign(&mut self, other: $Fixed) { *self = *self * other; }
+        }
+
+        impl DivAssign for $Fixed {
+            #[inline]
+            fn div_assign(&mut self, other: $Fixed) { *self = *self / other; }
+        }
+
+        impl RemAssign for $Fixed {
+            #[inline]
+            fn rem_assign(&mut self, other: $Fixed) { *self = *self % other; }
+        }
+
+        impl Zero for $Fixed {
+            #[inline]
+            fn zero() -> $Fixed { $Fixed(0) }
+
+            #[inline]
+            fn is_zero(&self) -> bool { self.0 == 0 }
+        }
+
+        impl One for $Fixed {
+            #[inline]
+            fn one() -> $Fixed { $Fixed(Self::ONE) }
+        }
+
+        impl Num for $Fixed {
+            type FromStrRadixErr = ParseFloatError;
+
+            fn from_str_radix(s: &str, radix: u32) -> Result<$Fixed, ParseFloatError> {
+                let v = try!(<f64 as Num>::from_str_radix(s, radix));
+                <$Fixed as NumCast>::from(v).ok_or(ParseFloatError { kind: FloatErrorKind::Invalid })
+            }
+        }
+
+        impl ToPrimitive for $Fixed {
+            /// The integer part, truncated towards zero.
+            #[inline]
+            fn to_i64(&self) -> Option<i64> {
+                Some((self.0 / Self::ONE) as i64)
+            }
+
+            #[inline]
+            fn to_u64(&self) -> Option<u64> {
+                let i = (self.0 / Self::ONE) as i64;
+                if i < 0 { None } else { Some(i as u64) }
+            }
+
+            #[inline]
+            fn to_f64(&self) -> Option<f64> {
+                Some(self.0 as f64 / Self::ONE as f64)
+            }
+        }
+
+        impl NumCast for $Fixed {
+            /// Convert a number, rounding to the nearest representable value.
+            /// Returns `None` if it is out of range.
+            ///
+            /// Fixed-point values are converted from their raw representation,
+            /// so casts between `Fixed32` and `Fixed64` are exact wherever the
+            /// target can hold the value, and otherwise round half up.
+            fn from<T: ToPrimitive>(n: T) -> Option<$Fixed> {
+                if let Some((bits, frac)) = fixed_bits(&n) {
+                    let v = if frac <= Self::FRAC {
+                        bits << (Self::FRAC - frac)
+                    } else {
+                        let shift = frac - Self::FRAC;
+                        (bits + (1 << (shift - 1))) >> shift
+                    };
+                    let in_range = v >= $S::min_value() as i128 && v <= $S::max_value() as i128;
+                    return if in_range { Some($Fixed(v as $S)) } else { None };
+                }
+
+                let v = match n.to_f64() {
+                    Some(v) => (v * Self::ONE as f64).round(),
+                    None => return None,
+                };
+                // `2^(bits - 1)`, exactly
+                let bound = -($S::min_value() as f64);
+                if v >= -bound && v < bound { Some($Fixed(v as $S)) } else { None }
+            }
+        }
+
+        impl PartialOrd for $Fixed {
+            #[inline]
+            fn partial_min(self, other: $Fixed) -> $Fixed { cmp::min(self, other) }
+            #[inline]
+            fn partial_max(self, other: $Fixed) -> $Fixed { cmp::max(self, other) }
+        }
+
+        impl Float for $Fixed {
+            /// There is no `NaN`, so this returns zero.
+            #[inline] fn nan() -> $Fixed { $Fixed(0) }
+            #[inline] fn infinity() -> $Fixed { $Fixed($S::max_value()) }
+            #[inline] fn neg_infinity() -> $Fixed { $Fixed($S::min_value()) }
+            #[inline] fn neg_zero() -> $Fixed { $Fixed(0) }
+            #[inline] fn min_value() -> $Fixed { $Fixed($S::min_value()) }
+            #[inline] fn min_positive_value() -> $Fixed { $Fixed(1) }
+            #[inline] fn epsilon() -> $Fixed { $Fixed(1) }
+            #[inline] fn max_value() -> $Fixed { $Fixed($S::max_value()) }
+
+            #[inline] fn is_nan(self) -> bool { false }
+            #[inline] fn is_infinite(self) -> bool { self.0 == $S::max_value() || self.0 == $S::min_value() }
+            #[inline] fn is_finite(self) -> bool { !self.is_infinite() }
+            #[inline] fn is_normal(self) -> bool { self.0 != 0 && self.is_finite() }
+
+            fn classify(self) -> FpCategory {
+                if self.0 == 0 {
+                    FpCategory::Zero
+                } else if self.is_infinite() {
+                    FpCategory::Infinite
+                } else {
+                    FpCategory::Normal
+                }
+            }
+
+            #[inline]
+            fn floor(self) -> $Fixed {
+                let t = self.0 - self.0 % Self::ONE;
+                if self.0 < t { $Fixed(t - Self::ONE) } else { $Fixed(t) }
+            }
+
+            #[inline]
+            fn ceil(self) -> $Fixed {
+                let t = self.0 - self.0 % Self::ONE;
+                if self.0 > t { $Fixed(t.saturating_add(Self::ONE)) } else { $Fixed(t) }
+            }
+
+            /// Round half-way cases away from zero.
+            #[inline]
+            fn round(self) -> $Fixed {
+                let f = self.0 % Self::ONE;
+                let t = self.0 - f;
+                if 2 * (f as $Wide) >= Self::ONE as $Wide {
+                    $Fixed(t.saturating_add(Self::ONE))
+                } else if 2 * (f as $Wide) <= -(Self::ONE as $Wide) {
+                    $Fixed(t - Self::ONE)
+                } else {
+                    $Fixed(t)
+                }
+            }
+
+            #[inline] fn trunc(self) -> $Fixed { $Fixed(self.0 - self.0 % Self::ONE) }
+            #[inline] fn fract(self) -> $Fixed { $Fixed(self.0 % Self::ONE) }
+            #[inline] fn abs(self) -> $Fixed { if self.0 < 0 { -self } else { self } }
+
+            /// `1` for zero and positive values, `-1` for negative values.
+            #[inline]
+            fn signum(self) -> $Fixed {
+                if self.0 < 0 { -$Fixed::one() } else { $Fixed::one() }
+            }
+
+            #[inline] fn is_sign_positive(self) -> bool { self.0 >= 0 }
+            #[inline] fn is_sign_negative(self) -> bool { self.0 < 0 }
+
+            /// `self * a + b`, with a single rounding.
+            #[inline]
+            fn mul_add(self, a: $Fixed, b: $Fixed) -> $Fixed {
+                let p = self.0 as $Wide * a.0 as $Wide + ((b.0 as $Wide) << $frac);
+                $Fixed::saturate((p + (1 << ($frac - 1))) >> $frac)
+            }
+
+            #[inline] fn recip(self) -> $Fixed { $Fixed::one() / self }
+
+            fn powi(self, n: i32) -> $Fixed {
+                let mut base = self;
+                let mut e = (n as i64).abs();
+                let mut acc = $Fixed::one();
+                while e > 0 {
+                    if e & 1 == 1 {
+                        acc = acc * base;
+                    }
+                    base = base * base;
+                    e >>= 1;
+                }
+                if n < 0 { acc.recip() } else { acc }
+            }
+
+            fn powf(self, n: $Fixed) -> $Fixed {
+                match self.0.cmp(&0) {
+                    cmp::Ordering::Greater => $Fixed::from_q(exp(mul(ln(self.to_q()), n.to_q()))),
+                    cmp::Ordering::Equal if n.0 > 0 => $Fixed(0),
+                    cmp::Ordering::Equal if n.0 < 0 => $Fixed::infinity(),
+                    cmp::Ordering::Equal => $Fixed::one(),
+                    cmp::Ordering::Less => $Fixed(0),
+                }
+            }
+
+            /// The square root, rounded to nearest.
+            fn sqrt(self) -> $Fixed {
+                if self.0 <= 0 {
+                    $Fixed(0)
+                } else {
+                    $Fixed(isqrt((self.0 as u128) << $frac) as $S)
+                }
+            }
+
+            #[inline] fn exp(self) -> $Fixed { $Fixed::from_q(exp(self.to_q())) }
+            #[inline] fn exp2(self) -> $Fixed { $Fixed::from_q(exp(mul(self.to_q(), LN_2))) }
+
+            fn ln(self) -> $Fixed {
+                match self.0.cmp(&0) {
+                    cmp::Ordering::Greater => $Fixed::from_q(ln(self.to_q())),
+                    cmp::Ordering::Equal => $Fixed::neg_infinity(),
+                    cmp::Ordering::Less => $Fixed(0),
+                }
+            }
+
+            fn log(self, base: $Fixed) -> $Fixed {
+                if self.0 <= 0 {
+                    self.ln()
+                } else if base.0 <= 0 {
+                    $Fixed(0)
+                } else if base.0 == Self::ONE {
+                    match self.0.cmp(&Self::ONE) {
+                        cmp::Ordering::Greater => $Fixed::infinity(),
+                        cmp::Ordering::Less => $Fixed::neg_infinity(),
+                        cmp::Ordering::Equal => $Fixed(0),
+                    }
+                } else {
+                    $Fixed::from_q(div(ln(self.to_q()), ln(base.to_q())))
+                }
+            }
+
+            fn log2(self) -> $Fixed {
+                if self.0 <= 0 { self.ln() } else { $Fixed::from_q(div(ln(self.to_q()), LN_2)) }
+            }
+
+            fn log10(self) -> $Fixed {
+                if self.0 <= 0 { self.ln() } else { $Fixed::from_q(div(ln(self.to_q()), LN_10)) }
+            }
+
+            #[inline] fn to_degrees(self) -> $Fixed { $Fixed::from_q(mul(self.to_q(), DEG_PER_RAD)) }
+            #[inline] fn to_radians(self) -> $Fixed { $Fixed::from_q(mul(self.to_q(), RAD_PER_DEG)) }
+
+            #[inline] fn max(self, other: $Fixed) -> $Fixed { cmp::max(self, other) }
+            #[inline] fn min(self, other: $Fixed) -> $Fixed { cmp::min(self, other) }
+
+            #[inline]
+            fn abs_sub(self, other: $Fixed) -> $Fixed {
+                if self > other { self - other } else { $Fixed(0) }
+            }
+
+            fn cbrt(self) -> $Fixed {
+                if self.0 == 0 {
+                    return $Fixed(0);
+                }
+                let r = $Fixed::from_q(exp(ln(self.abs().to_q()) / 3));
+                if self.0 < 0 { -r } else { r }
+            }
+
+            /// The length of the hypotenuse, without intermediate overflow.
+            fn hypot(self, other: $Fixed) -> $Fixed {
+                let (a, b) = ((self.0 as i128).abs() as u128, (other.0 as i128).abs() as u128);
+                $Fixed::saturate(isqrt(a * a + b * b) as $Wide)
+            }
+
+            #[inline] fn sin(self) -> $Fixed { $Fixed::from_q(sin_cos(self.to_q()).0) }
+            #[inline] fn cos(self) -> $Fixed { $Fixed::from_q(sin_cos(self.to_q()).1) }
+
+            fn tan(self) -> $Fixed {
+                let (s, c) = sin_cos(self.to_q());
+                if c == 0 {
+                    if s > 0 { $Fixed::infinity() } else { $Fixed::neg_infinity() }
+                } else {
+                    $Fixed::from_q(div(s, c))
+                }
+            }
+
+            fn asin(self) -> $Fixed {
+                match self.unit_q() {
+                    Some(x) => $Fixed::from_q(atan2(x, sqrt(ONE - mul(x, x)))),
+                    None => $Fixed(0),
+                }
+            }
+
+            fn acos(self) -> $Fixed {
+                match self.unit_q() {
+                    Some(x) => $Fixed::from_q(atan2(sqrt(ONE - mul(x, x)), x)),
+                    None => $Fixed(0),
+                }
+            }
+
+            #[inline] fn atan(self) -> $Fixed { $Fixed::from_q(atan(self.to_q())) }
+            #[inline] fn atan2(self, other: $Fixed) -> $Fixed { $Fixed::from_q(atan2(self.to_q(), other.to_q())) }
+
+            #[inline]
+            fn sin_cos(self) -> ($Fixed, $Fixed) {
+                let (s, c) = sin_cos(self.to_q());
+                ($Fixed::from_q(s), $Fixed::from_q(c))
+            }
+
+            #[inline] fn exp_m1(self) -> $Fixed { $Fixed::from_q(exp(self.to_q()) - ONE) }
+
+            fn ln_1p(self) -> $Fixed {
+                let x = self.to_q() + ONE;
+                match x.cmp(&0) {
+                    cmp::Ordering::Greater => $Fixed::from_q(ln(x)),
+                    cmp::Ordering::Equal => $Fixed::neg_infinity(),
+                    cmp::Ordering::Less => $Fixed(0),
+                }
+            }
+
+            fn sinh(self) -> $Fixed {
+                let x = self.to_q();
+                $Fixed::from_q((exp(x) - exp(-x)) / 2)
+            }
+
+            fn cosh(self) -> $Fixed {
+                let x = self.to_q();
+                $Fixed::from_q((exp(x) + exp(-x)) / 2)
+            }
+
+            fn tanh(self) -> $Fixed {
+                // Using `e^-2|x|`, which cannot overflow
+                let e = exp(-2 * self.to_q().abs());
+                let r = $Fixed::from_q(div(ONE - e, ONE + e));
+                if self.0 < 0 { -r } else { r }
+            }
+
+            fn asinh(self) -> $Fixed {
+                let x = self.to_q().abs();
+                // Factor out `x` when it is large, so `x^2` cannot overflow
+                let r = if x > ONE {
+                    let t = div(ONE, x);
+                    ln(x) + ln(ONE + sqrt(ONE + mul(t, t)))
+                } else {
+                    ln(x + sqrt(mul(x, x) + ONE))
+                };
+                if self.0 < 0 { -$Fixed::from_q(r) } else { $Fixed::from_q(r) }
+            }
+
+            fn acosh(self) -> $Fixed {
+                let x = self.to_q();
+                if x < ONE {
+                    $Fixed(0)
+                } else {
+                    let t = div(ONE, x);
+                    $Fixed::from_q(ln(x) + ln(ONE + sqrt(ONE - mul(t, t))))
+                }
+            }
+
+            fn atanh(self) -> $Fixed {
+                match self.unit_q() {
+                    Some(x) if x == ONE => $Fixed::infinity(),
+                    Some(x) if x == -ONE => $Fixed::neg_infinity(),
+                    Some(x) => $Fixed::from_q(ln(div(ONE + x, ONE - x)) / 2),
+                    None => $Fixed(0),
+                }
+            }
+
+            #[inline]
+            fn integer_decode(self) -> (u64, i16, i8) {
+                let sign = if self.0 < 0 { -1 } else { 1 };
+                ((self.0 as i128).abs() as u64, -($frac as i16), sign)
+            }
+        }
+
+        impl ApproxEq for $Fixed {
+            type Epsilon = $Fixed;
+
+            #[inline]
+            fn default_epsilon() -> $Fixed {
+                $Fixed(1)
+            }
+
+            #[inline]
+            fn default_max_relative() -> $Fixed {
+                $Fixed(1)
+            }
+
+            #[inline]
+            fn default_max_ulps() -> u32 {
+                4
+            }
+
+            fn relative_eq(&self, other: &$Fixed, epsilon: $Fixed, max_relative: $Fixed) -> bool {
+                let diff = (self.0 as $Wide - other.0 as $Wide).abs();
+                if diff <= epsilon.0 as $Wide {
+                    return true;
+                }
+                let largest = cmp::max((self.0 as $Wide).abs(), (other.0 as $Wide).abs());
+                diff <= (largest * max_relative.0 as $Wide) >> $frac
+            }
+
+            fn ulps_eq(&self, other: &$Fixed, epsilon: $Fixed, max_ulps: u32) -> bool {
+                let diff = (self.0 as $Wide - other.0 as $Wide).abs();
+                diff <= epsilon.0 as $Wide || diff <= max_ulps as $Wide
+            }
+        }
+
+        impl BaseNum for $Fixed {}
+
+        impl BaseFloat for $Fixed {}
+    }
+}
+
+impl_fixed!(Fixed32, i32, i64, 16, "A Q16.16 fixed-point number, with 16 integer and 16 fractional bits.");
+impl_fixed!(Fixed64, i64, i128, 32, "A Q32.32 fixed-point number, with 32 integer and 32 fractional bits.");
@@ -0,0 +1,265 @@
+// Copyright 2016 The CGMath Developers. For a full listing of the authors,
+// refer to the Cargo.toml file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! View frustums, for culling geometry against a camera's field of view.
+
+use structure::*;
+
+use aabb::Aabb3;
+use approx::ApproxEq;
+use matrix::Matrix4;
+use num::BaseFloat;
+use plane::Plane;
+use point::Point3;
+use projection::DepthRange;
+use sphere::Sphere;
+use vector::{Vector3, Vector4};
+
+/// The relation of a volume to a bounding region.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Relation {
+    /// The volume lies entirely inside the region.
+    In,
+    /// The volume lies partly inside the region, or touches its boundary.
+    Cross,
+    /// The volume lies entirely outside the region.
+    Out,
+}
+
+/// A convex volume bounded by six planes, whose normals all face inwards.
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "rustc-serialize", derive(RustcEncodable, RustcDecodable))]
+#[cfg_attr(feature = "eders", derive(Serialize, Deserialize))]
+pub struct Frustum<S> {
+    pub left: Plane<S>,
+    pub right: Plane<S>,
+    pub bottom: Plane<S>,
+    pub top: Plane<S>,
+    pub near: Plane<S>,
+    pub far: Plane<S>,
+}
+
+impl<S: BaseFloat> Frustum<S> {
+    #[inline]
+    pub fn new(left: Plane<S>, right: Plane<S>,
+               bottom: Plane<S>, top: Plane<S>,
+               near: Plane<S>, far: Plane<S>) -> Frustum<S> {
+        Frustum {
+            left: left,
+            right: right,
+            bottom: bottom,
+            top: top,
+            near: near,
+            far: far,
+        }
+    }
+
+    /// Extract the frustum planes from a projection or view-projection
+    /// matrix that maps depths between `-1` and `1`, as in OpenGL.
+    ///
+    /// Returns `None` if any of the planes is degenerate.
+    #[inline]
+    pub fn from_matrix4(mat: Matrix4<S>) -> Option<Frustum<S>> {
+        Frustum::from_matrix4_with_depth(mat, DepthRange::NegativeOneToOne)
+    }
+
+    /// Extract the frustum planes from a projection or view-projection
+    /// matrix that maps depths to `depth`, using the method described by
+    /// Gribb and Hartmann in [Fast Extraction of Viewing Frustum Planes from
+    /// the World-View-Projection
+    /// Matrix](http://www.cs.otago.ac.nz/postgrads/alexis/planeExtraction.pdf).
+    ///
+    /// Planes are in the space the matrix transforms from: view space for a
+    /// projection matrix, or world space for a view-projection matrix. If the
+    /// projection has an infinite far plane, the far plane of the frustum
+    /// will have a zero normal, and contain every point.
+    ///
+    /// Returns `None` if any of the planes is degenerate.
+    pub fn from_matrix4_with_depth(mat: Matrix4<S>, depth: DepthRange) -> Option<Frustum<S>> {
+        let (x, y, z, w) = (mat.row(0), mat.row(1), mat.row(2), mat.row(3));
+        let (near, far) = match depth {
+            DepthRange::NegativeOneToOne => (w + z, w - z),
+            DepthRange::ZeroToOne        => (z, w - z),
+            DepthRange::OneToZero        => (w - z, z),
+        };
+
+        Some(Frustum::new(try_opt!(plane_from_vector4(w + x)),
+                          try_opt!(plane_from_vector4(w - x)),
+                          try_opt!(plane_from_vector4(w + y)),
+                          try_opt!(plane_from_vector4(w - y)),
+                          try_opt!(plane_from_vector4(near)),
+                          try_opt!(plane_from_vector4(far))))
+    }
+
+    /// Extract the frustum planes from a `Perspective`, `PerspectiveFov` or
+    /// `Ortho` projection, in view space, as `from_matrix4` does.
+    ///
+    /// Returns `None` if any of the planes is degenerate, such as for an
+    /// orthographic projection with no width.
+    #[inline]
+    pub fn from_projection<P: Into<Matrix4<S>>>(projection: P) -> Option<Frustum<S>> {
+        Frustum::from_matrix4(projection.into())
+    }
+
+    /// The six bounding planes, in the order left, right, bottom, top, near,
+    /// far.
+    #[inline]
+    pub fn planes(&self) -> [Plane<S>; 6] {
+        [self.left, self.right, self.bottom, self.top, self.near, self.far]
+    }
+
+    /// The relation of `point` to the frustum. Points lying exactly on one
+    /// of the bounding planes are reported as `Relation::Cross`.
+    pub fn relate_point(&self, point: Point3<S>) -> Relation {
+        self.planes().iter().fold(Relation::In, |relation, plane| {
+            let distance = plane.signed_distance(point);
+            if relation == Relation::Out || distance < S::zero() {
+                Relation::Out
+            } else if distance.is_zero() {
+                Relation::Cross
+            } else {
+                relation
+            }
+        })
+    }
+
+    /// The relation of `sphere` to the frustum.
+    ///
+    /// This is conservative: spheres lying just outside a corner of the
+    /// frustum may be reported as `Relation::Cross`.
+    pub fn relate_sphere(&self, sphere: &Sphere<S>) -> Relation {
+        self.planes().iter().fold(Relation::In, |relation, plane| {
+            let distance = plane.signed_distance(sphere.center);
+            if relation == Relation::Out || distance < -sphere.radius {
+                Relation::Out
+            } else if distance <= sphere.radius {
+                Relation::Cross
+            } else {
+                relation
+            }
+        })
+    }
+
+    /// The relation of `aabb` to the frustum.
+    ///
+    /// This is conservative: boxes lying just outside a corner of the
+    /// frustum may be reported as `Relation::Cross`.
+    pub fn relate_aabb(&self, aabb: &Aabb3<S>) -> Relation {
+        self.planes().iter().fold(Relation::In, |relation, plane| {
+            // The corners of the box furthest along and against the normal.
+            let mut positive = aabb.min;
+            let mut negative = aabb.max;
+            for axis in 0..3 {
+                if plane.normal[axis] >= S::zero() {
+                    positive[axis] = aabb.max[axis];
+                    negative[axis] = aabb.min[axis];
+                }
+            }
+
+            if relation == Relation::Out || plane.signed_distance(positive) < S::zero() {
+                Relation::Out
+            } else if plane.signed_distance(negative) <= S::zero() {
+                Relation::Cross
+            } else {
+                relation
+            }
+        })
+    }
+
+    /// The four corners of the frustum on the near plane, in the order
+    /// bottom left, bottom right, top left, top right.
+    pub fn near_corners(&self) -> [Point3<S>; 4] {
+        let (l, r, b, t, n) = (&self.left, &self.right, &self.bottom, &self.top, &self.near);
+        [intersect_planes(l, b, n), intersect_planes(r, b, n),
+         intersect_planes(l, t, n), intersect_planes(r, t, n)]
+    }
+
+    /// The eight corners of the frustum. The four corners on the near plane
+    /// come first, as from `near_corners`, followed by the four on the far
+    /// plane, in the same order.
+    ///
+    /// Returns `None` if any of the corners is not finite, as for a frustum
+    /// with an infinite far plane. Use `near_corners` for those.
+    pub fn to_corners(&self) -> Option<[Point3<S>; 8]> {
+        let (l, r, b, t, f) = (&self.left, &self.right, &self.bottom, &self.top, &self.far);
+        let near = self.near_corners();
+        let corners = [near[0], near[1], near[2], near[3],
+                       intersect_planes(l, b, f), intersect_planes(r, b, f),
+                       intersect_planes(l, t, f), intersect_planes(r, t, f)];
+        if corners.iter().all(|p| p.x.is_finite() && p.y.is_finite() && p.z.is_finite()) {
+            Some(corners)
+        } else {
+            None
+        }
+    }
+}
+
+/// Normalize the plane `a * x + b * y + c * z + d = 0`.
+fn plane_from_vector4<S: BaseFloat>(v: Vector4<S>) -> Option<Plane<S>> {
+    let normal = v.truncate();
+    let magnitude = normal.magnitude();
+    if magnitude.is_zero() {
+        if v.w > S::zero() {
+            // An infinitely distant plane, with every point on its inner side.
+            Some(Plane::new(Vector3::zero(), S::neg_infinity()))
+        } else {
+            None
+        }
+    } else if magnitude.is_finite() && v.w.is_finite() {
+        Some(Plane::new(normal / magnitude, -v.w / magnitude))
+    } else {
+        None
+    }
+}
+
+/// The point at which three planes meet.
+fn intersect_planes<S: BaseFloat>(a: &Plane<S>, b: &Plane<S>, c: &Plane<S>) -> Point3<S> {
+    let bc = b.normal.cross(c.normal);
+    let ca = c.normal.cross(a.normal);
+    let ab = a.normal.cross(b.normal);
+    let sum: Vector3<S> = bc * a.distance + ca * b.distance + ab * c.distance;
+    Point3::from_vec(sum / a.normal.dot(bc))
+}
+
+impl<S: BaseFloat> ApproxEq for Frustum<S> {
+    type Epsilon = S::Epsilon;
+
+    #[inline]
+    fn default_epsilon() -> S::Epsilon {
+        S::default_epsilon()
+    }
+
+    #[inline]
+    fn default_max_relative() -> S::Epsilon {
+        S::default_max_relative()
+    }
+
+    #[inline]
+    fn default_max_ulps() -> u32 {
+        S::default_max_ulps()
+    }
+
+    #[inline]
+    fn relative_eq(&self, other: &Self, epsilon: S::Epsilon, max_relative: S::Epsilon) -> bool {
+        self.planes().iter().zip(other.planes().iter())
+            .all(|(a, b)| Plane::relative_eq(a, b, epsilon, max_relative))
+    }
+
+    #[inline]
+    fn ulps_eq(&self, other: &Self, epsilon: S::Epsilon, max_ulps: u32) -> bool {
+        self.planes().iter().zip(other.planes().iter())
+            .all(|(a, b)| Plane::ulps_eq(a, b, epsilon, max_ulps))
+    }
+}
@@ -70,18 +70,38 @@ pub use approx::*;
 pub use num::*;
 pub use structure::*;
 
+pub use dual_quaternion::DualQuaternion;
 pub use matrix::{Matrix2, Matrix3, Matrix4};
+pub use matrix::{Matrix2x3, Matrix2x4, Matrix3x2, Matrix3x4, Matrix4x2, Matrix4x3};
 pub use quaternion::Quaternion;
 pub use vector::{Vector1, Vector2, Vector3, Vector4, dot, vec1, vec2, vec3, vec4};
+#[cfg(feature = "fixed-point")]
+pub use fixed::{Fixed32, Fixed64};
+#[cfg(feature = "const-generics")]
+pub use matrix_rc::MatrixRC;
+#[cfg(feature = "const-generics")]
+pub use vector_n::VectorN;
 
 pub use angle::{Deg, Rad};
-pub use euler::Euler;
-pub use point::{Point1, Point2, Point3};
+pub use euler::{Euler, EulerFrame, EulerOrder, EulerSequence};
+pub use point::{Point1, Point2, Point3, Point4};
 pub use rotation::*;
+pub use rotation_spline::{bezier_keyframes, squad_keyframes};
 pub use transform::*;
 
 pub use projection::*;
 
+pub use aabb::{Aabb2, Aabb3};
+pub use arc_length::ArcLengthTable;
+pub use curve::{CatmullRom, ControlPoint, Coordinates, CubicBezier, CubicHermite, Curve};
+pub use curve::{Parameterization, QuadraticBezier, UniformBSpline};
+pub use decomposition::MatrixDecomposition;
+pub use factorization::{Cholesky, Lu, Qr};
+pub use frustum::{Frustum, Relation};
+pub use plane::Plane;
+pub use ray::{Ray3, RayHit, Segment3};
+pub use sphere::Sphere;
+
 // Modules
 
 pub mod conv;
@@ -90,16 +110,40 @@ pub mod prelude;
 mod macros;
 
 mod num;
+#[cfg(feature = "fixed-point")]
+mod fixed;
 mod structure;
 
+mod dual_quaternion;
 mod matrix;
 mod quaternion;
 mod vector;
 
+#[cfg(feature = "const-generics")]
+mod matrix_rc;
+#[cfg(feature = "const-generics")]
+mod vector_n;
+
 mod angle;
 mod euler;
 mod point;
 mod rotation;
+mod rotation_spline;
 mod transform;
 
 mod projection;
+
+mod aabb;
+mod arc_length;
+mod curve;
+mod decomposition;
+mod eigen;
+mod factorization;
+mod frustum;
+mod plane;
+mod ray;
+mod sphere;
+mod svd;
+
+#[cfg(feature = "simd")]
+mod simd;
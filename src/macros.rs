@@ -17,6 +17,12 @@
 
 #![macro_use]
 
+/// Unwrap an `Option`, returning `None` from the enclosing function if it is
+/// `None`, in the same way as `try!` does for a `Result`.
+macro_rules! try_opt {
+    ($e:expr) => (match $e { Some(x) => x, None => return None })
+}
+
 /// Generates a binary operator implementation for the permutations of by-ref and by-val
 macro_rules! impl_operator {
     // When it is an unary operator
@@ -117,6 +123,53 @@ macro_rules! impl_operator {
     };
 }
 
+/// Generates an operator implementation for the by-val and by-ref left
+/// operands of a type with const generic parameters. The generic parameters
+/// are given in brackets, for example `[S: BaseNum, const N: usize]`.
+#[cfg(feature = "const-generics")]
+macro_rules! impl_const_generic_operator {
+    // When it is an unary operator
+    ([$($generics:tt)*] $Op:ident for $Lhs:ty {
+        fn $op:ident($x:ident) -> $Output:ty { $body:expr }
+    }) => {
+        impl<$($generics)*> $Op for $Lhs {
+            type Output = $Output;
+            #[inline]
+            fn $op(self) -> $Output {
+                let $x = self; $body
+            }
+        }
+
+        impl<'a, $($generics)*> $Op for &'a $Lhs {
+            type Output = $Output;
+            #[inline]
+            fn $op(self) -> $Output {
+                let $x = self; $body
+            }
+        }
+    };
+    // When it is a binary operator
+    ([$($generics:tt)*] $Op:ident<$Rhs:ty> for $Lhs:ty {
+        fn $op:ident($lhs:ident, $rhs:ident) -> $Output:ty { $body:expr }
+    }) => {
+        impl<$($generics)*> $Op<$Rhs> for $Lhs {
+            type Output = $Output;
+            #[inline]
+            fn $op(self, other: $Rhs) -> $Output {
+                let ($lhs, $rhs) = (self, other); $body
+            }
+        }
+
+        impl<'a, $($generics)*> $Op<$Rhs> for &'a $Lhs {
+            type Output = $Output;
+            #[inline]
+            fn $op(self, other: $Rhs) -> $Output {
+                let ($lhs, $rhs) = (self, other); $body
+            }
+        }
+    };
+}
+
 macro_rules! impl_assignment_operator {
     (<$S:ident: $Constraint:ident> $Op:ident<$Rhs:ty> for $Lhs:ty {
         fn $op:ident(&mut $lhs:ident, $rhs:ident) $body:block
@@ -254,3 +307,6 @@ macro_rules! impl_index_operators {
         }
     }
 }
+
+#[cfg(feature = "swizzle")]
+include!(concat!(env!("OUT_DIR"), "/swizzle_operator_macro.rs"));
@@ -24,10 +24,14 @@ use structure::*;
 
 use angle::Rad;
 use approx::ApproxEq;
-use euler::Euler;
-use num::BaseFloat;
-use point::{Point2, Point3};
+use euler::{Euler, EulerFrame, EulerSequence};
+#[cfg(feature = "fixed-point")]
+use fixed::{Fixed32, Fixed64};
+use num::{BaseFloat, BaseNum};
+use point::{Point2, Point3, Point4};
 use quaternion::Quaternion;
+#[cfg(feature = "simd")]
+use simd;
 use transform::{Transform, Transform2, Transform3};
 use vector::{Vector2, Vector3, Vector4};
 
@@ -79,8 +83,104 @@ pub struct Matrix4<S> {
     pub w: Vector4<S>,
 }
 
+/// A 2 x 3, column major matrix, with 2 rows and 3 columns
+///
+/// This type is marked as `#[repr(C)]`.
+#[repr(C)]
+#[derive(Copy, Clone, PartialEq)]
+#[cfg_attr(feature = "rustc-serialize", derive(RustcEncodable, RustcDecodable))]
+#[cfg_attr(feature = "eders", derive(Serialize, Deserialize))]
+pub struct Matrix2x3<S> {
+    /// The first column of the matrix.
+    pub x: Vector2<S>,
+    /// The second column of the matrix.
+    pub y: Vector2<S>,
+    /// The third column of the matrix.
+    pub z: Vector2<S>,
+}
 
-impl<S: BaseFloat> Matrix2<S> {
+/// A 3 x 2, column major matrix, with 3 rows and 2 columns
+///
+/// This type is marked as `#[repr(C)]`.
+#[repr(C)]
+#[derive(Copy, Clone, PartialEq)]
+#[cfg_attr(feature = "rustc-serialize", derive(RustcEncodable, RustcDecodable))]
+#[cfg_attr(feature = "eders", derive(Serialize, Deserialize))]
+pub struct Matrix3x2<S> {
+    /// The first column of the matrix.
+    pub x: Vector3<S>,
+    /// The second column of the matrix.
+    pub y: Vector3<S>,
+}
+
+/// A 2 x 4, column major matrix, with 2 rows and 4 columns
+///
+/// This type is marked as `#[repr(C)]`.
+#[repr(C)]
+#[derive(Copy, Clone, PartialEq)]
+#[cfg_attr(feature = "rustc-serialize", derive(RustcEncodable, RustcDecodable))]
+#[cfg_attr(feature = "eders", derive(Serialize, Deserialize))]
+pub struct Matrix2x4<S> {
+    /// The first column of the matrix.
+    pub x: Vector2<S>,
+    /// The second column of the matrix.
+    pub y: Vector2<S>,
+    /// The third column of the matrix.
+    pub z: Vector2<S>,
+    /// The fourth column of the matrix.
+    pub w: Vector2<S>,
+}
+
+/// A 4 x 2, column major matrix, with 4 rows and 2 columns
+///
+/// This type is marked as `#[repr(C)]`.
+#[repr(C)]
+#[derive(Copy, Clone, PartialEq)]
+#[cfg_attr(feature = "rustc-serialize", derive(RustcEncodable, RustcDecodable))]
+#[cfg_attr(feature = "eders", derive(Serialize, Deserialize))]
+pub struct Matrix4x2<S> {
+    /// The first column of the matrix.
+    pub x: Vector4<S>,
+    /// The second column of the matrix.
+    pub y: Vector4<S>,
+}
+
+/// A 3 x 4, column major matrix, with 3 rows and 4 columns
+///
+/// This type is marked as `#[repr(C)]`.
+#[repr(C)]
+#[derive(Copy, Clone, PartialEq)]
+#[cfg_attr(feature = "rustc-serialize", derive(RustcEncodable, RustcDecodable))]
+#[cfg_attr(feature = "eders", derive(Serialize, Deserialize))]
+pub struct Matrix3x4<S> {
+    /// The first column of the matrix.
+    pub x: Vector3<S>,
+    /// The second column of the matrix.
+    pub y: Vector3<S>,
+    /// The third column of the matrix.
+    pub z: Vector3<S>,
+    /// The fourth column of the matrix.
+    pub w: Vector3<S>,
+}
+
+/// A 4 x 3, column major matrix, with 4 rows and 3 columns
+///
+/// This type is marked as `#[repr(C)]`.
+#[repr(C)]
+#[derive(Copy, Clone, PartialEq)]
+#[cfg_attr(feature = "rustc-serialize", derive(RustcEncodable, RustcDecodable))]
+#[cfg_attr(feature = "eders", derive(Serialize, Deserialize))]
+pub struct Matrix4x3<S> {
+    /// The first column of the matrix.
+    pub x: Vector4<S>,
+    /// The second column of the matrix.
+    pub y: Vector4<S>,
+    /// The third column of the matrix.
+    pub z: Vector4<S>,
+}
+
+
+impl<S: BaseNum> Matrix2<S> {
     /// Create a new matrix, providing values for each index.
     #[inline]
     pub fn new(c0r0: S, c0r1: S,
@@ -94,7 +194,9 @@ impl<S: BaseFloat> Matrix2<S> {
     pub fn from_cols(c0: Vector2<S>, c1: Vector2<S>) -> Matrix2<S> {
         Matrix2 { x: c0, y: c1 }
     }
+}
 
+impl<S: BaseFloat> Matrix2<S> {
     /// Create a transformation matrix that will cause a vector to point at
     /// `dir`, using `up` for orientation.
     pub fn look_at(dir: Vector2<S>, up: Vector2<S>) -> Matrix2<S> {
@@ -111,7 +213,7 @@ impl<S: BaseFloat> Matrix2<S> {
     }
 }
 
-impl<S: BaseFloat> Matrix3<S> {
+impl<S: BaseNum> Matrix3<S> {
     /// Create a new matrix, providing values for each index.
     #[inline]
     pub fn new(c0r0:S, c0r1:S, c0r2:S,
@@ -127,10 +229,21 @@ impl<S: BaseFloat> Matrix3<S> {
     pub fn from_cols(c0: Vector3<S>, c1: Vector3<S>, c2: Vector3<S>) -> Matrix3<S> {
         Matrix3 { x: c0, y: c1, z: c2 }
     }
+}
 
+impl<S: BaseFloat> Matrix3<S> {
     /// Create a rotation matrix that will cause a vector to point at
     /// `dir`, using `up` for orientation.
+    ///
+    /// This is the same as `look_at_lh`.
+    #[inline]
     pub fn look_at(dir: Vector3<S>, up: Vector3<S>) -> Matrix3<S> {
+        Matrix3::look_at_lh(dir, up)
+    }
+
+    /// Create a rotation matrix for a left-handed view space, which rotates
+    /// `dir` onto the positive `z` axis, and `up` into the `yz` plane.
+    pub fn look_at_lh(dir: Vector3<S>, up: Vector3<S>) -> Matrix3<S> {
         let dir = dir.normalize();
         let side = up.cross(dir).normalize();
         let up = dir.cross(side).normalize();
@@ -138,6 +251,13 @@ impl<S: BaseFloat> Matrix3<S> {
         Matrix3::from_cols(side, up, dir).transpose()
     }
 
+    /// Create a rotation matrix for a right-handed view space, which rotates
+    /// `dir` onto the negative `z` axis, and `up` into the `yz` plane.
+    #[inline]
+    pub fn look_at_rh(dir: Vector3<S>, up: Vector3<S>) -> Matrix3<S> {
+        Matrix3::look_at_lh(-dir, up)
+    }
+
     /// Create a rotation matrix from a rotation around the `x` axis (pitch).
     pub fn from_angle_x<A: Into<Rad<S>>>(theta: A) -> Matrix3<S> {
         // http://en.wikipedia.org/wiki/Rotation_matrix#Basic_rotations
@@ -186,7 +306,7 @@ impl<S: BaseFloat> Matrix3<S> {
     }
 }
 
-impl<S: BaseFloat> Matrix4<S> {
+impl<S: BaseNum> Matrix4<S> {
     /// Create a new matrix, providing values for each index.
     #[inline]
     pub fn new(c0r0: S, c0r1: S, c0r2: S, c0r3: S,
@@ -229,9 +349,30 @@ impl<S: BaseFloat> Matrix4<S> {
                      S::zero(), S::zero(), S::zero(), S::one())
     }
 
+    /// Transform a projective point, without dividing by `w`, so a
+    /// perspective projection maps points to clip space, and points at
+    /// infinity stay explicit.
+    #[inline]
+    pub fn transform_point4(&self, point: Point4<S>) -> Point4<S> {
+        let v = self * Vector4::new(point.x, point.y, point.z, point.w);
+        Point4::new(v.x, v.y, v.z, v.w)
+    }
+}
+
+impl<S: BaseFloat> Matrix4<S> {
     /// Create a homogeneous transformation matrix that will cause a vector to point at
     /// `dir`, using `up` for orientation.
+    ///
+    /// This is the same as `look_at_rh`.
+    #[inline]
     pub fn look_at(eye: Point3<S>, center: Point3<S>, up: Vector3<S>) -> Matrix4<S> {
+        Matrix4::look_at_rh(eye, center, up)
+    }
+
+    /// Create a view matrix for a right-handed view space, with the camera
+    /// at `eye` looking down the negative `z` axis towards `center`. This is
+    /// the convention used by OpenGL's `gluLookAt`.
+    pub fn look_at_rh(eye: Point3<S>, center: Point3<S>, up: Vector3<S>) -> Matrix4<S> {
         let f = (center - eye).normalize();
         let s = f.cross(up).normalize();
         let u = s.cross(f);
@@ -242,6 +383,20 @@ impl<S: BaseFloat> Matrix4<S> {
                      -eye.dot(s), -eye.dot(u), eye.dot(f), S::one())
     }
 
+    /// Create a view matrix for a left-handed view space, with the camera
+    /// at `eye` looking down the positive `z` axis towards `center`. This is
+    /// the convention used by Direct3D's `D3DXMatrixLookAtLH`.
+    pub fn look_at_lh(eye: Point3<S>, center: Point3<S>, up: Vector3<S>) -> Matrix4<S> {
+        let f = (center - eye).normalize();
+        let s = up.cross(f).normalize();
+        let u = f.cross(s);
+
+        Matrix4::new(s.x, u.x, f.x, S::zero(),
+                     s.y, u.y, f.y, S::zero(),
+                     s.z, u.z, f.z, S::zero(),
+                     -eye.dot(s), -eye.dot(u), -eye.dot(f), S::one())
+    }
+
     /// Create a homogeneous transformation matrix from a rotation around the `x` axis (pitch).
     pub fn from_angle_x<A: Into<Rad<S>>>(theta: A) -> Matrix4<S> {
         // http://en.wikipedia.org/wiki/Rotation_matrix#Basic_rotations
@@ -301,6 +456,114 @@ impl<S: BaseFloat> Matrix4<S> {
     }
 }
 
+impl<S: BaseNum> Matrix2x3<S> {
+    /// Create a new matrix, providing values for each index.
+    #[inline]
+    pub fn new(c0r0: S, c0r1: S,
+               c1r0: S, c1r1: S,
+               c2r0: S, c2r1: S) -> Matrix2x3<S> {
+        Matrix2x3::from_cols(Vector2::new(c0r0, c0r1),
+                             Vector2::new(c1r0, c1r1),
+                             Vector2::new(c2r0, c2r1))
+    }
+
+    /// Create a new matrix, providing columns.
+    #[inline]
+    pub fn from_cols(c0: Vector2<S>, c1: Vector2<S>, c2: Vector2<S>) -> Matrix2x3<S> {
+        Matrix2x3 { x: c0, y: c1, z: c2 }
+    }
+}
+
+impl<S: BaseNum> Matrix3x2<S> {
+    /// Create a new matrix, providing values for each index.
+    #[inline]
+    pub fn new(c0r0: S, c0r1: S, c0r2: S,
+               c1r0: S, c1r1: S, c1r2: S) -> Matrix3x2<S> {
+        Matrix3x2::from_cols(Vector3::new(c0r0, c0r1, c0r2),
+                             Vector3::new(c1r0, c1r1, c1r2))
+    }
+
+    /// Create a new matrix, providing columns.
+    #[inline]
+    pub fn from_cols(c0: Vector3<S>, c1: Vector3<S>) -> Matrix3x2<S> {
+        Matrix3x2 { x: c0, y: c1 }
+    }
+}
+
+impl<S: BaseNum> Matrix2x4<S> {
+    /// Create a new matrix, providing values for each index.
+    #[inline]
+    pub fn new(c0r0: S, c0r1: S,
+               c1r0: S, c1r1: S,
+               c2r0: S, c2r1: S,
+               c3r0: S, c3r1: S) -> Matrix2x4<S> {
+        Matrix2x4::from_cols(Vector2::new(c0r0, c0r1),
+                             Vector2::new(c1r0, c1r1),
+                             Vector2::new(c2r0, c2r1),
+                             Vector2::new(c3r0, c3r1))
+    }
+
+    /// Create a new matrix, providing columns.
+    #[inline]
+    pub fn from_cols(c0: Vector2<S>, c1: Vector2<S>, c2: Vector2<S>, c3: Vector2<S>) -> Matrix2x4<S> {
+        Matrix2x4 { x: c0, y: c1, z: c2, w: c3 }
+    }
+}
+
+impl<S: BaseNum> Matrix4x2<S> {
+    /// Create a new matrix, providing values for each index.
+    #[inline]
+    pub fn new(c0r0: S, c0r1: S, c0r2: S, c0r3: S,
+               c1r0: S, c1r1: S, c1r2: S, c1r3: S) -> Matrix4x2<S> {
+        Matrix4x2::from_cols(Vector4::new(c0r0, c0r1, c0r2, c0r3),
+                             Vector4::new(c1r0, c1r1, c1r2, c1r3))
+    }
+
+    /// Create a new matrix, providing columns.
+    #[inline]
+    pub fn from_cols(c0: Vector4<S>, c1: Vector4<S>) -> Matrix4x2<S> {
+        Matrix4x2 { x: c0, y: c1 }
+    }
+}
+
+impl<S: BaseNum> Matrix3x4<S> {
+    /// Create a new matrix, providing values for each index.
+    #[inline]
+    pub fn new(c0r0: S, c0r1: S, c0r2: S,
+               c1r0: S, c1r1: S, c1r2: S,
+               c2r0: S, c2r1: S, c2r2: S,
+               c3r0: S, c3r1: S, c3r2: S) -> Matrix3x4<S> {
+        Matrix3x4::from_cols(Vector3::new(c0r0, c0r1, c0r2),
+                             Vector3::new(c1r0, c1r1, c1r2),
+                             Vector3::new(c2r0, c2r1, c2r2),
+                             Vector3::new(c3r0, c3r1, c3r2))
+    }
+
+    /// Create a new matrix, providing columns.
+    #[inline]
+    pub fn from_cols(c0: Vector3<S>, c1: Vector3<S>, c2: Vector3<S>, c3: Vector3<S>) -> Matrix3x4<S> {
+        Matrix3x4 { x: c0, y: c1, z: c2, w: c3 }
+    }
+}
+
+impl<S: BaseNum> Matrix4x3<S> {
+    /// Create a new matrix, providing values for each index.
+    #[inline]
+    pub fn new(c0r0: S, c0r1: S, c0r2: S, c0r3: S,
+               c1r0: S, c1r1: S, c1r2: S, c1r3: S,
+               c2r0: S, c2r1: S, c2r2: S, c2r3: S) -> Matrix4x3<S> {
+        Matrix4x3::from_cols(Vector4::new(c0r0, c0r1, c0r2, c0r3),
+                             Vector4::new(c1r0, c1r1, c1r2, c1r3),
+                             Vector4::new(c2r0, c2r1, c2r2, c2r3))
+    }
+
+    /// Create a new matrix, providing columns.
+    #[inline]
+    pub fn from_cols(c0: Vector4<S>, c1: Vector4<S>, c2: Vector4<S>) -> Matrix4x3<S> {
+        Matrix4x3 { x: c0, y: c1, z: c2 }
+    }
+}
+
 impl<S: BaseFloat> Zero for Matrix2<S> {
     #[inline]
     fn zero() -> Matrix2<S> {
@@ -343,40 +606,46 @@ impl<S: BaseFloat> Zero for Matrix4<S> {
     }
 }
 
-impl<S: BaseFloat> One for Matrix2<S> {
+impl<S: BaseNum> One for Matrix2<S> {
     #[inline]
     fn one() -> Matrix2<S> {
-        Matrix2::from_value(S::one())
+        Matrix2::new(S::one(), S::zero(),
+                     S::zero(), S::one())
     }
 }
 
-impl<S: BaseFloat> One for Matrix3<S> {
+impl<S: BaseNum> One for Matrix3<S> {
     #[inline]
     fn one() -> Matrix3<S> {
-        Matrix3::from_value(S::one())
+        Matrix3::new(S::one(), S::zero(), S::zero(),
+                     S::zero(), S::one(), S::zero(),
+                     S::zero(), S::zero(), S::one())
     }
 }
 
-impl<S: BaseFloat> One for Matrix4<S> {
+impl<S: BaseNum> One for Matrix4<S> {
     #[inline]
     fn one() -> Matrix4<S> {
-        Matrix4::from_value(S::one())
+        Matrix4::new(S::one(), S::zero(), S::zero(), S::zero(),
+                     S::zero(), S::one(), S::zero(), S::zero(),
+                     S::zero(), S::zero(), S::one(), S::zero(),
+                     S::zero(), S::zero(), S::zero(), S::one())
     }
 }
 
-impl<S: BaseFloat> VectorSpace for Matrix2<S> {
+impl<S: BaseNum> VectorSpace for Matrix2<S> where Matrix2<S>: Zero {
     type Scalar = S;
 }
 
-impl<S: BaseFloat> VectorSpace for Matrix3<S> {
+impl<S: BaseNum> VectorSpace for Matrix3<S> where Matrix3<S>: Zero {
     type Scalar = S;
 }
 
-impl<S: BaseFloat> VectorSpace for Matrix4<S> {
+impl<S: BaseNum> VectorSpace for Matrix4<S> where Matrix4<S>: Zero {
     type Scalar = S;
 }
 
-impl<S: BaseFloat> Matrix for Matrix2<S> {
+impl<S: BaseNum> Matrix for Matrix2<S> where Matrix2<S>: Zero {
     type Column = Vector2<S>;
     type Row = Vector2<S>;
     type Transpose = Matrix2<S>;
@@ -411,7 +680,7 @@ impl<S: BaseFloat> Matrix for Matrix2<S> {
     }
 }
 
-impl<S: BaseFloat> SquareMatrix for Matrix2<S> {
+impl<S: BaseNum> SquareMatrix for Matrix2<S> where Matrix2<S>: Zero {
     type ColumnRow = Vector2<S>;
 
     #[inline]
@@ -443,7 +712,7 @@ impl<S: BaseFloat> SquareMatrix for Matrix2<S> {
     }
 
     #[inline]
-    fn invert(&self) -> Option<Matrix2<S>> {
+    fn invert(&self) -> Option<Matrix2<S>> where S: BaseFloat {
         let det = self.determinant();
         if ulps_eq!(det, &S::zero()) {
             None
@@ -454,20 +723,20 @@ impl<S: BaseFloat> SquareMatrix for Matrix2<S> {
     }
 
     #[inline]
-    fn is_diagonal(&self) -> bool {
+    fn is_diagonal(&self) -> bool where S: BaseFloat {
         ulps_eq!(self[0][1], &S::zero()) &&
         ulps_eq!(self[1][0], &S::zero())
     }
 
 
     #[inline]
-    fn is_symmetric(&self) -> bool {
+    fn is_symmetric(&self) -> bool where S: BaseFloat {
         ulps_eq!(self[0][1], &self[1][0]) &&
         ulps_eq!(self[1][0], &self[0][1])
     }
 }
 
-impl<S: BaseFloat> Matrix for Matrix3<S> {
+impl<S: BaseNum> Matrix for Matrix3<S> where Matrix3<S>: Zero {
     type Column = Vector3<S>;
     type Row = Vector3<S>;
     type Transpose = Matrix3<S>;
@@ -505,7 +774,7 @@ impl<S: BaseFloat> Matrix for Matrix3<S> {
     }
 }
 
-impl<S: BaseFloat> SquareMatrix for Matrix3<S> {
+impl<S: BaseNum> SquareMatrix for Matrix3<S> where Matrix3<S>: Zero {
     type ColumnRow = Vector3<S>;
 
     #[inline]
@@ -530,9 +799,7 @@ impl<S: BaseFloat> SquareMatrix for Matrix3<S> {
     }
 
     fn determinant(&self) -> S {
-        self[0][0] * (self[1][1] * self[2][2] - self[2][1] * self[1][2]) -
-        self[1][0] * (self[0][1] * self[2][2] - self[2][1] * self[0][2]) +
-        self[2][0] * (self[0][1] * self[1][2] - self[1][1] * self[0][2])
+        determinant3(self)
     }
 
     #[inline]
@@ -542,7 +809,7 @@ impl<S: BaseFloat> SquareMatrix for Matrix3<S> {
                      self[2][2])
     }
 
-    fn invert(&self) -> Option<Matrix3<S>> {
+    fn invert(&self) -> Option<Matrix3<S>> where S: BaseFloat {
         let det = self.determinant();
         if ulps_eq!(det, &S::zero()) { None } else {
             Some(Matrix3::from_cols(self[1].cross(self[2]) / det,
@@ -551,7 +818,7 @@ impl<S: BaseFloat> SquareMatrix for Matrix3<S> {
         }
     }
 
-    fn is_diagonal(&self) -> bool {
+    fn is_diagonal(&self) -> bool where S: BaseFloat {
         ulps_eq!(self[0][1], &S::zero()) &&
         ulps_eq!(self[0][2], &S::zero()) &&
 
@@ -562,7 +829,7 @@ impl<S: BaseFloat> SquareMatrix for Matrix3<S> {
         ulps_eq!(self[2][1], &S::zero())
     }
 
-    fn is_symmetric(&self) -> bool {
+    fn is_symmetric(&self) -> bool where S: BaseFloat {
         ulps_eq!(self[0][1], &self[1][0]) &&
         ulps_eq!(self[0][2], &self[2][0]) &&
 
@@ -574,7 +841,16 @@ impl<S: BaseFloat> SquareMatrix for Matrix3<S> {
     }
 }
 
-impl<S: BaseFloat> Matrix for Matrix4<S> {
+/// The determinant of a `Matrix3`, which `Matrix4::determinant` uses for its
+/// minors without needing `SquareMatrix` for `Matrix3`.
+#[inline]
+fn determinant3<S: BaseNum>(m: &Matrix3<S>) -> S {
+    m[0][0] * (m[1][1] * m[2][2] - m[2][1] * m[1][2]) -
+    m[1][0] * (m[0][1] * m[2][2] - m[2][1] * m[0][2]) +
+    m[2][0] * (m[0][1] * m[1][2] - m[1][1] * m[0][2])
+}
+
+impl<S: BaseNum> Matrix for Matrix4<S> where Matrix4<S>: Zero {
     type Column = Vector4<S>;
     type Row = Vector4<S>;
     type Transpose = Matrix4<S>;
@@ -615,7 +891,7 @@ impl<S: BaseFloat> Matrix for Matrix4<S> {
     }
 }
 
-impl<S: BaseFloat> SquareMatrix for Matrix4<S> {
+impl<S: BaseNum> SquareMatrix for Matrix4<S> where Matrix4<S>: Zero {
     type ColumnRow = Vector4<S>;
 
     #[inline]
@@ -644,6 +920,13 @@ impl<S: BaseFloat> SquareMatrix for Matrix4<S> {
     }
 
     fn determinant(&self) -> S {
+        #[cfg(feature = "simd")]
+        {
+            if let Some(det) = simd::matrix4_determinant(self) {
+                return det;
+            }
+        }
+
         let m0 = Matrix3::new(self[1][1], self[2][1], self[3][1],
                               self[1][2], self[2][2], self[3][2],
                               self[1][3], self[2][3], self[3][3]);
@@ -657,10 +940,10 @@ impl<S: BaseFloat> SquareMatrix for Matrix4<S> {
                               self[0][2], self[1][2], self[2][2],
                               self[0][3], self[1][3], self[2][3]);
 
-        self[0][0] * m0.determinant() -
-        self[1][0] * m1.determinant() +
-        self[2][0] * m2.determinant() -
-        self[3][0] * m3.determinant()
+        self[0][0] * determinant3(&m0) -
+        self[1][0] * determinant3(&m1) +
+        self[2][0] * determinant3(&m2) -
+        self[3][0] * determinant3(&m3)
     }
 
     #[inline]
@@ -671,7 +954,14 @@ impl<S: BaseFloat> SquareMatrix for Matrix4<S> {
                      self[3][3])
     }
 
-    fn invert(&self) -> Option<Matrix4<S>> {
+    fn invert(&self) -> Option<Matrix4<S>> where S: BaseFloat {
+        #[cfg(feature = "simd")]
+        {
+            if let Some((adjugate, det)) = simd::matrix4_adjugate(self) {
+                return if ulps_eq!(det, &S::zero()) { None } else { Some(adjugate / det) };
+            }
+        }
+
         let det = self.determinant();
         if ulps_eq!(det, &S::zero()) { None } else {
             let inv_det = S::one() / det;
@@ -695,7 +985,7 @@ impl<S: BaseFloat> SquareMatrix for Matrix4<S> {
         }
     }
 
-    fn is_diagonal(&self) -> bool {
+    fn is_diagonal(&self) -> bool where S: BaseFloat {
         ulps_eq!(self[0][1], &S::zero()) &&
         ulps_eq!(self[0][2], &S::zero()) &&
         ulps_eq!(self[0][3], &S::zero()) &&
@@ -713,7 +1003,7 @@ impl<S: BaseFloat> SquareMatrix for Matrix4<S> {
         ulps_eq!(self[3][2], &S::zero())
     }
 
-    fn is_symmetric(&self) -> bool {
+    fn is_symmetric(&self) -> bool where S: BaseFloat {
         ulps_eq!(self[0][1], &self[1][0]) &&
         ulps_eq!(self[0][2], &self[2][0]) &&
         ulps_eq!(self[0][3], &self[3][0]) &&
@@ -831,6 +1121,94 @@ impl<S: BaseFloat> ApproxEq for Matrix4<S> {
     }
 }
 
+macro_rules! impl_rect_matrix {
+    ($MatrixN:ident { $($field:ident : $col_index:expr),+ }, $ColumnN:ident, $RowN:ident { $($row_index:expr),+ }, $Transpose:ident) => {
+        impl<S: BaseFloat> Zero for $MatrixN<S> {
+            #[inline]
+            fn zero() -> $MatrixN<S> {
+                $MatrixN { $($field: $ColumnN::zero()),+ }
+            }
+
+            #[inline]
+            fn is_zero(&self) -> bool {
+                ulps_eq!(self, &Self::zero())
+            }
+        }
+
+        impl<S: BaseNum> VectorSpace for $MatrixN<S> where $MatrixN<S>: Zero {
+            type Scalar = S;
+        }
+
+        impl<S: BaseNum> Matrix for $MatrixN<S> where $MatrixN<S>: Zero, $Transpose<S>: Zero {
+            type Column = $ColumnN<S>;
+            type Row = $RowN<S>;
+            type Transpose = $Transpose<S>;
+
+            #[inline]
+            fn row(&self, r: usize) -> $RowN<S> {
+                $RowN::new($(self[$col_index][r]),+)
+            }
+
+            #[inline]
+            fn swap_rows(&mut self, a: usize, b: usize) {
+                $(self[$col_index].swap_elements(a, b);)+
+            }
+
+            #[inline]
+            fn swap_columns(&mut self, a: usize, b: usize) {
+                unsafe { ptr::swap(&mut self[a], &mut self[b]) };
+            }
+
+            #[inline]
+            fn swap_elements(&mut self, a: (usize, usize), b: (usize, usize)) {
+                let (ac, ar) = a;
+                let (bc, br) = b;
+                unsafe { ptr::swap(&mut self[ac][ar], &mut self[bc][br]) };
+            }
+
+            fn transpose(&self) -> $Transpose<S> {
+                $Transpose::from_cols($(self.row($row_index)),+)
+            }
+        }
+
+        impl<S: BaseFloat> ApproxEq for $MatrixN<S> {
+            type Epsilon = S::Epsilon;
+
+            #[inline]
+            fn default_epsilon() -> S::Epsilon {
+                cast(1.0e-6f64).unwrap()
+            }
+
+            #[inline]
+            fn default_max_relative() -> S::Epsilon {
+                S::default_max_relative()
+            }
+
+            #[inline]
+            fn default_max_ulps() -> u32 {
+                S::default_max_ulps()
+            }
+
+            #[inline]
+            fn relative_eq(&self, other: &Self, epsilon: S::Epsilon, max_relative: S::Epsilon) -> bool {
+                $($ColumnN::relative_eq(&self[$col_index], &other[$col_index], epsilon, max_relative))&&+
+            }
+
+            #[inline]
+            fn ulps_eq(&self, other: &Self, epsilon: S::Epsilon, max_ulps: u32) -> bool {
+                $($ColumnN::ulps_eq(&self[$col_index], &other[$col_index], epsilon, max_ulps))&&+
+            }
+        }
+    }
+}
+
+impl_rect_matrix!(Matrix2x3 { x: 0, y: 1, z: 2 }, Vector2, Vector3 { 0, 1 }, Matrix3x2);
+impl_rect_matrix!(Matrix3x2 { x: 0, y: 1 }, Vector3, Vector2 { 0, 1, 2 }, Matrix2x3);
+impl_rect_matrix!(Matrix2x4 { x: 0, y: 1, z: 2, w: 3 }, Vector2, Vector4 { 0, 1 }, Matrix4x2);
+impl_rect_matrix!(Matrix4x2 { x: 0, y: 1 }, Vector4, Vector2 { 0, 1, 2, 3 }, Matrix2x4);
+impl_rect_matrix!(Matrix3x4 { x: 0, y: 1, z: 2, w: 3 }, Vector3, Vector4 { 0, 1, 2 }, Matrix4x3);
+impl_rect_matrix!(Matrix4x3 { x: 0, y: 1, z: 2 }, Vector4, Vector3 { 0, 1, 2, 3 }, Matrix3x4);
+
 impl<S: BaseFloat> Transform<Point2<S>> for Matrix3<S> {
   fn one() -> Matrix3<S> {
     One::one()
@@ -913,52 +1291,74 @@ impl<S: BaseFloat> Transform<Point3<S>> for Matrix4<S> {
 
 impl<S: BaseFloat> Transform2<S> for Matrix3<S> {}
 
-impl<S: BaseFloat> Transform3<S> for Matrix3<S> {}
+impl<S: BaseFloat> Transform3<S> for Matrix3<S> {
+  fn look_at_lh(eye: Point3<S>, center: Point3<S>, up: Vector3<S>) -> Matrix3<S> {
+    Matrix3::look_at_lh(center - eye, up)
+  }
+
+  fn look_at_rh(eye: Point3<S>, center: Point3<S>, up: Vector3<S>) -> Matrix3<S> {
+    Matrix3::look_at_rh(center - eye, up)
+  }
+}
+
+impl<S: BaseFloat> Transform3<S> for Matrix4<S> {
+  fn look_at_lh(eye: Point3<S>, center: Point3<S>, up: Vector3<S>) -> Matrix4<S> {
+    Matrix4::look_at_lh(eye, center, up)
+  }
 
-impl<S: BaseFloat> Transform3<S> for Matrix4<S> {}
+  fn look_at_rh(eye: Point3<S>, center: Point3<S>, up: Vector3<S>) -> Matrix4<S> {
+    Matrix4::look_at_rh(eye, center, up)
+  }
+}
 
 macro_rules! impl_matrix {
-    ($MatrixN:ident, $VectorN:ident { $($field:ident : $row_index:expr),+ }) => {
-        impl_operator!(<S: BaseFloat> Neg for $MatrixN<S> {
-            fn neg(matrix) -> $MatrixN<S> { $MatrixN { $($field: -matrix.$field),+ } }
-        });
+    ($MatrixN:ident { $($field:ident),+ }, $RowN:ident, $ColumnN:ident { $($row_index:expr),+ }) => {
+        impl<S: Copy + Neg<Output = S>> Neg for $MatrixN<S> {
+            type Output = $MatrixN<S>;
 
-        impl_operator!(<S: BaseFloat> Mul<S> for $MatrixN<S> {
+            #[inline]
+            fn neg(self) -> $MatrixN<S> { $MatrixN { $($field: -self.$field),+ } }
+        }
+
+        impl<'a, S: Copy + Neg<Output = S>> Neg for &'a $MatrixN<S> {
+            type Output = $MatrixN<S>;
+
+            #[inline]
+            fn neg(self) -> $MatrixN<S> { $MatrixN { $($field: -self.$field),+ } }
+        }
+
+        impl_operator!(<S: BaseNum> Mul<S> for $MatrixN<S> {
             fn mul(matrix, scalar) -> $MatrixN<S> { $MatrixN { $($field: matrix.$field * scalar),+ } }
         });
-        impl_operator!(<S: BaseFloat> Div<S> for $MatrixN<S> {
+        impl_operator!(<S: BaseNum> Div<S> for $MatrixN<S> {
             fn div(matrix, scalar) -> $MatrixN<S> { $MatrixN { $($field: matrix.$field / scalar),+ } }
         });
-        impl_operator!(<S: BaseFloat> Rem<S> for $MatrixN<S> {
+        impl_operator!(<S: BaseNum> Rem<S> for $MatrixN<S> {
             fn rem(matrix, scalar) -> $MatrixN<S> { $MatrixN { $($field: matrix.$field % scalar),+ } }
         });
-        impl_assignment_operator!(<S: BaseFloat> MulAssign<S> for $MatrixN<S> {
+        impl_assignment_operator!(<S: BaseNum> MulAssign<S> for $MatrixN<S> {
             fn mul_assign(&mut self, scalar) { $(self.$field *= scalar);+ }
         });
-        impl_assignment_operator!(<S: BaseFloat> DivAssign<S> for $MatrixN<S> {
+        impl_assignment_operator!(<S: BaseNum> DivAssign<S> for $MatrixN<S> {
             fn div_assign(&mut self, scalar) { $(self.$field /= scalar);+ }
         });
-        impl_assignment_operator!(<S: BaseFloat> RemAssign<S> for $MatrixN<S> {
+        impl_assignment_operator!(<S: BaseNum> RemAssign<S> for $MatrixN<S> {
             fn rem_assign(&mut self, scalar) { $(self.$field %= scalar);+ }
         });
 
-        impl_operator!(<S: BaseFloat> Add<$MatrixN<S> > for $MatrixN<S> {
+        impl_operator!(<S: BaseNum> Add<$MatrixN<S> > for $MatrixN<S> {
             fn add(lhs, rhs) -> $MatrixN<S> { $MatrixN { $($field: lhs.$field + rhs.$field),+ } }
         });
-        impl_operator!(<S: BaseFloat> Sub<$MatrixN<S> > for $MatrixN<S> {
+        impl_operator!(<S: BaseNum> Sub<$MatrixN<S> > for $MatrixN<S> {
             fn sub(lhs, rhs) -> $MatrixN<S> { $MatrixN { $($field: lhs.$field - rhs.$field),+ } }
         });
-        impl<S: BaseFloat + AddAssign<S>> AddAssign<$MatrixN<S>> for $MatrixN<S> {
+        impl<S: BaseNum + AddAssign<S>> AddAssign<$MatrixN<S>> for $MatrixN<S> {
             fn add_assign(&mut self, other: $MatrixN<S>) { $(self.$field += other.$field);+ }
         }
-        impl<S: BaseFloat + SubAssign<S>> SubAssign<$MatrixN<S>> for $MatrixN<S> {
+        impl<S: BaseNum + SubAssign<S>> SubAssign<$MatrixN<S>> for $MatrixN<S> {
             fn sub_assign(&mut self, other: $MatrixN<S>) { $(self.$field -= other.$field);+ }
         }
 
-        impl_operator!(<S: BaseFloat> Mul<$VectorN<S> > for $MatrixN<S> {
-            fn mul(matrix, vector) -> $VectorN<S> { $VectorN::new($(matrix.row($row_index).dot(vector.clone())),+) }
-        });
-
         impl_scalar_ops!($MatrixN<usize> { $($field),+ });
         impl_scalar_ops!($MatrixN<u8> { $($field),+ });
         impl_scalar_ops!($MatrixN<u16> { $($field),+ });
@@ -971,6 +1371,21 @@ macro_rules! impl_matrix {
         impl_scalar_ops!($MatrixN<i64> { $($field),+ });
         impl_scalar_ops!($MatrixN<f32> { $($field),+ });
         impl_scalar_ops!($MatrixN<f64> { $($field),+ });
+        #[cfg(feature = "fixed-point")]
+        impl_scalar_ops!($MatrixN<Fixed32> { $($field),+ });
+        #[cfg(feature = "fixed-point")]
+        impl_scalar_ops!($MatrixN<Fixed64> { $($field),+ });
+
+        impl_int_zero!($MatrixN<usize> { $($field),+ }, $ColumnN);
+        impl_int_zero!($MatrixN<u8> { $($field),+ }, $ColumnN);
+        impl_int_zero!($MatrixN<u16> { $($field),+ }, $ColumnN);
+        impl_int_zero!($MatrixN<u32> { $($field),+ }, $ColumnN);
+        impl_int_zero!($MatrixN<u64> { $($field),+ }, $ColumnN);
+        impl_int_zero!($MatrixN<isize> { $($field),+ }, $ColumnN);
+        impl_int_zero!($MatrixN<i8> { $($field),+ }, $ColumnN);
+        impl_int_zero!($MatrixN<i16> { $($field),+ }, $ColumnN);
+        impl_int_zero!($MatrixN<i32> { $($field),+ }, $ColumnN);
+        impl_int_zero!($MatrixN<i64> { $($field),+ }, $ColumnN);
 
 
         impl<S: NumCast + Copy> $MatrixN<S> {
@@ -997,26 +1412,76 @@ macro_rules! impl_scalar_ops {
     };
 }
 
-impl_matrix!(Matrix2, Vector2 { x: 0, y: 1 });
-impl_matrix!(Matrix3, Vector3 { x: 0, y: 1, z: 2 });
-impl_matrix!(Matrix4, Vector4 { x: 0, y: 1, z: 2, w: 3 });
+// Integer matrices are compared exactly, while the `BaseFloat` impls above
+// allow for rounding error.
+macro_rules! impl_int_zero {
+    ($MatrixN:ident<$S:ident> { $($field:ident),+ }, $ColumnN:ident) => {
+        impl Zero for $MatrixN<$S> {
+            #[inline]
+            fn zero() -> $MatrixN<$S> {
+                $MatrixN { $($field: $ColumnN::zero()),+ }
+            }
 
-impl_operator!(<S: BaseFloat> Mul<Matrix2<S> > for Matrix2<S> {
+            #[inline]
+            fn is_zero(&self) -> bool {
+                $(self.$field.is_zero())&&+
+            }
+        }
+    };
+}
+
+impl_matrix!(Matrix2 { x, y }, Vector2, Vector2 { 0, 1 });
+impl_matrix!(Matrix3 { x, y, z }, Vector3, Vector3 { 0, 1, 2 });
+impl_matrix!(Matrix4 { x, y, z, w }, Vector4, Vector4 { 0, 1, 2, 3 });
+impl_matrix!(Matrix2x3 { x, y, z }, Vector3, Vector2 { 0, 1 });
+impl_matrix!(Matrix3x2 { x, y }, Vector2, Vector3 { 0, 1, 2 });
+impl_matrix!(Matrix2x4 { x, y, z, w }, Vector4, Vector2 { 0, 1 });
+impl_matrix!(Matrix4x2 { x, y }, Vector2, Vector4 { 0, 1, 2, 3 });
+impl_matrix!(Matrix3x4 { x, y, z, w }, Vector4, Vector3 { 0, 1, 2 });
+impl_matrix!(Matrix4x3 { x, y, z }, Vector3, Vector4 { 0, 1, 2, 3 });
+
+macro_rules! impl_matrix_vector_mul {
+    ($MatrixN:ident { $first:ident $(, $field:ident)* }, $RowN:ident, $ColumnN:ident) => {
+        impl_operator!(<S: BaseNum> Mul<$RowN<S> > for $MatrixN<S> {
+            fn mul(matrix, vector) -> $ColumnN<S> { matrix.$first * vector.$first $(+ matrix.$field * vector.$field)* }
+        });
+    }
+}
+
+impl_matrix_vector_mul!(Matrix2 { x, y }, Vector2, Vector2);
+impl_matrix_vector_mul!(Matrix3 { x, y, z }, Vector3, Vector3);
+impl_matrix_vector_mul!(Matrix2x3 { x, y, z }, Vector3, Vector2);
+impl_matrix_vector_mul!(Matrix3x2 { x, y }, Vector2, Vector3);
+impl_matrix_vector_mul!(Matrix2x4 { x, y, z, w }, Vector4, Vector2);
+impl_matrix_vector_mul!(Matrix4x2 { x, y }, Vector2, Vector4);
+impl_matrix_vector_mul!(Matrix3x4 { x, y, z, w }, Vector4, Vector3);
+impl_matrix_vector_mul!(Matrix4x3 { x, y, z }, Vector3, Vector4);
+
+impl_operator!(<S: BaseNum> Mul<Vector4<S> > for Matrix4<S> {
+    fn mul(matrix, vector) -> Vector4<S> {{
+        #[cfg(feature = "simd")]
+        {
+            if let Some(v) = simd::matrix4_mul_vector4(&matrix, &vector) {
+                return v;
+            }
+        }
+        matrix.x * vector.x + matrix.y * vector.y + matrix.z * vector.z + matrix.w * vector.w
+    }}
+});
+
+impl_operator!(<S: BaseNum> Mul<Matrix2<S> > for Matrix2<S> {
     fn mul(lhs, rhs) -> Matrix2<S> {
-        Matrix2::new(lhs.row(0).dot(rhs[0]), lhs.row(1).dot(rhs[0]),
-                     lhs.row(0).dot(rhs[1]), lhs.row(1).dot(rhs[1]))
+        Matrix2::from_cols(lhs * rhs[0], lhs * rhs[1])
     }
 });
 
-impl_operator!(<S: BaseFloat> Mul<Matrix3<S> > for Matrix3<S> {
+impl_operator!(<S: BaseNum> Mul<Matrix3<S> > for Matrix3<S> {
     fn mul(lhs, rhs) -> Matrix3<S> {
-        Matrix3::new(lhs.row(0).dot(rhs[0]), lhs.row(1).dot(rhs[0]), lhs.row(2).dot(rhs[0]),
-                     lhs.row(0).dot(rhs[1]), lhs.row(1).dot(rhs[1]), lhs.row(2).dot(rhs[1]),
-                     lhs.row(0).dot(rhs[2]), lhs.row(1).dot(rhs[2]), lhs.row(2).dot(rhs[2]))
+        Matrix3::from_cols(lhs * rhs[0], lhs * rhs[1], lhs * rhs[2])
     }
 });
 
-// Using self.row(0).dot(other[0]) like the other matrix multiplies
+// Using self.row(0).dot(other[0]) for each element
 // causes the LLVM to miss identical loads and multiplies. This optimization
 // causes the code to be auto vectorized properly increasing the performance
 // around ~4 times.
@@ -1029,23 +1494,62 @@ macro_rules! dot_matrix4 {
     };
 }
 
-impl_operator!(<S: BaseFloat> Mul<Matrix4<S> > for Matrix4<S> {
-    fn mul(lhs, rhs) -> Matrix4<S> {
+impl_operator!(<S: BaseNum> Mul<Matrix4<S> > for Matrix4<S> {
+    fn mul(lhs, rhs) -> Matrix4<S> {{
+        #[cfg(feature = "simd")]
+        {
+            if let Some(m) = simd::matrix4_mul(&lhs, &rhs) {
+                return m;
+            }
+        }
         Matrix4::new(dot_matrix4!(lhs, rhs, 0, 0), dot_matrix4!(lhs, rhs, 1, 0), dot_matrix4!(lhs, rhs, 2, 0), dot_matrix4!(lhs, rhs, 3, 0),
                      dot_matrix4!(lhs, rhs, 0, 1), dot_matrix4!(lhs, rhs, 1, 1), dot_matrix4!(lhs, rhs, 2, 1), dot_matrix4!(lhs, rhs, 3, 1),
                      dot_matrix4!(lhs, rhs, 0, 2), dot_matrix4!(lhs, rhs, 1, 2), dot_matrix4!(lhs, rhs, 2, 2), dot_matrix4!(lhs, rhs, 3, 2),
                      dot_matrix4!(lhs, rhs, 0, 3), dot_matrix4!(lhs, rhs, 1, 3), dot_matrix4!(lhs, rhs, 2, 3), dot_matrix4!(lhs, rhs, 3, 3))
-    }
+    }}
 });
 
+macro_rules! impl_matrix_mul {
+    ($Lhs:ident * $Rhs:ident => $Output:ident { $($field:ident),+ }) => {
+        impl_operator!(<S: BaseNum> Mul<$Rhs<S> > for $Lhs<S> {
+            fn mul(lhs, rhs) -> $Output<S> { $Output { $($field: lhs * rhs.$field),+ } }
+        });
+    }
+}
+
+impl_matrix_mul!(Matrix2 * Matrix2x3 => Matrix2x3 { x, y, z });
+impl_matrix_mul!(Matrix2 * Matrix2x4 => Matrix2x4 { x, y, z, w });
+impl_matrix_mul!(Matrix2x3 * Matrix3x2 => Matrix2 { x, y });
+impl_matrix_mul!(Matrix2x3 * Matrix3 => Matrix2x3 { x, y, z });
+impl_matrix_mul!(Matrix2x3 * Matrix3x4 => Matrix2x4 { x, y, z, w });
+impl_matrix_mul!(Matrix2x4 * Matrix4x2 => Matrix2 { x, y });
+impl_matrix_mul!(Matrix2x4 * Matrix4x3 => Matrix2x3 { x, y, z });
+impl_matrix_mul!(Matrix2x4 * Matrix4 => Matrix2x4 { x, y, z, w });
+impl_matrix_mul!(Matrix3x2 * Matrix2 => Matrix3x2 { x, y });
+impl_matrix_mul!(Matrix3x2 * Matrix2x3 => Matrix3 { x, y, z });
+impl_matrix_mul!(Matrix3x2 * Matrix2x4 => Matrix3x4 { x, y, z, w });
+impl_matrix_mul!(Matrix3 * Matrix3x2 => Matrix3x2 { x, y });
+impl_matrix_mul!(Matrix3 * Matrix3x4 => Matrix3x4 { x, y, z, w });
+impl_matrix_mul!(Matrix3x4 * Matrix4x2 => Matrix3x2 { x, y });
+impl_matrix_mul!(Matrix3x4 * Matrix4x3 => Matrix3 { x, y, z });
+impl_matrix_mul!(Matrix3x4 * Matrix4 => Matrix3x4 { x, y, z, w });
+impl_matrix_mul!(Matrix4x2 * Matrix2 => Matrix4x2 { x, y });
+impl_matrix_mul!(Matrix4x2 * Matrix2x3 => Matrix4x3 { x, y, z });
+impl_matrix_mul!(Matrix4x2 * Matrix2x4 => Matrix4 { x, y, z, w });
+impl_matrix_mul!(Matrix4x3 * Matrix3x2 => Matrix4x2 { x, y });
+impl_matrix_mul!(Matrix4x3 * Matrix3 => Matrix4x3 { x, y, z });
+impl_matrix_mul!(Matrix4x3 * Matrix3x4 => Matrix4 { x, y, z, w });
+impl_matrix_mul!(Matrix4 * Matrix4x2 => Matrix4x2 { x, y });
+impl_matrix_mul!(Matrix4 * Matrix4x3 => Matrix4x3 { x, y, z });
+
 macro_rules! index_operators {
-    ($MatrixN:ident<$S:ident>, $n:expr, $Output:ty, $I:ty) => {
+    ($MatrixN:ident<$S:ident>, $cols:expr, $rows:expr, $Output:ty, $I:ty) => {
         impl<$S> Index<$I> for $MatrixN<$S> {
             type Output = $Output;
 
             #[inline]
             fn index<'a>(&'a self, i: $I) -> &'a $Output {
-                let v: &[[$S; $n]; $n] = self.as_ref();
+                let v: &[[$S; $rows]; $cols] = self.as_ref();
                 From::from(&v[i])
             }
         }
@@ -1053,28 +1557,34 @@ macro_rules! index_operators {
         impl<$S> IndexMut<$I> for $MatrixN<$S> {
             #[inline]
             fn index_mut<'a>(&'a mut self, i: $I) -> &'a mut $Output {
-                let v: &mut [[$S; $n]; $n] = self.as_mut();
+                let v: &mut [[$S; $rows]; $cols] = self.as_mut();
                 From::from(&mut v[i])
             }
         }
     }
 }
 
-index_operators!(Matrix2<S>, 2, Vector2<S>, usize);
-index_operators!(Matrix3<S>, 3, Vector3<S>, usize);
-index_operators!(Matrix4<S>, 4, Vector4<S>, usize);
-// index_operators!(Matrix2<S>, 2, [Vector2<S>], Range<usize>);
-// index_operators!(Matrix3<S>, 3, [Vector3<S>], Range<usize>);
-// index_operators!(Matrix4<S>, 4, [Vector4<S>], Range<usize>);
-// index_operators!(Matrix2<S>, 2, [Vector2<S>], RangeTo<usize>);
-// index_operators!(Matrix3<S>, 3, [Vector3<S>], RangeTo<usize>);
-// index_operators!(Matrix4<S>, 4, [Vector4<S>], RangeTo<usize>);
-// index_operators!(Matrix2<S>, 2, [Vector2<S>], RangeFrom<usize>);
-// index_operators!(Matrix3<S>, 3, [Vector3<S>], RangeFrom<usize>);
-// index_operators!(Matrix4<S>, 4, [Vector4<S>], RangeFrom<usize>);
-// index_operators!(Matrix2<S>, 2, [Vector2<S>], RangeFull);
-// index_operators!(Matrix3<S>, 3, [Vector3<S>], RangeFull);
-// index_operators!(Matrix4<S>, 4, [Vector4<S>], RangeFull);
+index_operators!(Matrix2<S>, 2, 2, Vector2<S>, usize);
+index_operators!(Matrix3<S>, 3, 3, Vector3<S>, usize);
+index_operators!(Matrix4<S>, 4, 4, Vector4<S>, usize);
+index_operators!(Matrix2x3<S>, 3, 2, Vector2<S>, usize);
+index_operators!(Matrix3x2<S>, 2, 3, Vector3<S>, usize);
+index_operators!(Matrix2x4<S>, 4, 2, Vector2<S>, usize);
+index_operators!(Matrix4x2<S>, 2, 4, Vector4<S>, usize);
+index_operators!(Matrix3x4<S>, 4, 3, Vector3<S>, usize);
+index_operators!(Matrix4x3<S>, 3, 4, Vector4<S>, usize);
+// index_operators!(Matrix2<S>, 2, 2, [Vector2<S>], Range<usize>);
+// index_operators!(Matrix3<S>, 3, 3, [Vector3<S>], Range<usize>);
+// index_operators!(Matrix4<S>, 4, 4, [Vector4<S>], Range<usize>);
+// index_operators!(Matrix2<S>, 2, 2, [Vector2<S>], RangeTo<usize>);
+// index_operators!(Matrix3<S>, 3, 3, [Vector3<S>], RangeTo<usize>);
+// index_operators!(Matrix4<S>, 4, 4, [Vector4<S>], RangeTo<usize>);
+// index_operators!(Matrix2<S>, 2, 2, [Vector2<S>], RangeFrom<usize>);
+// index_operators!(Matrix3<S>, 3, 3, [Vector3<S>], RangeFrom<usize>);
+// index_operators!(Matrix4<S>, 4, 4, [Vector4<S>], RangeFrom<usize>);
+// index_operators!(Matrix2<S>, 2, 2, [Vector2<S>], RangeFull);
+// index_operators!(Matrix3<S>, 3, 3, [Vector3<S>], RangeFull);
+// index_operators!(Matrix4<S>, 4, 4, [Vector4<S>], RangeFull);
 
 impl<A> From<Euler<A>> for Matrix3<<A as Angle>::Unitless> where
     A: Angle + Into<Rad<<A as Angle>::Unitless>>,
@@ -1107,102 +1617,137 @@ impl<A> From<Euler<A>> for Matrix4<<A as Angle>::Unitless> where
     }
 }
 
+impl<A> From<EulerSequence<A>> for Matrix3<<A as Angle>::Unitless> where
+    A: Angle + Into<Rad<<A as Angle>::Unitless>>,
+{
+    fn from(src: EulerSequence<A>) -> Matrix3<A::Unitless> {
+        let axes = src.order.axes();
+        let from_axis_angle = |axis, theta: A| match axis {
+            0 => Matrix3::from_angle_x(theta),
+            1 => Matrix3::from_angle_y(theta),
+            _ => Matrix3::from_angle_z(theta),
+        };
+        let first = from_axis_angle(axes[0], src.first);
+        let second = from_axis_angle(axes[1], src.second);
+        let third = from_axis_angle(axes[2], src.third);
+
+        match src.frame {
+            EulerFrame::Intrinsic => first * second * third,
+            EulerFrame::Extrinsic => third * second * first,
+        }
+    }
+}
+
+impl<A> From<EulerSequence<A>> for Matrix4<<A as Angle>::Unitless> where
+    A: Angle + Into<Rad<<A as Angle>::Unitless>>,
+{
+    fn from(src: EulerSequence<A>) -> Matrix4<A::Unitless> {
+        Matrix3::from(src).into()
+    }
+}
+
 macro_rules! fixed_array_conversions {
-    ($MatrixN:ident <$S:ident> { $($field:ident : $index:expr),+ }, $n:expr) => {
-        impl<$S> Into<[[$S; $n]; $n]> for $MatrixN<$S> {
+    ($MatrixN:ident <$S:ident> { $($field:ident : $index:expr),+ }, $cols:expr, $rows:expr) => {
+        impl<$S> Into<[[$S; $rows]; $cols]> for $MatrixN<$S> {
             #[inline]
-            fn into(self) -> [[$S; $n]; $n] {
+            fn into(self) -> [[$S; $rows]; $cols] {
                 match self { $MatrixN { $($field),+ } => [$($field.into()),+] }
             }
         }
 
-        impl<$S> AsRef<[[$S; $n]; $n]> for $MatrixN<$S> {
+        impl<$S> AsRef<[[$S; $rows]; $cols]> for $MatrixN<$S> {
             #[inline]
-            fn as_ref(&self) -> &[[$S; $n]; $n] {
+            fn as_ref(&self) -> &[[$S; $rows]; $cols] {
                 unsafe { mem::transmute(self) }
             }
         }
 
-        impl<$S> AsMut<[[$S; $n]; $n]> for $MatrixN<$S> {
+        impl<$S> AsMut<[[$S; $rows]; $cols]> for $MatrixN<$S> {
             #[inline]
-            fn as_mut(&mut self) -> &mut [[$S; $n]; $n] {
+            fn as_mut(&mut self) -> &mut [[$S; $rows]; $cols] {
                 unsafe { mem::transmute(self) }
             }
         }
 
-        impl<$S: Copy> From<[[$S; $n]; $n]> for $MatrixN<$S> {
+        impl<$S: Copy> From<[[$S; $rows]; $cols]> for $MatrixN<$S> {
             #[inline]
-            fn from(m: [[$S; $n]; $n]) -> $MatrixN<$S> {
+            fn from(m: [[$S; $rows]; $cols]) -> $MatrixN<$S> {
                 // We need to use a copy here because we can't pattern match on arrays yet
                 $MatrixN { $($field: From::from(m[$index])),+ }
             }
         }
 
-        impl<'a, $S> From<&'a [[$S; $n]; $n]> for &'a $MatrixN<$S> {
+        impl<'a, $S> From<&'a [[$S; $rows]; $cols]> for &'a $MatrixN<$S> {
             #[inline]
-            fn from(m: &'a [[$S; $n]; $n]) -> &'a $MatrixN<$S> {
+            fn from(m: &'a [[$S; $rows]; $cols]) -> &'a $MatrixN<$S> {
                 unsafe { mem::transmute(m) }
             }
         }
 
-        impl<'a, $S> From<&'a mut [[$S; $n]; $n]> for &'a mut $MatrixN<$S> {
+        impl<'a, $S> From<&'a mut [[$S; $rows]; $cols]> for &'a mut $MatrixN<$S> {
             #[inline]
-            fn from(m: &'a mut [[$S; $n]; $n]) -> &'a mut $MatrixN<$S> {
+            fn from(m: &'a mut [[$S; $rows]; $cols]) -> &'a mut $MatrixN<$S> {
                 unsafe { mem::transmute(m) }
             }
         }
 
-        // impl<$S> Into<[$S; ($n * $n)]> for $MatrixN<$S> {
+        // impl<$S> Into<[$S; ($cols * $rows)]> for $MatrixN<$S> {
         //     #[inline]
-        //     fn into(self) -> [[$S; $n]; $n] {
+        //     fn into(self) -> [[$S; $rows]; $cols] {
         //         // TODO: Not sure how to implement this...
         //         unimplemented!()
         //     }
         // }
 
-        impl<$S> AsRef<[$S; ($n * $n)]> for $MatrixN<$S> {
+        impl<$S> AsRef<[$S; ($cols * $rows)]> for $MatrixN<$S> {
             #[inline]
-            fn as_ref(&self) -> &[$S; ($n * $n)] {
+            fn as_ref(&self) -> &[$S; ($cols * $rows)] {
                 unsafe { mem::transmute(self) }
             }
         }
 
-        impl<$S> AsMut<[$S; ($n * $n)]> for $MatrixN<$S> {
+        impl<$S> AsMut<[$S; ($cols * $rows)]> for $MatrixN<$S> {
             #[inline]
-            fn as_mut(&mut self) -> &mut [$S; ($n * $n)] {
+            fn as_mut(&mut self) -> &mut [$S; ($cols * $rows)] {
                 unsafe { mem::transmute(self) }
             }
         }
 
-        // impl<$S> From<[$S; ($n * $n)]> for $MatrixN<$S> {
+        // impl<$S> From<[$S; ($cols * $rows)]> for $MatrixN<$S> {
         //     #[inline]
-        //     fn from(m: [$S; ($n * $n)]) -> $MatrixN<$S> {
+        //     fn from(m: [$S; ($cols * $rows)]) -> $MatrixN<$S> {
         //         // TODO: Not sure how to implement this...
         //         unimplemented!()
         //     }
         // }
 
-        impl<'a, $S> From<&'a [$S; ($n * $n)]> for &'a $MatrixN<$S> {
+        impl<'a, $S> From<&'a [$S; ($cols * $rows)]> for &'a $MatrixN<$S> {
             #[inline]
-            fn from(m: &'a [$S; ($n * $n)]) -> &'a $MatrixN<$S> {
+            fn from(m: &'a [$S; ($cols * $rows)]) -> &'a $MatrixN<$S> {
                 unsafe { mem::transmute(m) }
             }
         }
 
-        impl<'a, $S> From<&'a mut [$S; ($n * $n)]> for &'a mut $MatrixN<$S> {
+        impl<'a, $S> From<&'a mut [$S; ($cols * $rows)]> for &'a mut $MatrixN<$S> {
             #[inline]
-            fn from(m: &'a mut [$S; ($n * $n)]) -> &'a mut $MatrixN<$S> {
+            fn from(m: &'a mut [$S; ($cols * $rows)]) -> &'a mut $MatrixN<$S> {
                 unsafe { mem::transmute(m) }
             }
         }
     }
 }
 
-fixed_array_conversions!(Matrix2<S> { x:0, y:1 }, 2);
-fixed_array_conversions!(Matrix3<S> { x:0, y:1, z:2 }, 3);
-fixed_array_conversions!(Matrix4<S> { x:0, y:1, z:2, w:3 }, 4);
-
-impl<S: BaseFloat> From<Matrix2<S>> for Matrix3<S> {
+fixed_array_conversions!(Matrix2<S> { x:0, y:1 }, 2, 2);
+fixed_array_conversions!(Matrix3<S> { x:0, y:1, z:2 }, 3, 3);
+fixed_array_conversions!(Matrix4<S> { x:0, y:1, z:2, w:3 }, 4, 4);
+fixed_array_conversions!(Matrix2x3<S> { x:0, y:1, z:2 }, 3, 2);
+fixed_array_conversions!(Matrix3x2<S> { x:0, y:1 }, 2, 3);
+fixed_array_conversions!(Matrix2x4<S> { x:0, y:1, z:2, w:3 }, 4, 2);
+fixed_array_conversions!(Matrix4x2<S> { x:0, y:1 }, 2, 4);
+fixed_array_conversions!(Matrix3x4<S> { x:0, y:1, z:2, w:3 }, 4, 3);
+fixed_array_conversions!(Matrix4x3<S> { x:0, y:1, z:2 }, 3, 4);
+
+impl<S: BaseNum> From<Matrix2<S>> for Matrix3<S> {
     /// Clone the elements of a 2-dimensional matrix into the top-left corner
     /// of a 3-dimensional identity matrix.
     fn from(m: Matrix2<S>) -> Matrix3<S> {
@@ -1212,7 +1757,7 @@ impl<S: BaseFloat> From<Matrix2<S>> for Matrix3<S> {
     }
 }
 
-impl<S: BaseFloat> From<Matrix2<S>> for Matrix4<S> {
+impl<S: BaseNum> From<Matrix2<S>> for Matrix4<S> {
     /// Clone the elements of a 2-dimensional matrix into the top-left corner
     /// of a 4-dimensional identity matrix.
     fn from(m: Matrix2<S>) -> Matrix4<S> {
@@ -1223,7 +1768,7 @@ impl<S: BaseFloat> From<Matrix2<S>> for Matrix4<S> {
     }
 }
 
-impl<S: BaseFloat> From<Matrix3<S>> for Matrix4<S> {
+impl<S: BaseNum> From<Matrix3<S>> for Matrix4<S> {
     /// Clone the elements of a 3-dimensional matrix into the top-left corner
     /// of a 4-dimensional identity matrix.
     fn from(m: Matrix3<S>) -> Matrix4<S> {
@@ -1234,6 +1779,23 @@ impl<S: BaseFloat> From<Matrix3<S>> for Matrix4<S> {
     }
 }
 
+impl<S: BaseNum> From<Matrix4<S>> for Matrix3x4<S> {
+    /// Drop the bottom row of an affine 4-dimensional matrix, leaving the
+    /// compact 3 x 4 form.
+    fn from(m: Matrix4<S>) -> Matrix3x4<S> {
+        Matrix3x4::from_cols(m.x.truncate(), m.y.truncate(), m.z.truncate(), m.w.truncate())
+    }
+}
+
+impl<S: BaseNum> From<Matrix3x4<S>> for Matrix4<S> {
+    /// Expand a compact 3 x 4 affine matrix into a 4-dimensional matrix, with
+    /// `[0, 0, 0, 1]` as the bottom row.
+    fn from(m: Matrix3x4<S>) -> Matrix4<S> {
+        Matrix4::from_cols(m.x.extend(S::zero()), m.y.extend(S::zero()),
+                           m.z.extend(S::zero()), m.w.extend(S::one()))
+    }
+}
+
 impl<S: BaseFloat> From<Matrix3<S>> for Quaternion<S> {
     /// Convert the matrix to a quaternion
     fn from(mat: Matrix3<S>) -> Quaternion<S> {
@@ -1298,6 +1860,48 @@ impl<S: fmt::Debug> fmt::Debug for Matrix4<S> {
     }
 }
 
+impl<S: fmt::Debug> fmt::Debug for Matrix2x3<S> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        try!(write!(f, "Matrix2x3 "));
+        <[[S; 2]; 3] as fmt::Debug>::fmt(self.as_ref(), f)
+    }
+}
+
+impl<S: fmt::Debug> fmt::Debug for Matrix3x2<S> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        try!(write!(f, "Matrix3x2 "));
+        <[[S; 3]; 2] as fmt::Debug>::fmt(self.as_ref(), f)
+    }
+}
+
+impl<S: fmt::Debug> fmt::Debug for Matrix2x4<S> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        try!(write!(f, "Matrix2x4 "));
+        <[[S; 2]; 4] as fmt::Debug>::fmt(self.as_ref(), f)
+    }
+}
+
+impl<S: fmt::Debug> fmt::Debug for Matrix4x2<S> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        try!(write!(f, "Matrix4x2 "));
+        <[[S; 4]; 2] as fmt::Debug>::fmt(self.as_ref(), f)
+    }
+}
+
+impl<S: fmt::Debug> fmt::Debug for Matrix3x4<S> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        try!(write!(f, "Matrix3x4 "));
+        <[[S; 3]; 4] as fmt::Debug>::fmt(self.as_ref(), f)
+    }
+}
+
+impl<S: fmt::Debug> fmt::Debug for Matrix4x3<S> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        try!(write!(f, "Matrix4x3 "));
+        <[[S; 4]; 3] as fmt::Debug>::fmt(self.as_ref(), f)
+    }
+}
+
 impl<S: BaseFloat + Rand> Rand for Matrix2<S> {
     #[inline]
     fn rand<R: Rng>(rng: &mut R) -> Matrix2<S> {
@@ -1318,3 +1922,45 @@ impl<S: BaseFloat + Rand> Rand for Matrix4<S> {
         Matrix4{ x: rng.gen(), y: rng.gen(), z: rng.gen(), w: rng.gen() }
     }
 }
+
+impl<S: BaseFloat + Rand> Rand for Matrix2x3<S> {
+    #[inline]
+    fn rand<R: Rng>(rng: &mut R) -> Matrix2x3<S> {
+        Matrix2x3{ x: rng.gen(), y: rng.gen(), z: rng.gen() }
+    }
+}
+
+impl<S: BaseFloat + Rand> Rand for Matrix3x2<S> {
+    #[inline]
+    fn rand<R: Rng>(rng: &mut R) -> Matrix3x2<S> {
+        Matrix3x2{ x: rng.gen(), y: rng.gen() }
+    }
+}
+
+impl<S: BaseFloat + Rand> Rand for Matrix2x4<S> {
+    #[inline]
+    fn rand<R: Rng>(rng: &mut R) -> Matrix2x4<S> {
+        Matrix2x4{ x: rng.gen(), y: rng.gen(), z: rng.gen(), w: rng.gen() }
+    }
+}
+
+impl<S: BaseFloat + Rand> Rand for Matrix4x2<S> {
+    #[inline]
+    fn rand<R: Rng>(rng: &mut R) -> Matrix4x2<S> {
+        Matrix4x2{ x: rng.gen(), y: rng.gen() }
+    }
+}
+
+impl<S: BaseFloat + Rand> Rand for Matrix3x4<S> {
+    #[inline]
+    fn rand<R: Rng>(rng: &mut R) -> Matrix3x4<S> {
+        Matrix3x4{ x: rng.gen(), y: rng.gen(), z: rng.gen(), w: rng.gen() }
+    }
+}
+
+impl<S: BaseFloat + Rand> Rand for Matrix4x3<S> {
+    #[inline]
+    fn rand<R: Rng>(rng: &mut R) -> Matrix4x3<S> {
+        Matrix4x3{ x: rng.gen(), y: rng.gen(), z: rng.gen() }
+    }
+}
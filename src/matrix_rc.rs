@@ -0,0 +1,373 @@
+// Copyright 2016 The CGMath Developers. For a full listing of the authors,
+// refer to the Cargo.toml file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Matrices of any size, using const generics.
+//!
+//! This module is only compiled with the `const-generics` feature, which
+//! requires Rust 1.63 or later.
+
+use num_traits::{cast, NumCast};
+use std::array;
+use std::fmt;
+use std::ops::*;
+
+use structure::*;
+
+use approx::ApproxEq;
+use matrix::{Matrix2, Matrix3, Matrix4};
+use matrix::{Matrix2x3, Matrix2x4, Matrix3x2, Matrix3x4, Matrix4x2, Matrix4x3};
+use num::BaseFloat;
+use vector_n::VectorN;
+
+/// A column-major matrix with `R` rows and `C` columns.
+///
+/// This type is marked as `#[repr(C)]`.
+#[repr(C)]
+#[derive(Copy, Clone, PartialEq)]
+pub struct MatrixRC<S, const R: usize, const C: usize> {
+    /// The columns of the matrix.
+    pub columns: [VectorN<S, R>; C],
+}
+
+impl<S, const R: usize, const C: usize> MatrixRC<S, R, C> {
+    /// Create a new matrix, providing columns.
+    #[inline]
+    pub fn from_cols(columns: [VectorN<S, R>; C]) -> MatrixRC<S, R, C> {
+        MatrixRC { columns: columns }
+    }
+}
+
+impl<S: Copy, const R: usize, const C: usize> MatrixRC<S, R, C> {
+    /// Apply `f` to each of the elements of the matrix.
+    #[inline]
+    pub fn map<T, F: FnMut(S) -> T>(self, mut f: F) -> MatrixRC<T, R, C> {
+        MatrixRC::from_cols(array::from_fn(|j| self.columns[j].map(&mut f)))
+    }
+
+    /// Combine the columns of the matrix with those of `other` using `f`.
+    #[inline]
+    fn zip<F: FnMut(VectorN<S, R>, VectorN<S, R>) -> VectorN<S, R>>(self, other: MatrixRC<S, R, C>, mut f: F) -> MatrixRC<S, R, C> {
+        MatrixRC::from_cols(array::from_fn(|j| f(self.columns[j], other.columns[j])))
+    }
+}
+
+impl<S: NumCast + Copy, const R: usize, const C: usize> MatrixRC<S, R, C> {
+    /// Component-wise casting to another type
+    #[inline]
+    pub fn cast<T: NumCast>(&self) -> MatrixRC<T, R, C> {
+        self.map(|x| NumCast::from(x).unwrap())
+    }
+}
+
+impl<S: BaseFloat, const R: usize, const C: usize> Zero for MatrixRC<S, R, C> {
+    #[inline]
+    fn zero() -> MatrixRC<S, R, C> {
+        MatrixRC::from_cols([VectorN::zero(); C])
+    }
+
+    #[inline]
+    fn is_zero(&self) -> bool {
+        ulps_eq!(self, &MatrixRC::zero())
+    }
+}
+
+impl<S: BaseFloat, const N: usize> One for MatrixRC<S, N, N> {
+    #[inline]
+    fn one() -> MatrixRC<S, N, N> {
+        MatrixRC::from_value(S::one())
+    }
+}
+
+impl<S: BaseFloat, const R: usize, const C: usize> VectorSpace for MatrixRC<S, R, C> {
+    type Scalar = S;
+}
+
+impl<S: BaseFloat, const R: usize, const C: usize> Matrix for MatrixRC<S, R, C> {
+    type Row = VectorN<S, C>;
+    type Column = VectorN<S, R>;
+    type Transpose = MatrixRC<S, C, R>;
+
+    #[inline]
+    fn row(&self, r: usize) -> VectorN<S, C> {
+        VectorN::new(array::from_fn(|j| self[j][r]))
+    }
+
+    #[inline]
+    fn swap_rows(&mut self, a: usize, b: usize) {
+        for column in &mut self.columns {
+            column.swap_elements(a, b);
+        }
+    }
+
+    #[inline]
+    fn swap_columns(&mut self, a: usize, b: usize) {
+        self.columns.swap(a, b);
+    }
+
+    #[inline]
+    fn swap_elements(&mut self, a: (usize, usize), b: (usize, usize)) {
+        let (ac, ar) = a;
+        let (bc, br) = b;
+        let tmp = self[ac][ar];
+        self[ac][ar] = self[bc][br];
+        self[bc][br] = tmp;
+    }
+
+    fn transpose(&self) -> MatrixRC<S, C, R> {
+        MatrixRC::from_cols(array::from_fn(|i| self.row(i)))
+    }
+}
+
+impl<S: BaseFloat, const N: usize> SquareMatrix for MatrixRC<S, N, N> {
+    type ColumnRow = VectorN<S, N>;
+
+    #[inline]
+    fn from_value(value: S) -> MatrixRC<S, N, N> {
+        MatrixRC::from_diagonal(VectorN::from_value(value))
+    }
+
+    #[inline]
+    fn from_diagonal(value: VectorN<S, N>) -> MatrixRC<S, N, N> {
+        let mut m = MatrixRC::zero();
+        for i in 0..N {
+            m[i][i] = value[i];
+        }
+        m
+    }
+
+    #[inline]
+    fn transpose_self(&mut self) {
+        for j in 0..N {
+            for i in j + 1..N {
+                self.swap_elements((j, i), (i, j));
+            }
+        }
+    }
+
+    /// The determinant of the matrix, computed by Gaussian elimination with
+    /// partial pivoting.
+    fn determinant(&self) -> S {
+        let mut a = *self;
+        let mut det = S::one();
+        for k in 0..N {
+            let pivot = (k..N).fold(k, |p, i| if a[k][i].abs() > a[k][p].abs() { i } else { p });
+            if a[k][pivot].is_zero() {
+                return S::zero();
+            }
+            if pivot != k {
+                a.swap_rows(k, pivot);
+                det = -det;
+            }
+            det = det * a[k][k];
+            for i in k + 1..N {
+                let factor = a[k][i] / a[k][k];
+                for j in k..N {
+                    a[j][i] = a[j][i] - factor * a[j][k];
+                }
+            }
+        }
+        det
+    }
+
+    #[inline]
+    fn diagonal(&self) -> VectorN<S, N> {
+        VectorN::new(array::from_fn(|i| self[i][i]))
+    }
+
+    /// The inverse of the matrix, computed by Gauss-Jordan elimination with
+    /// partial pivoting.
+    fn invert(&self) -> Option<MatrixRC<S, N, N>> {
+        if ulps_eq!(self.determinant(), &S::zero()) {
+            return None;
+        }
+
+        // Reduce `a` to the identity, applying the same row operations to
+        // `inverse`.
+        let mut a = *self;
+        let mut inverse = MatrixRC::identity();
+        for k in 0..N {
+            let pivot = (k..N).fold(k, |p, i| if a[k][i].abs() > a[k][p].abs() { i } else { p });
+            a.swap_rows(k, pivot);
+            inverse.swap_rows(k, pivot);
+
+            let scale = a[k][k].recip();
+            for j in 0..N {
+                a[j][k] = a[j][k] * scale;
+                inverse[j][k] = inverse[j][k] * scale;
+            }
+            for i in (0..N).filter(|&i| i != k) {
+                let factor = a[k][i];
+                for j in 0..N {
+                    a[j][i] = a[j][i] - factor * a[j][k];
+                    inverse[j][i] = inverse[j][i] - factor * inverse[j][k];
+                }
+            }
+        }
+        Some(inverse)
+    }
+
+    fn is_diagonal(&self) -> bool {
+        (0..N).all(|j| (0..N).all(|i| i == j || ulps_eq!(self[j][i], &S::zero())))
+    }
+
+    fn is_symmetric(&self) -> bool {
+        (0..N).all(|j| (0..N).all(|i| ulps_eq!(self[j][i], &self[i][j])))
+    }
+}
+
+impl<S: BaseFloat, const R: usize, const C: usize> ApproxEq for MatrixRC<S, R, C> {
+    type Epsilon = S::Epsilon;
+
+    #[inline]
+    fn default_epsilon() -> S::Epsilon {
+        cast(1.0e-6f64).unwrap()
+    }
+
+    #[inline]
+    fn default_max_relative() -> S::Epsilon {
+        S::default_max_relative()
+    }
+
+    #[inline]
+    fn default_max_ulps() -> u32 {
+        S::default_max_ulps()
+    }
+
+    #[inline]
+    fn relative_eq(&self, other: &Self, epsilon: S::Epsilon, max_relative: S::Epsilon) -> bool {
+        (0..C).all(|j| VectorN::relative_eq(&self[j], &other[j], epsilon, max_relative))
+    }
+
+    #[inline]
+    fn ulps_eq(&self, other: &Self, epsilon: S::Epsilon, max_ulps: u32) -> bool {
+        (0..C).all(|j| VectorN::ulps_eq(&self[j], &other[j], epsilon, max_ulps))
+    }
+}
+
+impl_const_generic_operator!([S: BaseFloat, const R: usize, const C: usize] Neg for MatrixRC<S, R, C> {
+    fn neg(matrix) -> MatrixRC<S, R, C> { matrix.map(|x| -x) }
+});
+
+impl_const_generic_operator!([S: BaseFloat, const R: usize, const C: usize] Add<MatrixRC<S, R, C> > for MatrixRC<S, R, C> {
+    fn add(lhs, rhs) -> MatrixRC<S, R, C> { lhs.zip(rhs, Add::add) }
+});
+impl_const_generic_operator!([S: BaseFloat, const R: usize, const C: usize] Sub<MatrixRC<S, R, C> > for MatrixRC<S, R, C> {
+    fn sub(lhs, rhs) -> MatrixRC<S, R, C> { lhs.zip(rhs, Sub::sub) }
+});
+impl_const_generic_operator!([S: BaseFloat, const R: usize, const C: usize] Mul<S> for MatrixRC<S, R, C> {
+    fn mul(matrix, scalar) -> MatrixRC<S, R, C> { matrix.map(|x| x * scalar) }
+});
+impl_const_generic_operator!([S: BaseFloat, const R: usize, const C: usize] Div<S> for MatrixRC<S, R, C> {
+    fn div(matrix, scalar) -> MatrixRC<S, R, C> { matrix.map(|x| x / scalar) }
+});
+impl_const_generic_operator!([S: BaseFloat, const R: usize, const C: usize] Rem<S> for MatrixRC<S, R, C> {
+    fn rem(matrix, scalar) -> MatrixRC<S, R, C> { matrix.map(|x| x % scalar) }
+});
+
+impl_const_generic_operator!([S: BaseFloat, const R: usize, const C: usize] Mul<VectorN<S, C> > for MatrixRC<S, R, C> {
+    fn mul(matrix, vector) -> VectorN<S, R> {
+        (0..C).fold(VectorN::zero(), |sum, j| sum + matrix[j] * vector[j])
+    }
+});
+
+impl_const_generic_operator!([S: BaseFloat, const R: usize, const K: usize, const C: usize] Mul<MatrixRC<S, K, C> > for MatrixRC<S, R, K> {
+    fn mul(lhs, rhs) -> MatrixRC<S, R, C> { MatrixRC::from_cols(array::from_fn(|j| lhs * rhs[j])) }
+});
+
+impl<S: BaseFloat, const R: usize, const C: usize> AddAssign<MatrixRC<S, R, C>> for MatrixRC<S, R, C> {
+    fn add_assign(&mut self, other: MatrixRC<S, R, C>) { for j in 0..C { self[j] += other[j]; } }
+}
+
+impl<S: BaseFloat, const R: usize, const C: usize> SubAssign<MatrixRC<S, R, C>> for MatrixRC<S, R, C> {
+    fn sub_assign(&mut self, other: MatrixRC<S, R, C>) { for j in 0..C { self[j] -= other[j]; } }
+}
+
+impl<S: BaseFloat, const R: usize, const C: usize> MulAssign<S> for MatrixRC<S, R, C> {
+    fn mul_assign(&mut self, scalar: S) { for j in 0..C { self[j] *= scalar; } }
+}
+
+impl<S: BaseFloat, const R: usize, const C: usize> DivAssign<S> for MatrixRC<S, R, C> {
+    fn div_assign(&mut self, scalar: S) { for j in 0..C { self[j] /= scalar; } }
+}
+
+impl<S: BaseFloat, const R: usize, const C: usize> RemAssign<S> for MatrixRC<S, R, C> {
+    fn rem_assign(&mut self, scalar: S) { for j in 0..C { self[j] %= scalar; } }
+}
+
+impl<S, const R: usize, const C: usize> Index<usize> for MatrixRC<S, R, C> {
+    type Output = VectorN<S, R>;
+
+    #[inline]
+    fn index(&self, i: usize) -> &VectorN<S, R> {
+        &self.columns[i]
+    }
+}
+
+impl<S, const R: usize, const C: usize> IndexMut<usize> for MatrixRC<S, R, C> {
+    #[inline]
+    fn index_mut(&mut self, i: usize) -> &mut VectorN<S, R> {
+        &mut self.columns[i]
+    }
+}
+
+impl<S: Copy, const R: usize, const C: usize> Into<[[S; R]; C]> for MatrixRC<S, R, C> {
+    #[inline]
+    fn into(self) -> [[S; R]; C] {
+        array::from_fn(|j| self.columns[j].elements)
+    }
+}
+
+impl<S: Copy, const R: usize, const C: usize> From<[[S; R]; C]> for MatrixRC<S, R, C> {
+    #[inline]
+    fn from(m: [[S; R]; C]) -> MatrixRC<S, R, C> {
+        MatrixRC::from_cols(array::from_fn(|j| VectorN::new(m[j])))
+    }
+}
+
+impl<S: fmt::Debug, const R: usize, const C: usize> fmt::Debug for MatrixRC<S, R, C> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        try!(write!(f, "MatrixRC "));
+        f.debug_list().entries(self.columns.iter().map(|c| &c.elements)).finish()
+    }
+}
+
+macro_rules! impl_named_conversions {
+    ($MatrixN:ident { $($field:ident),+ }, $rows:expr, $cols:expr) => {
+        impl<S: Copy> From<$MatrixN<S>> for MatrixRC<S, $rows, $cols> {
+            #[inline]
+            fn from(m: $MatrixN<S>) -> MatrixRC<S, $rows, $cols> {
+                MatrixRC::from_cols([$(m.$field.into()),+])
+            }
+        }
+
+        impl<S: Copy> From<MatrixRC<S, $rows, $cols>> for $MatrixN<S> {
+            #[inline]
+            fn from(m: MatrixRC<S, $rows, $cols>) -> $MatrixN<S> {
+                let [$($field),+] = m.columns;
+                $MatrixN { $($field: $field.into()),+ }
+            }
+        }
+    }
+}
+
+impl_named_conversions!(Matrix2 { x, y }, 2, 2);
+impl_named_conversions!(Matrix3 { x, y, z }, 3, 3);
+impl_named_conversions!(Matrix4 { x, y, z, w }, 4, 4);
+impl_named_conversions!(Matrix2x3 { x, y, z }, 2, 3);
+impl_named_conversions!(Matrix3x2 { x, y }, 3, 2);
+impl_named_conversions!(Matrix2x4 { x, y, z, w }, 2, 4);
+impl_named_conversions!(Matrix4x2 { x, y }, 4, 2);
+impl_named_conversions!(Matrix3x4 { x, y, z, w }, 3, 4);
+impl_named_conversions!(Matrix4x3 { x, y, z }, 4, 3);
@@ -0,0 +1,117 @@
+// Copyright 2016 The CGMath Developers. For a full listing of the authors,
+// refer to the Cargo.toml file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use num_traits::Zero;
+
+use structure::*;
+
+use approx::ApproxEq;
+use num::BaseFloat;
+use point::Point3;
+use vector::Vector3;
+
+/// A plane in 3-dimensional space, made up of the points `p` for which
+/// `normal.dot(p.to_vec()) == distance`.
+///
+/// The normal is expected to be of unit length, in which case `distance` is
+/// the signed distance from the origin to the plane along the normal.
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "rustc-serialize", derive(RustcEncodable, RustcDecodable))]
+#[cfg_attr(feature = "eders", derive(Serialize, Deserialize))]
+pub struct Plane<S> {
+    /// The unit normal of the plane.
+    pub normal: Vector3<S>,
+    /// The signed distance from the origin to the plane along the normal.
+    pub distance: S,
+}
+
+impl<S: BaseFloat> Plane<S> {
+    /// Construct a plane from a unit normal and a distance from the origin.
+    #[inline]
+    pub fn new(normal: Vector3<S>, distance: S) -> Plane<S> {
+        Plane { normal: normal, distance: distance }
+    }
+
+    /// Construct a plane passing through `point`, facing in the direction of
+    /// `normal`. The normal does not need to be of unit length.
+    #[inline]
+    pub fn from_point_normal(point: Point3<S>, normal: Vector3<S>) -> Plane<S> {
+        let normal = normal.normalize();
+        Plane::new(normal, normal.dot(point.to_vec()))
+    }
+
+    /// Construct a plane passing through three points. The normal faces the
+    /// side from which the points appear in counter-clockwise order.
+    ///
+    /// Returns `None` if the points are collinear.
+    pub fn from_points(a: Point3<S>, b: Point3<S>, c: Point3<S>) -> Option<Plane<S>> {
+        let normal = (b - a).cross(c - a);
+        if normal.is_zero() {
+            None
+        } else {
+            Some(Plane::from_point_normal(a, normal))
+        }
+    }
+
+    /// The signed distance from the plane to `point`, which is positive on
+    /// the side the normal faces.
+    #[inline]
+    pub fn signed_distance(&self, point: Point3<S>) -> S {
+        self.normal.dot(point.to_vec()) - self.distance
+    }
+
+    /// The point on the plane closest to `point`.
+    #[inline]
+    pub fn project_point(&self, point: Point3<S>) -> Point3<S> {
+        point + self.normal * -self.signed_distance(point)
+    }
+
+    /// The same plane, with its normal facing the other way.
+    #[inline]
+    pub fn flip(&self) -> Plane<S> {
+        Plane::new(-self.normal, -self.distance)
+    }
+}
+
+impl<S: BaseFloat> ApproxEq for Plane<S> {
+    type Epsilon = S::Epsilon;
+
+    #[inline]
+    fn default_epsilon() -> S::Epsilon {
+        S::default_epsilon()
+    }
+
+    #[inline]
+    fn default_max_relative() -> S::Epsilon {
+        S::default_max_relative()
+    }
+
+    #[inline]
+    fn default_max_ulps() -> u32 {
+        S::default_max_ulps()
+    }
+
+    #[inline]
+    fn relative_eq(&self, other: &Self, epsilon: S::Epsilon, max_relative: S::Epsilon) -> bool {
+        Vector3::relative_eq(&self.normal, &other.normal, epsilon, max_relative) &&
+        S::relative_eq(&self.distance, &other.distance, epsilon, max_relative)
+    }
+
+    #[inline]
+    fn ulps_eq(&self, other: &Self, epsilon: S::Epsilon, max_ulps: u32) -> bool {
+        Vector3::ulps_eq(&self.normal, &other.normal, epsilon, max_ulps) &&
+        S::ulps_eq(&self.distance, &other.distance, epsilon, max_ulps)
+    }
+}
@@ -25,6 +25,8 @@ use std::ops::*;
 use structure::*;
 
 use approx::ApproxEq;
+#[cfg(feature = "fixed-point")]
+use fixed::{Fixed32, Fixed64};
 use num::{BaseNum, BaseFloat};
 use vector::{Vector1, Vector2, Vector3, Vector4};
 
@@ -64,6 +66,34 @@ pub struct Point3<S> {
     pub z: S,
 }
 
+/// A point in 3-dimensional projective space, in homogeneous coordinates.
+///
+/// A point with a non-zero `w` is the point `(x / w, y / w, z / w)` in
+/// 3-dimensional space, and every non-zero multiple of its coordinates is the
+/// same point. A `w` of zero represents the point at infinity in the
+/// direction `(x, y, z)`.
+///
+/// This is not a `EuclideanSpace`, as there are no displacements or distances
+/// between projective points: convert them with `to_point3` first. Use
+/// `Matrix4::transform_point4` to transform them.
+///
+/// `==`, `Hash` and the `ApproxEq` comparisons compare the homogeneous
+/// coordinates as they are stored, so `Point4::new(1, 2, 3, 1)` and
+/// `Point4::new(2, 4, 6, 2)` are not equal, even though they represent the
+/// same point.
+///
+/// This type is marked as `#[repr(C)]`.
+#[repr(C)]
+#[derive(PartialEq, Eq, Copy, Clone, Hash)]
+#[cfg_attr(feature = "rustc-serialize", derive(RustcEncodable, RustcDecodable))]
+#[cfg_attr(feature = "eders", derive(Serialize, Deserialize))]
+pub struct Point4<S> {
+    pub x: S,
+    pub y: S,
+    pub z: S,
+    pub w: S,
+}
+
 impl<S: BaseNum> Point1<S> {
     #[inline]
     pub fn new(x: S) -> Point1<S> {
@@ -85,6 +115,50 @@ impl<S: BaseNum> Point3<S> {
     }
 }
 
+impl<S: BaseNum> Point4<S> {
+    #[inline]
+    pub fn new(x: S, y: S, z: S, w: S) -> Point4<S> {
+        Point4 { x: x, y: y, z: z, w: w }
+    }
+
+    /// The point at infinity in the direction of `v`.
+    #[inline]
+    pub fn at_infinity(v: Vector3<S>) -> Point4<S> {
+        Point4::new(v.x, v.y, v.z, S::zero())
+    }
+
+    /// Whether this is a point at infinity, with a `w` of exactly zero.
+    #[inline]
+    pub fn is_at_infinity(&self) -> bool {
+        self.w.is_zero()
+    }
+
+    /// Divide by `w`, returning the point in 3-dimensional space, or `None`
+    /// if this is a point at infinity.
+    #[inline]
+    pub fn to_point3(self) -> Option<Point3<S>> {
+        if self.is_at_infinity() {
+            None
+        } else {
+            Some(Point3::new(self.x / self.w, self.y / self.w, self.z / self.w))
+        }
+    }
+
+    /// The `x`, `y` and `z` coordinates, without dividing by `w`. For a point
+    /// at infinity, this is its direction.
+    #[inline]
+    pub fn truncate(self) -> Vector3<S> {
+        Vector3::new(self.x, self.y, self.z)
+    }
+}
+
+impl<S: BaseNum> From<Point3<S>> for Point4<S> {
+    #[inline]
+    fn from(p: Point3<S>) -> Point4<S> {
+        Point4::new(p.x, p.y, p.z, S::one())
+    }
+}
+
 impl<S: BaseNum> Point3<S> {
     #[inline]
     pub fn from_homogeneous(v: Vector4<S>) -> Point3<S> {
@@ -98,8 +172,10 @@ impl<S: BaseNum> Point3<S> {
     }
 }
 
-macro_rules! impl_point {
-    ($PointN:ident { $($field:ident),+ }, $VectorN:ident, $n:expr) => {
+// Utility macro for the array, comparison and indexing implementations shared
+// by the Euclidean and the projective points
+macro_rules! impl_point_array {
+    ($PointN:ident { $($field:ident),+ }, $n:expr) => {
         impl<S: BaseNum> Array for $PointN<S> {
             type Element = S;
 
@@ -108,6 +184,11 @@ macro_rules! impl_point {
                 $PointN { $($field: scalar),+ }
             }
 
+            #[inline]
+            fn len() -> usize {
+                $n
+            }
+
             #[inline]
             fn sum(self) -> S where S: Add<Output = S> {
                 fold_array!(add, { $(self.$field),+ })
@@ -137,66 +218,78 @@ macro_rules! impl_point {
             }
         }
 
-        impl<S: BaseFloat> MetricSpace for $PointN<S> {
-            type Metric = S;
+        impl<S: BaseFloat> ApproxEq for $PointN<S> {
+            type Epsilon = S::Epsilon;
 
             #[inline]
-            fn distance2(self, other: Self) -> S {
-                (other - self).magnitude2()
+            fn default_epsilon() -> S::Epsilon {
+                S::default_epsilon()
             }
-        }
-
-        impl<S: BaseNum> EuclideanSpace for $PointN<S> {
-            type Scalar = S;
-            type Diff = $VectorN<S>;
 
             #[inline]
-            fn origin() -> $PointN<S> {
-                $PointN { $($field: S::zero()),+ }
+            fn default_max_relative() -> S::Epsilon {
+                S::default_max_relative()
             }
 
             #[inline]
-            fn from_vec(v: $VectorN<S>) -> $PointN<S> {
-                $PointN::new($(v.$field),+)
+            fn default_max_ulps() -> u32 {
+                S::default_max_ulps()
             }
 
             #[inline]
-            fn to_vec(self) -> $VectorN<S> {
-                $VectorN::new($(self.$field),+)
+            fn relative_eq(&self, other: &Self, epsilon: S::Epsilon, max_relative: S::Epsilon) -> bool {
+                $(S::relative_eq(&self.$field, &other.$field, epsilon, max_relative))&&+
             }
 
             #[inline]
-            fn dot(self, v: $VectorN<S>) -> S {
-                $VectorN::new($(self.$field * v.$field),+).sum()
+            fn ulps_eq(&self, other: &Self, epsilon: S::Epsilon, max_ulps: u32) -> bool {
+                $(S::ulps_eq(&self.$field, &other.$field, epsilon, max_ulps))&&+
             }
         }
 
-        impl<S: BaseFloat> ApproxEq for $PointN<S> {
-            type Epsilon = S::Epsilon;
+        impl_index_operators!($PointN<S>, $n, S, usize);
+        impl_index_operators!($PointN<S>, $n, [S], Range<usize>);
+        impl_index_operators!($PointN<S>, $n, [S], RangeTo<usize>);
+        impl_index_operators!($PointN<S>, $n, [S], RangeFrom<usize>);
+        impl_index_operators!($PointN<S>, $n, [S], RangeFull);
+    }
+}
+
+macro_rules! impl_point {
+    ($PointN:ident { $($field:ident),+ }, $VectorN:ident, $n:expr) => {
+        impl_point_array!($PointN { $($field),+ }, $n);
+
+        impl<S: BaseFloat> MetricSpace for $PointN<S> {
+            type Metric = S;
 
             #[inline]
-            fn default_epsilon() -> S::Epsilon {
-                S::default_epsilon()
+            fn distance2(self, other: Self) -> S {
+                (other - self).magnitude2()
             }
+        }
+
+        impl<S: BaseNum> EuclideanSpace for $PointN<S> {
+            type Scalar = S;
+            type Diff = $VectorN<S>;
 
             #[inline]
-            fn default_max_relative() -> S::Epsilon {
-                S::default_max_relative()
+            fn origin() -> $PointN<S> {
+                $PointN { $($field: S::zero()),+ }
             }
 
             #[inline]
-            fn default_max_ulps() -> u32 {
-                S::default_max_ulps()
+            fn from_vec(v: $VectorN<S>) -> $PointN<S> {
+                $PointN::new($(v.$field),+)
             }
 
             #[inline]
-            fn relative_eq(&self, other: &Self, epsilon: S::Epsilon, max_relative: S::Epsilon) -> bool {
-                $(S::relative_eq(&self.$field, &other.$field, epsilon, max_relative))&&+
+            fn to_vec(self) -> $VectorN<S> {
+                $VectorN::new($(self.$field),+)
             }
 
             #[inline]
-            fn ulps_eq(&self, other: &Self, epsilon: S::Epsilon, max_ulps: u32) -> bool {
-                $(S::ulps_eq(&self.$field, &other.$field, epsilon, max_ulps))&&+
+            fn dot(self, v: $VectorN<S>) -> S {
+                $VectorN::new($(self.$field * v.$field),+).sum()
             }
         }
 
@@ -242,12 +335,10 @@ macro_rules! impl_point {
         impl_scalar_ops!($PointN<i64> { $($field),+ });
         impl_scalar_ops!($PointN<f32> { $($field),+ });
         impl_scalar_ops!($PointN<f64> { $($field),+ });
-
-        impl_index_operators!($PointN<S>, $n, S, usize);
-        impl_index_operators!($PointN<S>, $n, [S], Range<usize>);
-        impl_index_operators!($PointN<S>, $n, [S], RangeTo<usize>);
-        impl_index_operators!($PointN<S>, $n, [S], RangeFrom<usize>);
-        impl_index_operators!($PointN<S>, $n, [S], RangeFull);
+        #[cfg(feature = "fixed-point")]
+        impl_scalar_ops!($PointN<Fixed32> { $($field),+ });
+        #[cfg(feature = "fixed-point")]
+        impl_scalar_ops!($PointN<Fixed64> { $($field),+ });
     }
 }
 
@@ -268,14 +359,24 @@ macro_rules! impl_scalar_ops {
 impl_point!(Point1 { x }, Vector1, 1);
 impl_point!(Point2 { x, y }, Vector2, 2);
 impl_point!(Point3 { x, y, z }, Vector3, 3);
+impl_point_array!(Point4 { x, y, z, w }, 4);
+
+#[cfg(feature = "swizzle")]
+impl_swizzle_functions!(Point1; Point2, Point3; x);
+#[cfg(feature = "swizzle")]
+impl_swizzle_functions!(Point2; Point2, Point3; x y);
+#[cfg(feature = "swizzle")]
+impl_swizzle_functions!(Point3; Point2, Point3; x y z);
 
 impl_fixed_array_conversions!(Point1<S> { x: 0 }, 1);
 impl_fixed_array_conversions!(Point2<S> { x: 0, y: 1 }, 2);
 impl_fixed_array_conversions!(Point3<S> { x: 0, y: 1, z: 2 }, 3);
+impl_fixed_array_conversions!(Point4<S> { x: 0, y: 1, z: 2, w: 3 }, 4);
 
 impl_tuple_conversions!(Point1<S> { x }, (S,));
 impl_tuple_conversions!(Point2<S> { x, y }, (S, S));
 impl_tuple_conversions!(Point3<S> { x, y, z }, (S, S, S));
+impl_tuple_conversions!(Point4<S> { x, y, z, w }, (S, S, S, S));
 
 impl<S: fmt::Debug> fmt::Debug for Point1<S> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
@@ -298,6 +399,13 @@ impl<S: fmt::Debug> fmt::Debug for Point3<S> {
     }
 }
 
+impl<S: fmt::Debug> fmt::Debug for Point4<S> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        try!(write!(f, "Point4 "));
+        <[S; 4] as fmt::Debug>::fmt(self.as_ref(), f)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     mod point2 {
@@ -16,11 +16,13 @@
 use num_traits::{Zero};
 use num_traits::cast;
 
-use structure::Angle;
+use structure::{Angle, SquareMatrix};
 
 use angle::Rad;
 use matrix::Matrix4;
 use num::BaseFloat;
+use point::Point3;
+use vector::Vector4;
 
 /// Create a perspective projection matrix.
 ///
@@ -35,6 +37,33 @@ pub fn perspective<S: BaseFloat, A: Into<Rad<S>>>(fovy: A, aspect: S, near: S, f
     }.into()
 }
 
+/// Create a perspective projection matrix, mapping depths to `depth`.
+///
+/// Passing `S::infinity()` as the `far` distance creates a projection with an
+/// infinite far plane.
+pub fn perspective_with_depth<S: BaseFloat, A: Into<Rad<S>>>(fovy: A, aspect: S, near: S, far: S, depth: DepthRange) -> Matrix4<S> {
+    PerspectiveFov {
+        fovy:   fovy.into(),
+        aspect: aspect,
+        near:   near,
+        far:    far,
+    }.to_matrix4(depth)
+}
+
+/// Create a perspective projection matrix for a left-handed view space,
+/// looking down the positive `z` axis, mapping depths to `depth`.
+///
+/// Passing `S::infinity()` as the `far` distance creates a projection with an
+/// infinite far plane.
+pub fn perspective_lh<S: BaseFloat, A: Into<Rad<S>>>(fovy: A, aspect: S, near: S, far: S, depth: DepthRange) -> Matrix4<S> {
+    PerspectiveFov {
+        fovy:   fovy.into(),
+        aspect: aspect,
+        near:   near,
+        far:    far,
+    }.to_matrix4_lh(depth)
+}
+
 /// Create a perspective matrix from a view frustrum.
 ///
 /// This is the equivalent of the now deprecated [glFrustrum]
@@ -50,6 +79,38 @@ pub fn frustum<S: BaseFloat>(left: S, right: S, bottom: S, top: S, near: S, far:
     }.into()
 }
 
+/// Create a perspective matrix from a view frustrum, mapping depths to
+/// `depth`.
+///
+/// Passing `S::infinity()` as the `far` distance creates a projection with an
+/// infinite far plane.
+pub fn frustum_with_depth<S: BaseFloat>(left: S, right: S, bottom: S, top: S, near: S, far: S, depth: DepthRange) -> Matrix4<S> {
+    Perspective {
+        left:   left,
+        right:  right,
+        bottom: bottom,
+        top:    top,
+        near:   near,
+        far:    far,
+    }.to_matrix4(depth)
+}
+
+/// Create a perspective matrix from a view frustrum, for a left-handed view
+/// space looking down the positive `z` axis, mapping depths to `depth`.
+///
+/// Passing `S::infinity()` as the `far` distance creates a projection with an
+/// infinite far plane.
+pub fn frustum_lh<S: BaseFloat>(left: S, right: S, bottom: S, top: S, near: S, far: S, depth: DepthRange) -> Matrix4<S> {
+    Perspective {
+        left:   left,
+        right:  right,
+        bottom: bottom,
+        top:    top,
+        near:   near,
+        far:    far,
+    }.to_matrix4_lh(depth)
+}
+
 /// Create an orthographic projection matrix.
 ///
 /// This is the equivalent of the now deprecated [glOrtho]
@@ -65,6 +126,171 @@ pub fn ortho<S: BaseFloat>(left: S, right: S, bottom: S, top: S, near: S, far: S
     }.into()
 }
 
+/// Create an orthographic projection matrix, mapping depths to `depth`.
+pub fn ortho_with_depth<S: BaseFloat>(left: S, right: S, bottom: S, top: S, near: S, far: S, depth: DepthRange) -> Matrix4<S> {
+    Ortho {
+        left:   left,
+        right:  right,
+        bottom: bottom,
+        top:    top,
+        near:   near,
+        far:    far,
+    }.to_matrix4(depth)
+}
+
+/// Create an orthographic projection matrix for a left-handed view space,
+/// looking down the positive `z` axis, mapping depths to `depth`.
+pub fn ortho_lh<S: BaseFloat>(left: S, right: S, bottom: S, top: S, near: S, far: S, depth: DepthRange) -> Matrix4<S> {
+    Ortho {
+        left:   left,
+        right:  right,
+        bottom: bottom,
+        top:    top,
+        near:   near,
+        far:    far,
+    }.to_matrix4_lh(depth)
+}
+
+/// Map `point` to window coordinates, using the `view_proj` matrix, which
+/// maps depths to `depth`, and the `viewport` rectangle.
+///
+/// The `x` and `y` window coordinates are measured from the origin of the
+/// viewport, with `y` pointing up, and the `z` coordinate is the depth,
+/// between `0` and `1`. This is the equivalent of the [gluProject]
+/// (https://www.opengl.org/sdk/docs/man2/xhtml/gluProject.xml) function.
+///
+/// Returns `None` if the point lies in the plane of the viewer, and has no
+/// projection.
+pub fn project<S: BaseFloat>(point: Point3<S>, view_proj: Matrix4<S>, viewport: Viewport<S>, depth: DepthRange) -> Option<Point3<S>> {
+    let clip = view_proj * point.to_homogeneous();
+    if clip.w.is_zero() {
+        return None;
+    }
+
+    let ndc = clip.truncate() / clip.w;
+    let two: S = cast(2).unwrap();
+    let z = match depth {
+        DepthRange::NegativeOneToOne => (ndc.z + S::one()) / two,
+        DepthRange::ZeroToOne | DepthRange::OneToZero => ndc.z,
+    };
+
+    Some(Point3::new(viewport.x + viewport.width * (ndc.x + S::one()) / two,
+                     viewport.y + viewport.height * (ndc.y + S::one()) / two,
+                     z))
+}
+
+/// Map the `window` coordinates back to the point they were projected from
+/// by `view_proj` and `viewport`. This is the inverse of `project`.
+///
+/// Returns `None` if `view_proj` cannot be inverted.
+pub fn unproject<S: BaseFloat>(window: Point3<S>, view_proj: Matrix4<S>, viewport: Viewport<S>, depth: DepthRange) -> Option<Point3<S>> {
+    view_proj.invert().and_then(|inverse| unproject_with_inverse(window, inverse, viewport, depth))
+}
+
+/// Map the `window` coordinates back to the point they were projected from,
+/// given the inverse of the view-projection matrix.
+///
+/// This avoids inverting the view-projection matrix, which loses precision
+/// for projections with distant far planes. The inverse can be built from
+/// the inverse view transform and the `to_inverse_matrix4` method of the
+/// projection.
+///
+/// Returns `None` if the point maps to infinity.
+pub fn unproject_with_inverse<S: BaseFloat>(window: Point3<S>, inverse_view_proj: Matrix4<S>, viewport: Viewport<S>, depth: DepthRange) -> Option<Point3<S>> {
+    let two: S = cast(2).unwrap();
+    let z = match depth {
+        DepthRange::NegativeOneToOne => window.z * two - S::one(),
+        DepthRange::ZeroToOne | DepthRange::OneToZero => window.z,
+    };
+    let ndc = Vector4::new(two * (window.x - viewport.x) / viewport.width - S::one(),
+                           two * (window.y - viewport.y) / viewport.height - S::one(),
+                           z,
+                           S::one());
+
+    let point = inverse_view_proj * ndc;
+    if point.w.is_zero() {
+        None
+    } else {
+        Some(Point3::from_homogeneous(point))
+    }
+}
+
+/// A rectangle of the window that normalized device coordinates are mapped
+/// onto, as set by `glViewport`.
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "rustc-serialize", derive(RustcEncodable, RustcDecodable))]
+#[cfg_attr(feature = "eders", derive(Serialize, Deserialize))]
+pub struct Viewport<S> {
+    pub x:      S,
+    pub y:      S,
+    pub width:  S,
+    pub height: S,
+}
+
+/// The range of normalized device coordinates that depths between the near
+/// and far planes are mapped to.
+///
+/// The projections converted with `From` and created by the free functions
+/// without a `depth` parameter all use `NegativeOneToOne`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "rustc-serialize", derive(RustcEncodable, RustcDecodable))]
+#[cfg_attr(feature = "eders", derive(Serialize, Deserialize))]
+pub enum DepthRange {
+    /// The near plane is mapped to `-1`, and the far plane to `1`, as in
+    /// OpenGL.
+    NegativeOneToOne,
+    /// The near plane is mapped to `0`, and the far plane to `1`, as in
+    /// Vulkan, Direct3D and Metal.
+    ZeroToOne,
+    /// The near plane is mapped to `1`, and the far plane to `0`. This is
+    /// known as reverse-Z, and distributes floating point depth precision far
+    /// more evenly than the other ranges.
+    OneToZero,
+}
+
+/// The entries in the third row of a perspective projection matrix that map
+/// view space depths between `-near` and `-far` to `depth`.
+fn perspective_depth<S: BaseFloat>(near: S, far: S, depth: DepthRange) -> (S, S) {
+    let two: S = cast(2).unwrap();
+
+    if far.is_infinite() {
+        match depth {
+            DepthRange::NegativeOneToOne => (-S::one(), -two * near),
+            DepthRange::ZeroToOne        => (-S::one(), -near),
+            DepthRange::OneToZero        => (S::zero(), near),
+        }
+    } else {
+        match depth {
+            DepthRange::NegativeOneToOne => ((far + near) / (near - far), (two * far * near) / (near - far)),
+            DepthRange::ZeroToOne        => (far / (near - far), (far * near) / (near - far)),
+            DepthRange::OneToZero        => (near / (far - near), (far * near) / (far - near)),
+        }
+    }
+}
+
+/// The inverse of a perspective projection matrix, computed in closed form.
+fn perspective_inverse<S: BaseFloat>(m: Matrix4<S>) -> Matrix4<S> {
+    // The projection only has entries on the diagonal of the upper 2x2
+    // block, the third column, and the third row.
+    let (a, b) = (m[0][0], m[1][1]);
+    let (c, d) = (m[2][0], m[2][1]);
+    let (z, w) = (m[2][2], m[3][2]);
+
+    Matrix4::new(S::one() / a, S::zero(), S::zero(), S::zero(),
+                 S::zero(), S::one() / b, S::zero(), S::zero(),
+                 S::zero(), S::zero(), S::zero(), S::one() / w,
+                 c / a, d / b, -S::one(), z / w)
+}
+
+/// Mirror the view space of the inverse of a projection along the `z` axis.
+fn inverse_lh<S: BaseFloat>(mut m: Matrix4<S>) -> Matrix4<S> {
+    m.x.z = -m.x.z;
+    m.y.z = -m.y.z;
+    m.z.z = -m.z.z;
+    m.w.z = -m.w.z;
+    m
+}
+
 /// A perspective projection based on a vertical field-of-view angle.
 #[derive(Copy, Clone, Debug, PartialEq)]
 #[cfg_attr(feature = "rustc-serialize", derive(RustcEncodable, RustcDecodable))]
@@ -92,21 +318,23 @@ impl<S: BaseFloat> PerspectiveFov<S> {
             far:     self.far.clone(),
         }
     }
-}
 
-impl<S: BaseFloat> From<PerspectiveFov<S>> for Matrix4<S> {
-    fn from(persp: PerspectiveFov<S>) -> Matrix4<S> {
-        assert!(persp.fovy   > Rad::zero(), "The vertical field of view cannot be below zero, found: {:?}", persp.fovy);
-        assert!(persp.fovy   < Rad::turn_div_2(), "The vertical field of view cannot be greater than a half turn, found: {:?}", persp.fovy);
-        assert!(persp.aspect > S::zero(), "The aspect ratio cannot be below zero, found: {:?}", persp.aspect);
-        assert!(persp.near   > S::zero(), "The near plane distance cannot be below zero, found: {:?}", persp.near);
-        assert!(persp.far    > S::zero(), "The far plane distance cannot be below zero, found: {:?}", persp.far);
-        assert!(persp.far    > persp.near, "The far plane cannot be closer than the near plane, found: far: {:?}, near: {:?}", persp.far, persp.near);
+    /// Create the projection matrix, mapping depths to `depth`.
+    ///
+    /// The `far` distance may be `S::infinity()`, for a projection with an
+    /// infinite far plane.
+    pub fn to_matrix4(&self, depth: DepthRange) -> Matrix4<S> {
+        assert!(self.fovy   > Rad::zero(), "The vertical field of view cannot be below zero, found: {:?}", self.fovy);
+        assert!(self.fovy   < Rad::turn_div_2(), "The vertical field of view cannot be greater than a half turn, found: {:?}", self.fovy);
+        assert!(self.aspect > S::zero(), "The aspect ratio cannot be below zero, found: {:?}", self.aspect);
+        assert!(self.near   > S::zero(), "The near plane distance cannot be below zero, found: {:?}", self.near);
+        assert!(self.far    > S::zero(), "The far plane distance cannot be below zero, found: {:?}", self.far);
+        assert!(self.far    > self.near, "The far plane cannot be closer than the near plane, found: far: {:?}, near: {:?}", self.far, self.near);
 
         let two: S = cast(2).unwrap();
-        let f = Rad::cot(persp.fovy / two);
+        let f = Rad::cot(self.fovy / two);
 
-        let c0r0 = f / persp.aspect;
+        let c0r0 = f / self.aspect;
         let c0r1 = S::zero();
         let c0r2 = S::zero();
         let c0r3 = S::zero();
@@ -118,12 +346,11 @@ impl<S: BaseFloat> From<PerspectiveFov<S>> for Matrix4<S> {
 
         let c2r0 = S::zero();
         let c2r1 = S::zero();
-        let c2r2 = (persp.far + persp.near) / (persp.near - persp.far);
+        let (c2r2, c3r2) = perspective_depth(self.near, self.far, depth);
         let c2r3 = -S::one();
 
         let c3r0 = S::zero();
         let c3r1 = S::zero();
-        let c3r2 = (two * persp.far * persp.near) / (persp.near - persp.far);
         let c3r3 = S::zero();
 
         Matrix4::new(c0r0, c0r1, c0r2, c0r3,
@@ -131,6 +358,36 @@ impl<S: BaseFloat> From<PerspectiveFov<S>> for Matrix4<S> {
                      c2r0, c2r1, c2r2, c2r3,
                      c3r0, c3r1, c3r2, c3r3)
     }
+
+    /// Create the projection matrix for a left-handed view space, looking
+    /// down the positive `z` axis, mapping depths to `depth`.
+    ///
+    /// The `near` and `far` distances are still given as positive distances
+    /// in front of the viewer.
+    pub fn to_matrix4_lh(&self, depth: DepthRange) -> Matrix4<S> {
+        // Mirror the view space along the `z` axis before projecting it.
+        let mut mat = self.to_matrix4(depth);
+        mat.z = -mat.z;
+        mat
+    }
+
+    /// Create the inverse of the projection matrix, mapping depths from
+    /// `depth`, without the loss of precision of a general matrix inversion.
+    pub fn to_inverse_matrix4(&self, depth: DepthRange) -> Matrix4<S> {
+        perspective_inverse(self.to_matrix4(depth))
+    }
+
+    /// Create the inverse of the left-handed projection matrix, mapping
+    /// depths from `depth`.
+    pub fn to_inverse_matrix4_lh(&self, depth: DepthRange) -> Matrix4<S> {
+        inverse_lh(self.to_inverse_matrix4(depth))
+    }
+}
+
+impl<S: BaseFloat> From<PerspectiveFov<S>> for Matrix4<S> {
+    fn from(persp: PerspectiveFov<S>) -> Matrix4<S> {
+        persp.to_matrix4(DepthRange::NegativeOneToOne)
+    }
 }
 
 /// A perspective projection with arbitrary left/right/bottom/top distances
@@ -146,32 +403,35 @@ pub struct Perspective<S> {
     pub far:    S,
 }
 
-impl<S: BaseFloat> From<Perspective<S>> for Matrix4<S> {
-    fn from(persp: Perspective<S>) -> Matrix4<S> {
-        assert!(persp.left   <= persp.right, "`left` cannot be greater than `right`, found: left: {:?} right: {:?}", persp.left, persp.right);
-        assert!(persp.bottom <= persp.top,   "`bottom` cannot be greater than `top`, found: bottom: {:?} top: {:?}", persp.bottom, persp.top);
-        assert!(persp.near   <= persp.far,   "`near` cannot be greater than `far`, found: near: {:?} far: {:?}", persp.near, persp.far);
+impl<S: BaseFloat> Perspective<S> {
+    /// Create the projection matrix, mapping depths to `depth`.
+    ///
+    /// The `far` distance may be `S::infinity()`, for a projection with an
+    /// infinite far plane.
+    pub fn to_matrix4(&self, depth: DepthRange) -> Matrix4<S> {
+        assert!(self.left   <= self.right, "`left` cannot be greater than `right`, found: left: {:?} right: {:?}", self.left, self.right);
+        assert!(self.bottom <= self.top,   "`bottom` cannot be greater than `top`, found: bottom: {:?} top: {:?}", self.bottom, self.top);
+        assert!(self.near   <= self.far,   "`near` cannot be greater than `far`, found: near: {:?} far: {:?}", self.near, self.far);
 
         let two: S = cast(2i8).unwrap();
 
-        let c0r0 = (two * persp.near) / (persp.right - persp.left);
+        let c0r0 = (two * self.near) / (self.right - self.left);
         let c0r1 = S::zero();
         let c0r2 = S::zero();
         let c0r3 = S::zero();
 
         let c1r0 = S::zero();
-        let c1r1 = (two * persp.near) / (persp.top - persp.bottom);
+        let c1r1 = (two * self.near) / (self.top - self.bottom);
         let c1r2 = S::zero();
         let c1r3 = S::zero();
 
-        let c2r0 = (persp.right + persp.left) / (persp.right - persp.left);
-        let c2r1 = (persp.top + persp.bottom) / (persp.top - persp.bottom);
-        let c2r2 = -(persp.far + persp.near) / (persp.far - persp.near);
+        let c2r0 = (self.right + self.left) / (self.right - self.left);
+        let c2r1 = (self.top + self.bottom) / (self.top - self.bottom);
+        let (c2r2, c3r2) = perspective_depth(self.near, self.far, depth);
         let c2r3 = -S::one();
 
         let c3r0 = S::zero();
         let c3r1 = S::zero();
-        let c3r2 = -(two * persp.far * persp.near) / (persp.far - persp.near);
         let c3r3 = S::zero();
 
         Matrix4::new(c0r0, c0r1, c0r2, c0r3,
@@ -179,6 +439,36 @@ impl<S: BaseFloat> From<Perspective<S>> for Matrix4<S> {
                      c2r0, c2r1, c2r2, c2r3,
                      c3r0, c3r1, c3r2, c3r3)
     }
+
+    /// Create the projection matrix for a left-handed view space, looking
+    /// down the positive `z` axis, mapping depths to `depth`.
+    ///
+    /// The `near` and `far` distances are still given as positive distances
+    /// in front of the viewer.
+    pub fn to_matrix4_lh(&self, depth: DepthRange) -> Matrix4<S> {
+        // Mirror the view space along the `z` axis before projecting it.
+        let mut mat = self.to_matrix4(depth);
+        mat.z = -mat.z;
+        mat
+    }
+
+    /// Create the inverse of the projection matrix, mapping depths from
+    /// `depth`, without the loss of precision of a general matrix inversion.
+    pub fn to_inverse_matrix4(&self, depth: DepthRange) -> Matrix4<S> {
+        perspective_inverse(self.to_matrix4(depth))
+    }
+
+    /// Create the inverse of the left-handed projection matrix, mapping
+    /// depths from `depth`.
+    pub fn to_inverse_matrix4_lh(&self, depth: DepthRange) -> Matrix4<S> {
+        inverse_lh(self.to_inverse_matrix4(depth))
+    }
+}
+
+impl<S: BaseFloat> From<Perspective<S>> for Matrix4<S> {
+    fn from(persp: Perspective<S>) -> Matrix4<S> {
+        persp.to_matrix4(DepthRange::NegativeOneToOne)
+    }
 }
 
 /// An orthographic projection with arbitrary left/right/bottom/top distances
@@ -194,28 +484,37 @@ pub struct Ortho<S> {
     pub far:    S,
 }
 
-impl<S: BaseFloat> From<Ortho<S>> for Matrix4<S> {
-    fn from(ortho: Ortho<S>) -> Matrix4<S> {
+impl<S: BaseFloat> Ortho<S> {
+    /// Create the projection matrix, mapping depths to `depth`.
+    pub fn to_matrix4(&self, depth: DepthRange) -> Matrix4<S> {
         let two: S = cast(2).unwrap();
 
-        let c0r0 = two / (ortho.right - ortho.left);
+        let c0r0 = two / (self.right - self.left);
         let c0r1 = S::zero();
         let c0r2 = S::zero();
         let c0r3 = S::zero();
 
         let c1r0 = S::zero();
-        let c1r1 = two / (ortho.top - ortho.bottom);
+        let c1r1 = two / (self.top - self.bottom);
         let c1r2 = S::zero();
         let c1r3 = S::zero();
 
         let c2r0 = S::zero();
         let c2r1 = S::zero();
-        let c2r2 = -two / (ortho.far - ortho.near);
+        let c2r2 = match depth {
+            DepthRange::NegativeOneToOne => -two / (self.far - self.near),
+            DepthRange::ZeroToOne        => -S::one() / (self.far - self.near),
+            DepthRange::OneToZero        => S::one() / (self.far - self.near),
+        };
         let c2r3 = S::zero();
 
-        let c3r0 = -(ortho.right + ortho.left) / (ortho.right - ortho.left);
-        let c3r1 = -(ortho.top + ortho.bottom) / (ortho.top - ortho.bottom);
-        let c3r2 = -(ortho.far + ortho.near) / (ortho.far - ortho.near);
+        let c3r0 = -(self.right + self.left) / (self.right - self.left);
+        let c3r1 = -(self.top + self.bottom) / (self.top - self.bottom);
+        let c3r2 = match depth {
+            DepthRange::NegativeOneToOne => -(self.far + self.near) / (self.far - self.near),
+            DepthRange::ZeroToOne        => -self.near / (self.far - self.near),
+            DepthRange::OneToZero        => self.far / (self.far - self.near),
+        };
         let c3r3 = S::one();
 
         Matrix4::new(c0r0, c0r1, c0r2, c0r3,
@@ -223,4 +522,40 @@ impl<S: BaseFloat> From<Ortho<S>> for Matrix4<S> {
                      c2r0, c2r1, c2r2, c2r3,
                      c3r0, c3r1, c3r2, c3r3)
     }
+
+    /// Create the projection matrix for a left-handed view space, looking
+    /// down the positive `z` axis, mapping depths to `depth`.
+    ///
+    /// The `near` and `far` distances are still given as positive distances
+    /// in front of the viewer.
+    pub fn to_matrix4_lh(&self, depth: DepthRange) -> Matrix4<S> {
+        // Mirror the view space along the `z` axis before projecting it.
+        let mut mat = self.to_matrix4(depth);
+        mat.z = -mat.z;
+        mat
+    }
+
+    /// Create the inverse of the projection matrix, mapping depths from
+    /// `depth`, without the loss of precision of a general matrix inversion.
+    pub fn to_inverse_matrix4(&self, depth: DepthRange) -> Matrix4<S> {
+        let m = self.to_matrix4(depth);
+        let (a, b, c) = (m[0][0], m[1][1], m[2][2]);
+
+        Matrix4::new(S::one() / a, S::zero(), S::zero(), S::zero(),
+                     S::zero(), S::one() / b, S::zero(), S::zero(),
+                     S::zero(), S::zero(), S::one() / c, S::zero(),
+                     -m[3][0] / a, -m[3][1] / b, -m[3][2] / c, S::one())
+    }
+
+    /// Create the inverse of the left-handed projection matrix, mapping
+    /// depths from `depth`.
+    pub fn to_inverse_matrix4_lh(&self, depth: DepthRange) -> Matrix4<S> {
+        inverse_lh(self.to_inverse_matrix4(depth))
+    }
+}
+
+impl<S: BaseFloat> From<Ortho<S>> for Matrix4<S> {
+    fn from(ortho: Ortho<S>) -> Matrix4<S> {
+        ortho.to_matrix4(DepthRange::NegativeOneToOne)
+    }
 }
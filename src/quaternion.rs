@@ -23,11 +23,15 @@ use structure::*;
 
 use angle::Rad;
 use approx::ApproxEq;
-use euler::Euler;
+use euler::{Euler, EulerFrame, EulerSequence};
+#[cfg(feature = "fixed-point")]
+use fixed::{Fixed32, Fixed64};
 use matrix::{Matrix3, Matrix4};
 use num::BaseFloat;
 use point::Point3;
 use rotation::{Rotation, Rotation3, Basis3};
+#[cfg(feature = "simd")]
+use simd;
 use vector::Vector3;
 
 
@@ -141,6 +145,97 @@ impl<S: BaseFloat> Quaternion<S> {
             (self * scale1 + other * scale2) * Rad::sin(theta).recip()
         }
     }
+
+    /// The exponential of the quaternion.
+    ///
+    /// The exponential of a quaternion with a zero scalar part and a vector
+    /// part of `axis * angle / 2` is the unit quaternion rotating by `angle`
+    /// around `axis`.
+    pub fn exp(self) -> Quaternion<S> {
+        let angle = self.v.magnitude();
+        let scale = self.s.exp();
+        if angle.is_zero() {
+            Quaternion::from_sv(scale, Vector3::zero())
+        } else {
+            Quaternion::from_sv(scale * angle.cos(), self.v * (scale * angle.sin() / angle))
+        }
+    }
+
+    /// The natural logarithm of the quaternion, the inverse of `exp`.
+    ///
+    /// The angle of the vector part is in the range `[0, π]`, so for a unit
+    /// quaternion the vector part is `axis * angle / 2`, where `angle` is in
+    /// the range `[0, 2π]`.
+    pub fn ln(self) -> Quaternion<S> {
+        let sin = self.v.magnitude();
+        let ln_magnitude = self.magnitude().ln();
+        if sin.is_zero() {
+            Quaternion::from_sv(ln_magnitude, Vector3::zero())
+        } else {
+            Quaternion::from_sv(ln_magnitude, self.v * (sin.atan2(self.s) / sin))
+        }
+    }
+
+    /// Raise the quaternion to the power `n`. For a unit quaternion, this
+    /// scales the angle of the rotation by `n`, keeping the axis.
+    #[inline]
+    pub fn powf(self, n: S) -> Quaternion<S> {
+        (self.ln() * n).exp()
+    }
+
+    /// The axis and angle of the rotation described by a unit quaternion.
+    ///
+    /// The angle is in the range `[0, π]`, flipping the axis if needed. For
+    /// the identity rotation, the axis is `Vector3::unit_x()`.
+    pub fn to_axis_angle(self) -> (Vector3<S>, Rad<S>) {
+        let q = if self.s < S::zero() { -self } else { self };
+        let sin = q.v.magnitude();
+        if sin.is_zero() {
+            (Vector3::unit_x(), Rad(S::zero()))
+        } else {
+            let two = S::one() + S::one();
+            (q.v / sin, Rad(sin.atan2(q.s) * two))
+        }
+    }
+
+    /// Construct the rotation by the angle `v.magnitude()` around the axis
+    /// `v.normalize()`. A zero vector gives the identity rotation.
+    #[inline]
+    pub fn from_rotation_vector(v: Vector3<S>) -> Quaternion<S> {
+        let two = S::one() + S::one();
+        Quaternion::from_sv(S::zero(), v / two).exp()
+    }
+
+    /// The axis of the rotation described by a unit quaternion, scaled by the
+    /// angle of the rotation in the range `[0, π]`. This is the inverse of
+    /// `from_rotation_vector`.
+    #[inline]
+    pub fn to_rotation_vector(self) -> Vector3<S> {
+        let two = S::one() + S::one();
+        let q = if self.s < S::zero() { -self } else { self };
+        q.ln().v * two
+    }
+
+    /// Advance the orientation by rotating at the constant `angular_velocity`,
+    /// given in world space in radians per unit of time, for the time step
+    /// `dt`.
+    ///
+    /// The result is normalized, so errors do not accumulate in the
+    /// magnitude of the orientation over repeated steps.
+    #[inline]
+    pub fn integrate(self, angular_velocity: Vector3<S>, dt: S) -> Quaternion<S> {
+        (Quaternion::from_rotation_vector(angular_velocity * dt) * self).normalize()
+    }
+
+    /// The constant angular velocity, in world space, that rotates the
+    /// orientation to `other` along the shortest path over the time `dt`.
+    /// This is the reverse of `integrate`.
+    ///
+    /// Both quaternions should be normalized first.
+    #[inline]
+    pub fn angular_velocity(self, other: Quaternion<S>, dt: S) -> Vector3<S> {
+        (other * self.conjugate()).to_rotation_vector() / dt
+    }
 }
 
 impl<S: BaseFloat> Zero for Quaternion<S> {
@@ -178,6 +273,12 @@ impl<S: BaseFloat> MetricSpace for Quaternion<S> {
 impl<S: BaseFloat> InnerSpace for Quaternion<S> {
     #[inline]
     fn dot(self, other: Quaternion<S>) -> S {
+        #[cfg(feature = "simd")]
+        {
+            if let Some(dot) = simd::dot4(self.as_ref(), other.as_ref()) {
+                return dot;
+            }
+        }
         self.s * other.s + self.v.dot(other.v)
     }
 }
@@ -203,6 +304,27 @@ impl<A> From<Euler<A>> for Quaternion<<A as Angle>::Unitless> where
     }
 }
 
+impl<A> From<EulerSequence<A>> for Quaternion<<A as Angle>::Unitless> where
+    A: Angle + Into<Rad<<A as Angle>::Unitless>>,
+{
+    fn from(src: EulerSequence<A>) -> Quaternion<A::Unitless> {
+        let axes = src.order.axes();
+        let from_axis_angle = |axis, theta: A| match axis {
+            0 => Quaternion::from_angle_x(theta),
+            1 => Quaternion::from_angle_y(theta),
+            _ => Quaternion::from_angle_z(theta),
+        };
+        let first = from_axis_angle(axes[0], src.first);
+        let second = from_axis_angle(axes[1], src.second);
+        let third = from_axis_angle(axes[2], src.third);
+
+        match src.frame {
+            EulerFrame::Intrinsic => first * second * third,
+            EulerFrame::Extrinsic => third * second * first,
+        }
+    }
+}
+
 impl_operator!(<S: BaseFloat> Neg for Quaternion<S> {
     fn neg(quat) -> Quaternion<S> {
         Quaternion::from_sv(-quat.s, -quat.v)
@@ -264,12 +386,18 @@ impl_assignment_operator!(<S: BaseFloat> SubAssign<Quaternion<S> > for Quaternio
 });
 
 impl_operator!(<S: BaseFloat> Mul<Quaternion<S> > for Quaternion<S> {
-    fn mul(lhs, rhs) -> Quaternion<S> {
+    fn mul(lhs, rhs) -> Quaternion<S> {{
+        #[cfg(feature = "simd")]
+        {
+            if let Some(q) = simd::quaternion_mul(&lhs, &rhs) {
+                return q;
+            }
+        }
         Quaternion::new(lhs.s * rhs.s - lhs.v.x * rhs.v.x - lhs.v.y * rhs.v.y - lhs.v.z * rhs.v.z,
                         lhs.s * rhs.v.x + lhs.v.x * rhs.s + lhs.v.y * rhs.v.z - lhs.v.z * rhs.v.y,
                         lhs.s * rhs.v.y + lhs.v.y * rhs.s + lhs.v.z * rhs.v.x - lhs.v.x * rhs.v.z,
                         lhs.s * rhs.v.z + lhs.v.z * rhs.s + lhs.v.x * rhs.v.y - lhs.v.y * rhs.v.x)
-    }
+    }}
 });
 
 macro_rules! impl_scalar_mul {
@@ -296,6 +424,14 @@ impl_scalar_mul!(f32);
 impl_scalar_mul!(f64);
 impl_scalar_div!(f32);
 impl_scalar_div!(f64);
+#[cfg(feature = "fixed-point")]
+impl_scalar_mul!(Fixed32);
+#[cfg(feature = "fixed-point")]
+impl_scalar_mul!(Fixed64);
+#[cfg(feature = "fixed-point")]
+impl_scalar_div!(Fixed32);
+#[cfg(feature = "fixed-point")]
+impl_scalar_div!(Fixed64);
 
 impl<S: BaseFloat> ApproxEq for Quaternion<S> {
     type Epsilon = S::Epsilon;
@@ -0,0 +1,315 @@
+// Copyright 2016 The CGMath Developers. For a full listing of the authors,
+// refer to the Cargo.toml file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Rays and line segments, and their intersections with other primitives.
+//!
+//! Every intersection query returns the first point at which the ray enters
+//! the surface, or, if the ray starts inside a closed surface, the point at
+//! which it leaves.
+
+use num_traits::Zero;
+
+use structure::*;
+
+use aabb::Aabb3;
+use approx::ApproxEq;
+use num::BaseFloat;
+use plane::Plane;
+use point::Point3;
+use sphere::Sphere;
+use vector::Vector3;
+
+/// A half-line in 3-dimensional space, starting at `origin` and extending
+/// infinitely in `direction`.
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "rustc-serialize", derive(RustcEncodable, RustcDecodable))]
+#[cfg_attr(feature = "eders", derive(Serialize, Deserialize))]
+pub struct Ray3<S> {
+    pub origin: Point3<S>,
+    /// The direction of the ray. Distances along the ray are measured in
+    /// multiples of this vector, so they are only Euclidean distances if it
+    /// is of unit length.
+    pub direction: Vector3<S>,
+}
+
+/// A line segment in 3-dimensional space.
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "rustc-serialize", derive(RustcEncodable, RustcDecodable))]
+#[cfg_attr(feature = "eders", derive(Serialize, Deserialize))]
+pub struct Segment3<S> {
+    pub start: Point3<S>,
+    pub end: Point3<S>,
+}
+
+/// The result of a successful intersection query.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct RayHit<S> {
+    /// The distance along the ray to the hit point, in multiples of the ray
+    /// direction.
+    pub distance: S,
+    /// The unit surface normal at the hit point.
+    pub normal: Vector3<S>,
+}
+
+impl<S: BaseFloat> Ray3<S> {
+    #[inline]
+    pub fn new(origin: Point3<S>, direction: Vector3<S>) -> Ray3<S> {
+        Ray3 { origin: origin, direction: direction }
+    }
+
+    /// The point at `distance` along the ray.
+    #[inline]
+    pub fn at(&self, distance: S) -> Point3<S> {
+        self.origin + self.direction * distance
+    }
+
+    /// Intersect the ray with a plane. The returned normal faces back
+    /// towards the origin of the ray, whichever side of the plane it is on.
+    ///
+    /// Returns `None` if the ray is parallel to the plane, or points away
+    /// from it.
+    pub fn intersect_plane(&self, plane: &Plane<S>) -> Option<RayHit<S>> {
+        let denom = plane.normal.dot(self.direction);
+        if denom.is_zero() {
+            return None;
+        }
+
+        let distance = -plane.signed_distance(self.origin) / denom;
+        if distance < S::zero() {
+            return None;
+        }
+
+        let normal = if denom > S::zero() { -plane.normal } else { plane.normal };
+        Some(RayHit { distance: distance, normal: normal })
+    }
+
+    /// Intersect the ray with a sphere. The returned normal points out of
+    /// the sphere.
+    pub fn intersect_sphere(&self, sphere: &Sphere<S>) -> Option<RayHit<S>> {
+        let offset = self.origin - sphere.center;
+        let a = self.direction.magnitude2();
+        let b = offset.dot(self.direction);
+        let c = offset.magnitude2() - sphere.radius * sphere.radius;
+
+        let discriminant = b * b - a * c;
+        if a.is_zero() || discriminant < S::zero() {
+            return None;
+        }
+
+        let root = discriminant.sqrt();
+        let mut distance = (-b - root) / a;
+        if distance < S::zero() {
+            // The origin may lie inside the sphere.
+            distance = (-b + root) / a;
+            if distance < S::zero() {
+                return None;
+            }
+        }
+
+        let normal = (self.at(distance) - sphere.center).normalize();
+        Some(RayHit { distance: distance, normal: normal })
+    }
+
+    /// Intersect the ray with an axis-aligned box, using the slab method.
+    /// The returned normal is the outward normal of the face that was hit.
+    pub fn intersect_aabb(&self, aabb: &Aabb3<S>) -> Option<RayHit<S>> {
+        let mut near = S::neg_infinity();
+        let mut far = S::infinity();
+        let mut near_normal = Vector3::zero();
+        let mut far_normal = Vector3::zero();
+
+        for axis in 0..3 {
+            let (origin, direction) = (self.origin[axis], self.direction[axis]);
+            let (min, max) = (aabb.min[axis], aabb.max[axis]);
+
+            if direction.is_zero() {
+                if origin < min || origin > max {
+                    return None;
+                }
+                continue;
+            }
+
+            let mut normal = Vector3::zero();
+            normal[axis] = -direction.signum();
+
+            let t1 = (min - origin) / direction;
+            let t2 = (max - origin) / direction;
+            let (t_enter, t_exit) = if t1 < t2 { (t1, t2) } else { (t2, t1) };
+
+            if t_enter > near {
+                near = t_enter;
+                near_normal = normal;
+            }
+            if t_exit < far {
+                far = t_exit;
+                far_normal = -normal;
+            }
+            if near > far {
+                return None;
+            }
+        }
+
+        if far < S::zero() {
+            None
+        } else if near >= S::zero() {
+            Some(RayHit { distance: near, normal: near_normal })
+        } else if far.is_finite() {
+            Some(RayHit { distance: far, normal: far_normal })
+        } else {
+            // A zero direction, starting inside the box.
+            None
+        }
+    }
+
+    /// Intersect the ray with the triangle `abc`, using the Möller–Trumbore
+    /// algorithm. Both sides of the triangle are hit, and the returned normal
+    /// faces back towards the origin of the ray.
+    ///
+    /// Returns `None` if the ray misses, is parallel to the triangle, or the
+    /// triangle is degenerate.
+    pub fn intersect_triangle(&self, a: Point3<S>, b: Point3<S>, c: Point3<S>) -> Option<RayHit<S>> {
+        let edge1 = b - a;
+        let edge2 = c - a;
+
+        let p = self.direction.cross(edge2);
+        let det = edge1.dot(p);
+        if det.is_zero() {
+            return None;
+        }
+        let inv_det = det.recip();
+
+        let offset = self.origin - a;
+        let u = offset.dot(p) * inv_det;
+        if u < S::zero() || u > S::one() {
+            return None;
+        }
+
+        let q = offset.cross(edge1);
+        let v = self.direction.dot(q) * inv_det;
+        if v < S::zero() || u + v > S::one() {
+            return None;
+        }
+
+        let distance = edge2.dot(q) * inv_det;
+        if distance < S::zero() {
+            return None;
+        }
+
+        let normal = edge1.cross(edge2).normalize();
+        let normal = if normal.dot(self.direction) > S::zero() { -normal } else { normal };
+        Some(RayHit { distance: distance, normal: normal })
+    }
+}
+
+impl<S: BaseFloat> Segment3<S> {
+    #[inline]
+    pub fn new(start: Point3<S>, end: Point3<S>) -> Segment3<S> {
+        Segment3 { start: start, end: end }
+    }
+
+    /// The vector from the start to the end of the segment.
+    #[inline]
+    pub fn direction(&self) -> Vector3<S> {
+        self.end - self.start
+    }
+
+    #[inline]
+    pub fn length(&self) -> S {
+        self.start.distance(self.end)
+    }
+
+    #[inline]
+    pub fn midpoint(&self) -> Point3<S> {
+        self.start.midpoint(self.end)
+    }
+
+    /// The point on the segment closest to `point`.
+    pub fn closest_point(&self, point: Point3<S>) -> Point3<S> {
+        let direction = self.direction();
+        let length2 = direction.magnitude2();
+        if length2.is_zero() {
+            return self.start;
+        }
+        let t = (point - self.start).dot(direction) / length2;
+        self.start + direction * t.max(S::zero()).min(S::one())
+    }
+
+    /// A ray starting at the start of the segment, with a direction of unit
+    /// length pointing towards the end.
+    #[inline]
+    pub fn to_ray(&self) -> Ray3<S> {
+        Ray3::new(self.start, self.direction().normalize())
+    }
+
+    /// Intersect the segment with a plane. The hit distance is measured from
+    /// the start of the segment.
+    pub fn intersect_plane(&self, plane: &Plane<S>) -> Option<RayHit<S>> {
+        self.clip(self.to_ray().intersect_plane(plane))
+    }
+
+    /// Intersect the segment with a sphere. The hit distance is measured from
+    /// the start of the segment.
+    pub fn intersect_sphere(&self, sphere: &Sphere<S>) -> Option<RayHit<S>> {
+        self.clip(self.to_ray().intersect_sphere(sphere))
+    }
+
+    /// Intersect the segment with an axis-aligned box. The hit distance is
+    /// measured from the start of the segment.
+    pub fn intersect_aabb(&self, aabb: &Aabb3<S>) -> Option<RayHit<S>> {
+        self.clip(self.to_ray().intersect_aabb(aabb))
+    }
+
+    /// Intersect the segment with the triangle `abc`. The hit distance is
+    /// measured from the start of the segment.
+    pub fn intersect_triangle(&self, a: Point3<S>, b: Point3<S>, c: Point3<S>) -> Option<RayHit<S>> {
+        self.clip(self.to_ray().intersect_triangle(a, b, c))
+    }
+
+    #[inline]
+    fn clip(&self, hit: Option<RayHit<S>>) -> Option<RayHit<S>> {
+        hit.and_then(|hit| if hit.distance <= self.length() { Some(hit) } else { None })
+    }
+}
+
+impl<S: BaseFloat> ApproxEq for RayHit<S> {
+    type Epsilon = S::Epsilon;
+
+    #[inline]
+    fn default_epsilon() -> S::Epsilon {
+        S::default_epsilon()
+    }
+
+    #[inline]
+    fn default_max_relative() -> S::Epsilon {
+        S::default_max_relative()
+    }
+
+    #[inline]
+    fn default_max_ulps() -> u32 {
+        S::default_max_ulps()
+    }
+
+    #[inline]
+    fn relative_eq(&self, other: &Self, epsilon: S::Epsilon, max_relative: S::Epsilon) -> bool {
+        S::relative_eq(&self.distance, &other.distance, epsilon, max_relative) &&
+        Vector3::relative_eq(&self.normal, &other.normal, epsilon, max_relative)
+    }
+
+    #[inline]
+    fn ulps_eq(&self, other: &Self, epsilon: S::Epsilon, max_ulps: u32) -> bool {
+        S::ulps_eq(&self.distance, &other.distance, epsilon, max_ulps) &&
+        Vector3::ulps_eq(&self.normal, &other.normal, epsilon, max_ulps)
+    }
+}
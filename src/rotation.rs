@@ -20,11 +20,12 @@ use structure::*;
 
 use angle::Rad;
 use approx::ApproxEq;
-use euler::Euler;
+use euler::{Euler, EulerSequence};
 use matrix::{Matrix2, Matrix3};
 use num::BaseFloat;
 use point::{Point2, Point3};
 use quaternion::Quaternion;
+use svd::sigma_tolerance;
 use vector::{Vector2, Vector3};
 
 /// A trait for a generic rotation. A rotation is a transformation that
@@ -35,6 +36,9 @@ pub trait Rotation<P: EuclideanSpace>: Sized + Copy + One where
     <P as EuclideanSpace>::Scalar: BaseFloat,
 {
     /// Create a rotation to a given direction with an 'up' vector
+    ///
+    /// In three dimensions, this rotates `dir` onto the positive `z` axis,
+    /// as in a left-handed view space.
     fn look_at(dir: P::Diff, up: P::Diff) -> Self;
 
     /// Create a shortest rotation to transform vector 'a' into 'b'.
@@ -93,6 +97,20 @@ pub trait Rotation3<S: BaseFloat>: Rotation<Point3<S>>
     fn from_angle_z<A: Into<Rad<S>>>(theta: A) -> Self {
         Rotation3::from_axis_angle(Vector3::unit_z(), theta)
     }
+
+    /// Create a rotation for a left-handed view space, which rotates `dir`
+    /// onto the positive `z` axis, and `up` into the `yz` plane.
+    #[inline]
+    fn look_at_lh(dir: Vector3<S>, up: Vector3<S>) -> Self {
+        Rotation::look_at(dir, up)
+    }
+
+    /// Create a rotation for a right-handed view space, which rotates `dir`
+    /// onto the negative `z` axis, and `up` into the `yz` plane.
+    #[inline]
+    fn look_at_rh(dir: Vector3<S>, up: Vector3<S>) -> Self {
+        Rotation::look_at(-dir, up)
+    }
 }
 
 
@@ -146,6 +164,44 @@ pub struct Basis2<S> {
     mat: Matrix2<S>
 }
 
+impl<S: BaseFloat> Basis2<S> {
+    /// Create a rotation matrix from the rotation closest to `mat`.
+    ///
+    /// Returns `None` if there is no single closest rotation, which is the
+    /// case when `mat` is a multiple of a reflection.
+    pub fn from_matrix(mat: Matrix2<S>) -> Option<Basis2<S>> {
+        // The closest rotation maximizes the trace of `rotᵀ * mat`.
+        let (c, s) = (mat.x.x + mat.y.y, mat.x.y - mat.y.x);
+        let magnitude = c.hypot(s);
+        if magnitude.is_zero() {
+            return None;
+        }
+        let (c, s) = (c / magnitude, s / magnitude);
+        Some(Basis2 { mat: Matrix2::new(c, s, -s, c) })
+    }
+
+    /// Restore the orthogonality of a rotation matrix that has drifted
+    /// through accumulated rounding errors, by replacing it with the closest
+    /// rotation.
+    ///
+    /// Returns `None` if there is no single closest rotation, as for
+    /// `from_matrix`, which can only happen if the matrix has drifted too far
+    /// to be meaningful.
+    #[inline]
+    pub fn orthonormalize(&self) -> Option<Basis2<S>> {
+        Basis2::from_matrix(self.mat)
+    }
+
+    /// Restore the orthogonality of a rotation matrix with the Gram-Schmidt
+    /// process, which keeps the direction of its `x` axis.
+    ///
+    /// Returns `None` if the axes have become linearly dependent.
+    #[inline]
+    pub fn gram_schmidt(&self) -> Option<Basis2<S>> {
+        self.mat.gram_schmidt().map(|mat| Basis2 { mat: mat })
+    }
+}
+
 impl<S: BaseFloat> AsRef<Matrix2<S>> for Basis2<S> {
     #[inline]
     fn as_ref(&self) -> &Matrix2<S> {
@@ -246,6 +302,58 @@ impl<S: BaseFloat> Basis3<S> {
     pub fn from_quaternion(quaternion: &Quaternion<S>) -> Basis3<S> {
         Basis3 { mat: quaternion.clone().into() }
     }
+
+    /// Create a rotation matrix from the rotation closest to `mat`, found
+    /// with its singular value decomposition `u * sigma * v_t` as
+    /// `u * d * v_t`, where `d` flips the axis of the smallest singular value
+    /// if `u * v_t` is a reflection.
+    ///
+    /// If the smallest singular value is repeated and `mat` has a negative
+    /// determinant, the closest rotation is not unique, and one of them is
+    /// returned. Returns `None` if `mat` is singular or is not finite.
+    pub fn from_matrix(mat: Matrix3<S>) -> Option<Basis3<S>> {
+        if !(0..3).all(|i| (0..3).all(|j| mat[i][j].is_finite())) {
+            return None;
+        }
+        let (mut u, sigma, v_t) = mat.svd();
+        if sigma.z.z <= sigma_tolerance(sigma.x.x, 3) {
+            return None;
+        }
+        if (u * v_t).determinant() < S::zero() {
+            u.z = -u.z;
+        }
+        Some(Basis3 { mat: u * v_t })
+    }
+
+    /// Restore the orthogonality of a rotation matrix that has drifted
+    /// through accumulated rounding errors, by replacing it with the closest
+    /// rotation.
+    ///
+    /// Returns `None` if there is no single closest rotation, as for
+    /// `from_matrix`, which can only happen if the matrix has drifted too far
+    /// to be meaningful.
+    #[inline]
+    pub fn orthonormalize(&self) -> Option<Basis3<S>> {
+        Basis3::from_matrix(self.mat)
+    }
+
+    /// Restore the orthogonality of a rotation matrix with the Gram-Schmidt
+    /// process, which keeps the direction of its `x` axis and the plane of
+    /// its `x` and `y` axes. This is cheaper than `orthonormalize`.
+    ///
+    /// Returns `None` if the axes have become linearly dependent.
+    #[inline]
+    pub fn gram_schmidt(&self) -> Option<Basis3<S>> {
+        self.mat.gram_schmidt().map(|mat| Basis3 { mat: mat })
+    }
+}
+
+/// Create a rotation matrix from a matrix that is already known to be a
+/// rotation, such as an accumulated product of rotations, without projecting
+/// it onto the closest one.
+#[inline]
+pub(crate) fn basis3_from_rotation_matrix<S>(mat: Matrix3<S>) -> Basis3<S> {
+    Basis3 { mat: mat }
 }
 
 impl<S> AsRef<Matrix3<S>> for Basis3<S> {
@@ -353,6 +461,18 @@ impl<A: Angle> From<Euler<A>> for Basis3<<A as Angle>::Unitless> where
     }
 }
 
+impl<A: Angle> From<EulerSequence<A>> for Basis3<<A as Angle>::Unitless> where
+    A: Into<Rad<<A as Angle>::Unitless>>,
+{
+    /// Create a three-dimensional rotation matrix from a sequence of euler
+    /// angles.
+    fn from(src: EulerSequence<A>) -> Basis3<A::Unitless> {
+        Basis3 {
+            mat: Matrix3::from(src),
+        }
+    }
+}
+
 impl<S: fmt::Debug> fmt::Debug for Basis3<S> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         try!(write!(f, "Basis3 "));
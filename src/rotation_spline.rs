@@ -0,0 +1,178 @@
+// Copyright 2016 The CGMath Developers. For a full listing of the authors,
+// refer to the Cargo.toml file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Smooth interpolation of rotations through keyframes, with spherical
+//! quadrangle interpolation (SQUAD) or cubic Bézier curves on the unit
+//! quaternions.
+//!
+//! The control points between keyframes are chosen so that the angular
+//! velocity is continuous across every keyframe, even when the keyframes are
+//! not evenly spaced in time.
+
+use num_traits::cast;
+
+use structure::*;
+
+use num::BaseFloat;
+use quaternion::Quaternion;
+use vector::Vector3;
+
+impl<S: BaseFloat> Quaternion<S> {
+    /// Spherical quadrangle interpolation from the quaternion to `other`,
+    /// with the intermediate control points `a` and `b`, by `amount`.
+    ///
+    /// All of the quaternions should be normalized first.
+    pub fn squad(self, a: Quaternion<S>, b: Quaternion<S>, other: Quaternion<S>, amount: S) -> Quaternion<S> {
+        let two = S::one() + S::one();
+        let outer = self.slerp(other, amount);
+        let inner = a.slerp(b, amount);
+        outer.slerp(inner, two * amount * (S::one() - amount))
+    }
+
+    /// Interpolate along the cubic Bézier curve on the unit quaternions from
+    /// the quaternion to `other`, with the control points `c1` and `c2`, by
+    /// `amount`, using de Casteljau's algorithm with `slerp`.
+    ///
+    /// All of the quaternions should be normalized first.
+    pub fn bezier(self, c1: Quaternion<S>, c2: Quaternion<S>, other: Quaternion<S>, amount: S) -> Quaternion<S> {
+        let (q01, q12, q23) = (self.slerp(c1, amount), c1.slerp(c2, amount), c2.slerp(other, amount));
+        let (q012, q123) = (q01.slerp(q12, amount), q12.slerp(q23, amount));
+        q012.slerp(q123, amount)
+    }
+}
+
+/// Interpolate the rotation at `time` through the keyframe rotations `keys`,
+/// reached at the increasing `times`, with spherical quadrangle
+/// interpolation. Times outside of the keyframes are clamped to the first or
+/// last keyframe.
+///
+/// The keyframes should be normalized. They may be given with either sign,
+/// as consecutive keys are interpolated along the shortest path.
+///
+/// # Panics
+///
+/// Panics if `keys` is empty, or if `keys` and `times` have different
+/// lengths.
+pub fn squad_keyframes<S: BaseFloat>(keys: &[Quaternion<S>], times: &[S], time: S) -> Quaternion<S> {
+    let segment = match Segment::find(keys, times, time) {
+        Some(segment) => segment,
+        None => return keys[0],
+    };
+    let two = S::one() + S::one();
+    let a = segment.start * exp((segment.start_tangent - segment.delta) / two);
+    let b = segment.end * exp(-(segment.end_tangent - segment.delta) / two);
+    segment.start.squad(a, b, segment.end, segment.amount)
+}
+
+/// Interpolate the rotation at `time` through the keyframe rotations `keys`,
+/// reached at the increasing `times`, along cubic Bézier curves on the unit
+/// quaternions. Times outside of the keyframes are clamped to the first or
+/// last keyframe.
+///
+/// The keyframes should be normalized. They may be given with either sign,
+/// as consecutive keys are interpolated along the shortest path.
+///
+/// # Panics
+///
+/// Panics if `keys` is empty, or if `keys` and `times` have different
+/// lengths.
+pub fn bezier_keyframes<S: BaseFloat>(keys: &[Quaternion<S>], times: &[S], time: S) -> Quaternion<S> {
+    let segment = match Segment::find(keys, times, time) {
+        Some(segment) => segment,
+        None => return keys[0],
+    };
+    let three: S = cast(3).unwrap();
+    let c1 = segment.start * exp(segment.start_tangent / three);
+    let c2 = segment.end * exp(-segment.end_tangent / three);
+    segment.start.bezier(c1, c2, segment.end, segment.amount)
+}
+
+/// The segment between two keyframes, with the rotations at either end
+/// brought into the same hemisphere, and the tangents at either end in the
+/// logarithmic space of the end's rotation, scaled by the segment duration.
+struct Segment<S> {
+    start: Quaternion<S>,
+    end: Quaternion<S>,
+    /// The logarithm of the rotation from `start` to `end`.
+    delta: Vector3<S>,
+    start_tangent: Vector3<S>,
+    end_tangent: Vector3<S>,
+    amount: S,
+}
+
+impl<S: BaseFloat> Segment<S> {
+    /// The segment containing `time`, or `None` if there is only one
+    /// keyframe.
+    fn find(keys: &[Quaternion<S>], times: &[S], time: S) -> Option<Segment<S>> {
+        assert!(!keys.is_empty(), "Expected at least one keyframe");
+        assert_eq!(keys.len(), times.len());
+        let n = keys.len();
+        if n == 1 {
+            return None;
+        }
+
+        // Find the last keyframe before `time`, clamping to the ends.
+        let (mut i, mut high) = (0, n - 1);
+        while high - i > 1 {
+            let mid = (i + high) / 2;
+            if times[mid] <= time { i = mid; } else { high = mid; }
+        }
+        let duration = times[i + 1] - times[i];
+        let amount = ((time - times[i]) / duration).max(S::zero()).min(S::one());
+
+        let start = keys[i];
+        let end = align(keys[i + 1], start);
+        let delta = ln(start.conjugate() * end);
+
+        // The angular velocity at each end is the difference of the rotations
+        // to its neighbours, divided by the time between them.
+        let start_tangent = if i > 0 {
+            let before = ln(start.conjugate() * align(keys[i - 1], start));
+            (delta - before) * (duration / (times[i + 1] - times[i - 1]))
+        } else {
+            delta
+        };
+        let end_tangent = if i + 2 < n {
+            let after = ln(end.conjugate() * align(keys[i + 2], end));
+            (after + delta) * (duration / (times[i + 2] - times[i]))
+        } else {
+            delta
+        };
+
+        Some(Segment {
+            start: start,
+            end: end,
+            delta: delta,
+            start_tangent: start_tangent,
+            end_tangent: end_tangent,
+            amount: amount,
+        })
+    }
+}
+
+/// `q`, or its negation, whichever is closer to `reference`.
+fn align<S: BaseFloat>(q: Quaternion<S>, reference: Quaternion<S>) -> Quaternion<S> {
+    if q.dot(reference) < S::zero() { -q } else { q }
+}
+
+/// The exponential of the pure quaternion with vector part `v`.
+fn exp<S: BaseFloat>(v: Vector3<S>) -> Quaternion<S> {
+    Quaternion::from_sv(S::zero(), v).exp()
+}
+
+/// The vector part of the logarithm of the unit quaternion `q`.
+fn ln<S: BaseFloat>(q: Quaternion<S>) -> Vector3<S> {
+    q.ln().v
+}
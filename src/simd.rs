@@ -0,0 +1,285 @@
+// Copyright 2016 The CGMath Developers. For a full listing of the authors,
+// refer to the Cargo.toml file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! SIMD implementations of the hot `f32` operations, used by the generic
+//! matrix, quaternion and vector implementations when the `simd` feature is
+//! enabled.
+//!
+//! The kernels are written once against a handful of operations on four
+//! lanes, which use SSE2 on x86, NEON on AArch64, and plain arrays elsewhere.
+//! The public functions at the bottom of the module are generic over the
+//! scalar, and return `None` unless it is `f32`.
+
+use std::any;
+use std::mem;
+
+use matrix::Matrix4;
+use quaternion::Quaternion;
+use vector::Vector4;
+
+#[cfg(any(target_arch = "x86_64", all(target_arch = "x86", target_feature = "sse2")))]
+#[macro_use]
+#[allow(unused_unsafe)]
+mod lanes {
+    #[cfg(target_arch = "x86")]
+    pub use std::arch::x86::*;
+    #[cfg(target_arch = "x86_64")]
+    pub use std::arch::x86_64::*;
+
+    pub type F32x4 = __m128;
+
+    #[inline(always)]
+    pub fn load(a: &[f32; 4]) -> F32x4 { unsafe { _mm_loadu_ps(a.as_ptr()) } }
+    #[inline(always)]
+    pub fn store(v: F32x4) -> [f32; 4] {
+        let mut a = [0.0; 4];
+        unsafe { _mm_storeu_ps(a.as_mut_ptr(), v) };
+        a
+    }
+    #[inline(always)]
+    pub fn splat(x: f32) -> F32x4 { unsafe { _mm_set1_ps(x) } }
+    #[inline(always)]
+    pub fn add(a: F32x4, b: F32x4) -> F32x4 { unsafe { _mm_add_ps(a, b) } }
+    #[inline(always)]
+    pub fn sub(a: F32x4, b: F32x4) -> F32x4 { unsafe { _mm_sub_ps(a, b) } }
+    #[inline(always)]
+    pub fn mul(a: F32x4, b: F32x4) -> F32x4 { unsafe { _mm_mul_ps(a, b) } }
+
+    /// The sum of the four lanes.
+    #[inline(always)]
+    pub fn sum(v: F32x4) -> f32 {
+        unsafe {
+            let pairs = _mm_add_ps(v, _mm_movehl_ps(v, v));
+            _mm_cvtss_f32(_mm_add_ss(pairs, _mm_shuffle_ps(pairs, pairs, 1)))
+        }
+    }
+
+    /// Rearrange the lanes of `$v`, taking lane `$i` for each position.
+    macro_rules! shuffle {
+        ($v:expr, [$i0:expr, $i1:expr, $i2:expr, $i3:expr]) => {{
+            let v = $v;
+            unsafe { _mm_shuffle_ps(v, v, ($i3 << 6) | ($i2 << 4) | ($i1 << 2) | $i0) }
+        }};
+    }
+}
+
+#[cfg(target_arch = "aarch64")]
+#[macro_use]
+#[allow(unused_unsafe)]
+mod lanes {
+    pub use std::arch::aarch64::*;
+
+    pub type F32x4 = float32x4_t;
+
+    #[inline(always)]
+    pub fn load(a: &[f32; 4]) -> F32x4 { unsafe { vld1q_f32(a.as_ptr()) } }
+    #[inline(always)]
+    pub fn store(v: F32x4) -> [f32; 4] {
+        let mut a = [0.0; 4];
+        unsafe { vst1q_f32(a.as_mut_ptr(), v) };
+        a
+    }
+    #[inline(always)]
+    pub fn splat(x: f32) -> F32x4 { unsafe { vdupq_n_f32(x) } }
+    #[inline(always)]
+    pub fn add(a: F32x4, b: F32x4) -> F32x4 { unsafe { vaddq_f32(a, b) } }
+    #[inline(always)]
+    pub fn sub(a: F32x4, b: F32x4) -> F32x4 { unsafe { vsubq_f32(a, b) } }
+    #[inline(always)]
+    pub fn mul(a: F32x4, b: F32x4) -> F32x4 { unsafe { vmulq_f32(a, b) } }
+
+    /// The sum of the four lanes.
+    #[inline(always)]
+    pub fn sum(v: F32x4) -> f32 { unsafe { vaddvq_f32(v) } }
+
+    /// Rearrange the lanes of `$v`, taking lane `$i` for each position.
+    ///
+    /// NEON has no general four-lane shuffle, so this goes through memory and
+    /// is left to the compiler to turn into table lookups.
+    macro_rules! shuffle {
+        ($v:expr, [$i0:expr, $i1:expr, $i2:expr, $i3:expr]) => {{
+            let a = store($v);
+            load(&[a[$i0], a[$i1], a[$i2], a[$i3]])
+        }};
+    }
+}
+
+#[cfg(not(any(target_arch = "x86_64",
+              all(target_arch = "x86", target_feature = "sse2"),
+              target_arch = "aarch64")))]
+#[macro_use]
+mod lanes {
+    pub type F32x4 = [f32; 4];
+
+    #[inline(always)]
+    pub fn load(a: &[f32; 4]) -> F32x4 { *a }
+    #[inline(always)]
+    pub fn store(v: F32x4) -> [f32; 4] { v }
+    #[inline(always)]
+    pub fn splat(x: f32) -> F32x4 { [x; 4] }
+    #[inline(always)]
+    pub fn add(a: F32x4, b: F32x4) -> F32x4 { [a[0] + b[0], a[1] + b[1], a[2] + b[2], a[3] + b[3]] }
+    #[inline(always)]
+    pub fn sub(a: F32x4, b: F32x4) -> F32x4 { [a[0] - b[0], a[1] - b[1], a[2] - b[2], a[3] - b[3]] }
+    #[inline(always)]
+    pub fn mul(a: F32x4, b: F32x4) -> F32x4 { [a[0] * b[0], a[1] * b[1], a[2] * b[2], a[3] * b[3]] }
+
+    /// The sum of the four lanes.
+    #[inline(always)]
+    pub fn sum(v: F32x4) -> f32 { (v[0] + v[2]) + (v[1] + v[3]) }
+
+    /// Rearrange the lanes of `$v`, taking lane `$i` for each position.
+    macro_rules! shuffle {
+        ($v:expr, [$i0:expr, $i1:expr, $i2:expr, $i3:expr]) => {{
+            let a = $v;
+            [a[$i0], a[$i1], a[$i2], a[$i3]]
+        }};
+    }
+}
+
+use self::lanes::*;
+
+/// The columns of a `Matrix4`, loaded into registers.
+struct Columns([F32x4; 4]);
+
+impl Columns {
+    #[inline(always)]
+    fn load(m: &Matrix4<f32>) -> Columns {
+        Columns([load(m.x.as_ref()), load(m.y.as_ref()), load(m.z.as_ref()), load(m.w.as_ref())])
+    }
+
+    /// The product of the matrix with `v`, as the sum of the columns scaled by
+    /// the elements of `v`.
+    #[inline(always)]
+    fn mul_vector(&self, v: &Vector4<f32>) -> F32x4 {
+        let xy = add(mul(self.0[0], splat(v.x)), mul(self.0[1], splat(v.y)));
+        let zw = add(mul(self.0[2], splat(v.z)), mul(self.0[3], splat(v.w)));
+        add(xy, zw)
+    }
+}
+
+/// The three dimensional cross product of the first three lanes of `a` and
+/// `b`. The last lane of the result is zero.
+#[inline(always)]
+fn cross(a: F32x4, b: F32x4) -> F32x4 {
+    sub(mul(shuffle!(a, [1, 2, 0, 3]), shuffle!(b, [2, 0, 1, 3])),
+        mul(shuffle!(a, [2, 0, 1, 3]), shuffle!(b, [1, 2, 0, 3])))
+}
+
+#[inline]
+fn matrix4_mul_f32(lhs: &Matrix4<f32>, rhs: &Matrix4<f32>) -> Matrix4<f32> {
+    let lhs = Columns::load(lhs);
+    Matrix4::from_cols(store(lhs.mul_vector(&rhs.x)).into(),
+                       store(lhs.mul_vector(&rhs.y)).into(),
+                       store(lhs.mul_vector(&rhs.z)).into(),
+                       store(lhs.mul_vector(&rhs.w)).into())
+}
+
+#[inline]
+fn matrix4_mul_vector4_f32(m: &Matrix4<f32>, v: &Vector4<f32>) -> Vector4<f32> {
+    store(Columns::load(m).mul_vector(v)).into()
+}
+
+#[inline]
+fn dot4_f32(a: &[f32; 4], b: &[f32; 4]) -> f32 {
+    sum(mul(load(a), load(b)))
+}
+
+#[inline]
+fn quaternion_mul_f32(lhs: &Quaternion<f32>, rhs: &Quaternion<f32>) -> Quaternion<f32> {
+    // With the lanes holding `[s, x, y, z]`, each lane of the Hamilton
+    // product is the sum of four products, the first of which is always with
+    // `lhs.s`, and the sign of the others depends only on the term and lane.
+    let (a, b) = (load(lhs.as_ref()), load(rhs.as_ref()));
+    let negate_first = load(&[-1.0, 1.0, 1.0, 1.0]);
+
+    let t0 = mul(splat(lhs.s), b);
+    let t1 = mul(mul(shuffle!(a, [1, 1, 2, 3]), shuffle!(b, [1, 0, 0, 0])), negate_first);
+    let t2 = mul(mul(shuffle!(a, [2, 2, 3, 1]), shuffle!(b, [2, 3, 1, 2])), negate_first);
+    let t3 = mul(shuffle!(a, [3, 3, 1, 2]), shuffle!(b, [3, 2, 3, 1]));
+    store(sub(add(add(t0, t1), t2), t3)).into()
+}
+
+/// The determinant of `m`, computed in the same way as by
+/// `matrix4_adjugate_f32`.
+#[inline]
+fn matrix4_determinant_f32(m: &Matrix4<f32>) -> f32 {
+    let Columns([a, b, c, d]) = Columns::load(m);
+    let (x, y, z, w) = (splat(m.x.w), splat(m.y.w), splat(m.z.w), splat(m.w.w));
+    let u = sub(mul(a, y), mul(b, x));
+    let v = sub(mul(c, w), mul(d, z));
+    sum(add(mul(cross(a, b), v), mul(cross(c, d), u)))
+}
+
+/// The adjugate and determinant of `m`, following the formulation of the
+/// inverse in terms of cross products from Eric Lengyel, "Foundations of Game
+/// Engine Development, Volume 1", section 1.7.5.
+#[inline]
+fn matrix4_adjugate_f32(m: &Matrix4<f32>) -> (Matrix4<f32>, f32) {
+    let Columns([a, b, c, d]) = Columns::load(m);
+    let (x, y, z, w) = (splat(m.x.w), splat(m.y.w), splat(m.z.w), splat(m.w.w));
+
+    // The last lane of each of these is zero, so it drops out of the sums.
+    let s = cross(a, b);
+    let t = cross(c, d);
+    let u = sub(mul(a, y), mul(b, x));
+    let v = sub(mul(c, w), mul(d, z));
+    let det = sum(add(mul(s, v), mul(t, u)));
+
+    let r0 = store(add(cross(b, v), mul(t, y)));
+    let r1 = store(sub(cross(v, a), mul(t, x)));
+    let r2 = store(add(cross(d, u), mul(s, w)));
+    let r3 = store(sub(cross(u, c), mul(s, z)));
+    let r = [r0, r1, r2, r3];
+    let last = [-sum(mul(b, t)), sum(mul(a, t)), -sum(mul(d, s)), sum(mul(c, s))];
+
+    // The rows of the adjugate are `r`, with `last` as the final column.
+    let adjugate = Matrix4::new(r[0][0], r[1][0], r[2][0], r[3][0],
+                                r[0][1], r[1][1], r[2][1], r[3][1],
+                                r[0][2], r[1][2], r[2][2], r[3][2],
+                                last[0], last[1], last[2], last[3]);
+    (adjugate, det)
+}
+
+/// Whether `S` is `f32`. `TypeId` would need `S: 'static`, which the generic
+/// implementations do not otherwise require of their scalars.
+#[inline(always)]
+fn is_f32<S>() -> bool {
+    mem::size_of::<S>() == mem::size_of::<f32>() && any::type_name::<S>() == "f32"
+}
+
+macro_rules! dispatch {
+    ($(fn $name:ident<$S:ident>($($arg:ident: $Arg:ty),+) -> $Output:ty = $kernel:ident;)+) => {
+        $(
+            #[inline]
+            pub fn $name<$S>($($arg: $Arg),+) -> Option<$Output> {
+                if !is_f32::<$S>() {
+                    return None;
+                }
+                // `S` is `f32`, so these only change the name of the type.
+                unsafe { Some(mem::transmute_copy(&$kernel($(mem::transmute_copy(&$arg)),+))) }
+            }
+        )+
+    }
+}
+
+dispatch! {
+    fn matrix4_mul<S>(lhs: &Matrix4<S>, rhs: &Matrix4<S>) -> Matrix4<S> = matrix4_mul_f32;
+    fn matrix4_mul_vector4<S>(m: &Matrix4<S>, v: &Vector4<S>) -> Vector4<S> = matrix4_mul_vector4_f32;
+    fn matrix4_determinant<S>(m: &Matrix4<S>) -> S = matrix4_determinant_f32;
+    fn matrix4_adjugate<S>(m: &Matrix4<S>) -> (Matrix4<S>, S) = matrix4_adjugate_f32;
+    fn quaternion_mul<S>(lhs: &Quaternion<S>, rhs: &Quaternion<S>) -> Quaternion<S> = quaternion_mul_f32;
+    fn dot4<S>(a: &[S; 4], b: &[S; 4]) -> S = dot4_f32;
+}
@@ -0,0 +1,97 @@
+// Copyright 2016 The CGMath Developers. For a full listing of the authors,
+// refer to the Cargo.toml file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use structure::*;
+
+use aabb::Aabb3;
+use approx::ApproxEq;
+use num::BaseFloat;
+use point::Point3;
+use vector::Vector3;
+
+/// A sphere in 3-dimensional space.
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "rustc-serialize", derive(RustcEncodable, RustcDecodable))]
+#[cfg_attr(feature = "eders", derive(Serialize, Deserialize))]
+pub struct Sphere<S> {
+    pub center: Point3<S>,
+    pub radius: S,
+}
+
+impl<S: BaseFloat> Sphere<S> {
+    #[inline]
+    pub fn new(center: Point3<S>, radius: S) -> Sphere<S> {
+        Sphere { center: center, radius: radius }
+    }
+
+    /// Whether `point` lies inside or on the surface of the sphere.
+    #[inline]
+    pub fn contains(&self, point: Point3<S>) -> bool {
+        self.center.distance2(point) <= self.radius * self.radius
+    }
+
+    /// Whether the sphere and `other` overlap or touch.
+    #[inline]
+    pub fn intersects(&self, other: &Sphere<S>) -> bool {
+        let radii = self.radius + other.radius;
+        self.center.distance2(other.center) <= radii * radii
+    }
+
+    /// Whether the sphere and `aabb` overlap or touch.
+    pub fn intersects_aabb(&self, aabb: &Aabb3<S>) -> bool {
+        let closest = Point3::new(self.center.x.max(aabb.min.x).min(aabb.max.x),
+                                  self.center.y.max(aabb.min.y).min(aabb.max.y),
+                                  self.center.z.max(aabb.min.z).min(aabb.max.z));
+        self.contains(closest)
+    }
+
+    /// The smallest axis-aligned box containing the sphere.
+    #[inline]
+    pub fn aabb(&self) -> Aabb3<S> {
+        let extents = Vector3::new(self.radius, self.radius, self.radius);
+        Aabb3::new(self.center + -extents, self.center + extents)
+    }
+}
+
+impl<S: BaseFloat> ApproxEq for Sphere<S> {
+    type Epsilon = S::Epsilon;
+
+    #[inline]
+    fn default_epsilon() -> S::Epsilon {
+        S::default_epsilon()
+    }
+
+    #[inline]
+    fn default_max_relative() -> S::Epsilon {
+        S::default_max_relative()
+    }
+
+    #[inline]
+    fn default_max_ulps() -> u32 {
+        S::default_max_ulps()
+    }
+
+    #[inline]
+    fn relative_eq(&self, other: &Self, epsilon: S::Epsilon, max_relative: S::Epsilon) -> bool {
+        Point3::relative_eq(&self.center, &other.center, epsilon, max_relative) &&
+        S::relative_eq(&self.radius, &other.radius, epsilon, max_relative)
+    }
+
+    #[inline]
+    fn ulps_eq(&self, other: &Self, epsilon: S::Epsilon, max_ulps: u32) -> bool {
+        Point3::ulps_eq(&self.center, &other.center, epsilon, max_ulps) &&
+        S::ulps_eq(&self.radius, &other.radius, epsilon, max_ulps)
+    }
+}
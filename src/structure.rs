@@ -45,6 +45,16 @@ pub trait Array where
     /// ```
     fn from_value(value: Self::Element) -> Self;
 
+    /// The number of elements in the array.
+    ///
+    /// ```rust
+    /// use cgmath::prelude::*;
+    /// use cgmath::Vector3;
+    ///
+    /// assert_eq!(Vector3::<f32>::len(), 3);
+    /// ```
+    fn len() -> usize;
+
     /// Get the pointer to the first element of the array.
     #[inline]
     fn as_ptr(&self) -> *const Self::Element {
@@ -399,13 +409,13 @@ pub trait EuclideanSpace: Copy + Clone where
 /// trait. This is due to the complexities of implementing these operators with
 /// Rust's current type system. For the multiplication of square matrices,
 /// see `SquareMatrix`.
+///
+/// The scalar only needs to be a `BaseNum`, so integer matrices can be used
+/// for exact transforms.
 pub trait Matrix: VectorSpace where
-    Self::Scalar: BaseFloat,
-
     // FIXME: Ugly type signatures - blocked by rust-lang/rust#24092
     Self: Index<usize, Output = <Self as Matrix>::Column>,
     Self: IndexMut<usize, Output = <Self as Matrix>::Column>,
-    Self: ApproxEq<Epsilon = <Self as VectorSpace>::Scalar>,
 {
     /// The row vector of the matrix.
     type Row: VectorSpace<Scalar = Self::Scalar> + Array<Element = Self::Scalar>;
@@ -451,9 +461,10 @@ pub trait Matrix: VectorSpace where
 }
 
 /// A column-major major matrix where the rows and column vectors are of the same dimensions.
+///
+/// Inversion and the approximate tests for the identity, diagonal and
+/// symmetric matrices are only available when the scalar is a `BaseFloat`.
 pub trait SquareMatrix where
-    Self::Scalar: BaseFloat,
-
     Self: One,
 
     Self: Matrix<
@@ -507,24 +518,31 @@ pub trait SquareMatrix where
     /// the identity matrix. Returns `None` if this matrix is not invertible
     /// (has a determinant of zero).
     #[must_use]
-    fn invert(&self) -> Option<Self>;
+    fn invert(&self) -> Option<Self> where Self::Scalar: BaseFloat;
 
     /// Test if this matrix is invertible.
     #[inline]
-    fn is_invertible(&self) -> bool { ulps_ne!(self.determinant(), &Self::Scalar::zero()) }
+    fn is_invertible(&self) -> bool where Self::Scalar: BaseFloat {
+        ulps_ne!(self.determinant(), &Self::Scalar::zero())
+    }
 
     /// Test if this matrix is the identity matrix. That is, it is diagonal
     /// and every element in the diagonal is one.
     #[inline]
-    fn is_identity(&self) -> bool { ulps_eq!(self, &Self::identity()) }
+    fn is_identity(&self) -> bool where
+        Self::Scalar: BaseFloat,
+        Self: ApproxEq<Epsilon = <Self as VectorSpace>::Scalar>,
+    {
+        ulps_eq!(self, &Self::identity())
+    }
 
     /// Test if this is a diagonal matrix. That is, every element outside of
     /// the diagonal is 0.
-    fn is_diagonal(&self) -> bool;
+    fn is_diagonal(&self) -> bool where Self::Scalar: BaseFloat;
 
     /// Test if this matrix is symmetric. That is, it is equal to its
     /// transpose.
-    fn is_symmetric(&self) -> bool;
+    fn is_symmetric(&self) -> bool where Self::Scalar: BaseFloat;
 }
 
 /// Angles and their associated trigonometric functions.
@@ -0,0 +1,180 @@
+// Copyright 2016 The CGMath Developers. For a full listing of the authors,
+// refer to the Cargo.toml file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Singular value decomposition of square matrices, and the least-squares
+//! helpers built on top of it.
+
+use structure::*;
+
+use matrix::{Matrix2, Matrix3, Matrix4};
+use num::BaseFloat;
+use vector::{Vector2, Vector3, Vector4};
+
+/// The maximum number of sweeps used by the one-sided Jacobi method.
+const JACOBI_SWEEPS: usize = 32;
+
+/// Decompose `m` into `u * Matrix::from_diagonal(sigma) * vᵀ`, with the
+/// one-sided Jacobi method described by Demmel and Veselić in "Jacobi's Method
+/// is More Accurate than QR".
+///
+/// The singular values are sorted from largest to smallest, and `u` and `v`
+/// are orthogonal.
+fn jacobi_svd<S, M>(m: M, n: usize) -> (M, M::ColumnRow, M) where
+    S: BaseFloat,
+    M: SquareMatrix + VectorSpace<Scalar = S>,
+    M::ColumnRow: InnerSpace<Scalar = S>,
+{
+    let two = S::one() + S::one();
+    let epsilon = S::default_epsilon();
+
+    // Rotate pairs of columns of `u` until they are all orthogonal, so that
+    // `u = m * v` holds an orthogonal basis scaled by the singular values.
+    let mut u = m;
+    let mut v = M::identity();
+    for _ in 0..JACOBI_SWEEPS {
+        let mut rotated = false;
+        for i in 0..n {
+            for j in i + 1..n {
+                let alpha = u[i].magnitude2();
+                let beta = u[j].magnitude2();
+                let gamma = u[i].dot(u[j]);
+                if gamma.abs() <= epsilon * (alpha * beta).sqrt() {
+                    continue;
+                }
+                rotated = true;
+
+                let zeta = (beta - alpha) / (two * gamma);
+                let t = zeta.signum() / (zeta.abs() + (zeta * zeta + S::one()).sqrt());
+                let c = (t * t + S::one()).sqrt().recip();
+                let s = c * t;
+
+                let (ui, uj) = (u[i], u[j]);
+                u[i] = ui * c - uj * s;
+                u[j] = ui * s + uj * c;
+                let (vi, vj) = (v[i], v[j]);
+                v[i] = vi * c - vj * s;
+                v[j] = vi * s + vj * c;
+            }
+        }
+        if !rotated {
+            break;
+        }
+    }
+
+    let mut sigma = M::ColumnRow::zero();
+    for i in 0..n {
+        sigma[i] = u[i].magnitude();
+    }
+
+    // Sort the singular values, moving the singular vectors along with them.
+    for i in 0..n {
+        for j in 0..n - 1 - i {
+            if sigma[j] < sigma[j + 1] {
+                sigma.swap_elements(j, j + 1);
+                u.swap_columns(j, j + 1);
+                v.swap_columns(j, j + 1);
+            }
+        }
+    }
+
+    // Normalize the columns of `u`. The columns belonging to singular values
+    // that are zero, up to rounding, are replaced by whichever of the axes is
+    // furthest from the span of the previous columns.
+    let tolerance = sigma_tolerance(sigma[0], n);
+    for i in 0..n {
+        if sigma[i] > tolerance {
+            u[i] = u[i] / sigma[i];
+        } else {
+            let mut best = M::ColumnRow::zero();
+            for k in 0..n {
+                let mut axis = M::identity()[k];
+                for j in 0..i {
+                    axis = axis - u[j] * u[j].dot(axis);
+                }
+                if axis.magnitude2() > best.magnitude2() {
+                    best = axis;
+                }
+            }
+            u[i] = best.normalize();
+        }
+    }
+
+    (u, sigma, v)
+}
+
+/// The singular values at or below which a matrix is treated as singular.
+pub fn sigma_tolerance<S: BaseFloat>(max: S, n: usize) -> S {
+    max * S::from(n).unwrap() * S::default_epsilon()
+}
+
+macro_rules! impl_svd {
+    ($MatrixN:ident, $VectorN:ident, $n:expr) => {
+        impl<S: BaseFloat> $MatrixN<S> {
+            /// The singular value decomposition of the matrix, as `(u, sigma,
+            /// v_t)`, where `u` and `v_t` are orthogonal and `sigma` is
+            /// diagonal, such that `self = u * sigma * v_t`.
+            ///
+            /// The singular values on the diagonal of `sigma` are sorted from
+            /// largest to smallest. `u` and `v_t` may be reflections rather
+            /// than rotations.
+            pub fn svd(&self) -> ($MatrixN<S>, $MatrixN<S>, $MatrixN<S>) {
+                let (u, sigma, v) = jacobi_svd(*self, $n);
+                (u, $MatrixN::from_diagonal(sigma), v.transpose())
+            }
+
+            /// The singular values of the matrix, sorted from largest to
+            /// smallest.
+            #[inline]
+            pub fn singular_values(&self) -> $VectorN<S> {
+                jacobi_svd(*self, $n).1
+            }
+
+            /// The Moore-Penrose pseudo-inverse of the matrix. This is the
+            /// inverse for invertible matrices, and gives the least-squares
+            /// solution of `self * x = b` as `pseudo_inverse * b` otherwise.
+            pub fn pseudo_inverse(&self) -> $MatrixN<S> {
+                let (u, mut sigma, v) = jacobi_svd(*self, $n);
+                let tolerance = sigma_tolerance(sigma[0], $n);
+                for i in 0..$n {
+                    sigma[i] = if sigma[i] > tolerance { sigma[i].recip() } else { S::zero() };
+                }
+                v * $MatrixN::from_diagonal(sigma) * u.transpose()
+            }
+
+            /// The number of linearly independent columns of the matrix.
+            pub fn rank(&self) -> usize {
+                let sigma = self.singular_values();
+                let tolerance = sigma_tolerance(sigma[0], $n);
+                (0..$n).filter(|&i| sigma[i] > tolerance).count()
+            }
+
+            /// The condition number of the matrix, the ratio of its largest
+            /// and smallest singular values. This is infinite for singular
+            /// matrices.
+            pub fn condition_number(&self) -> S {
+                let sigma = self.singular_values();
+                if sigma[$n - 1].is_zero() {
+                    S::infinity()
+                } else {
+                    sigma[0] / sigma[$n - 1]
+                }
+            }
+        }
+    }
+}
+
+impl_svd!(Matrix2, Vector2, 2);
+impl_svd!(Matrix3, Vector3, 3);
+impl_svd!(Matrix4, Vector4, 4);
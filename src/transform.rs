@@ -32,6 +32,11 @@ pub trait Transform<P: EuclideanSpace>: Sized {
 
     /// Create a transformation that rotates a vector to look at `center` from
     /// `eye`, using `up` for orientation.
+    ///
+    /// The handedness of the resulting view space depends on the
+    /// implementation: `Matrix4` produces a right-handed view, while the
+    /// other transforms produce a left-handed one. Use `Transform3::look_at_lh`
+    /// or `Transform3::look_at_rh` to be explicit.
     fn look_at(eye: P, center: P, up: P::Diff) -> Self;
 
     /// Transform a vector using this transform.
@@ -125,7 +130,26 @@ impl<P: EuclideanSpace, R: Rotation<P>> Transform<P> for Decomposed<P::Diff, R>
 }
 
 pub trait Transform2<S: BaseNum>: Transform<Point2<S>> + Into<Matrix3<S>> {}
-pub trait Transform3<S: BaseNum>: Transform<Point3<S>> + Into<Matrix4<S>> {}
+pub trait Transform3<S: BaseNum>: Transform<Point3<S>> + Into<Matrix4<S>> {
+    /// Create a transformation into a left-handed view space, where the
+    /// viewer is at the origin looking down the positive `z` axis, from a
+    /// viewer at `eye` looking towards `center`.
+    ///
+    /// This has no default implementation, as the handedness of
+    /// `Transform::look_at` depends on the implementation.
+    fn look_at_lh(eye: Point3<S>, center: Point3<S>, up: Vector3<S>) -> Self;
+
+    /// Create a transformation into a right-handed view space, where the
+    /// viewer is at the origin looking down the negative `z` axis, from a
+    /// viewer at `eye` looking towards `center`.
+    ///
+    /// The default implementation is `look_at_lh` looking directly away from
+    /// `center`, which gives the same view space, mirrored along `z`.
+    #[inline]
+    fn look_at_rh(eye: Point3<S>, center: Point3<S>, up: Vector3<S>) -> Self {
+        Self::look_at_lh(eye, eye + (eye - center), up)
+    }
+}
 
 impl<S: BaseFloat, R: Rotation2<S>> From<Decomposed<Vector2<S>, R>> for Matrix3<S> {
     fn from(dec: Decomposed<Vector2<S>, R>) -> Matrix3<S> {
@@ -147,7 +171,192 @@ impl<S: BaseFloat, R: Rotation3<S>> From<Decomposed<Vector3<S>, R>> for Matrix4<
 
 impl<S: BaseFloat, R: Rotation2<S>> Transform2<S> for Decomposed<Vector2<S>, R> {}
 
-impl<S: BaseFloat, R: Rotation3<S>> Transform3<S> for Decomposed<Vector3<S>, R> {}
+impl<S: BaseFloat, R: Rotation3<S>> Transform3<S> for Decomposed<Vector3<S>, R> {
+    #[inline]
+    fn look_at_lh(eye: Point3<S>, center: Point3<S>, up: Vector3<S>) -> Decomposed<Vector3<S>, R> {
+        let rot = R::look_at_lh(center - eye, up);
+        Decomposed {
+            scale: S::one(),
+            rot: rot,
+            disp: rot.rotate_vector(Point3::origin() - eye),
+        }
+    }
+
+    #[inline]
+    fn look_at_rh(eye: Point3<S>, center: Point3<S>, up: Vector3<S>) -> Decomposed<Vector3<S>, R> {
+        let rot = R::look_at_rh(center - eye, up);
+        Decomposed {
+            scale: S::one(),
+            rot: rot,
+            disp: rot.rotate_vector(Point3::origin() - eye),
+        }
+    }
+}
+
+/// A three-dimensional transformation consisting of a scale along each axis,
+/// followed by a rotation and then a displacement. This is the form that
+/// the nodes of most scene formats, such as glTF and FBX, are stored in.
+///
+/// Composing rotated non-uniform scales can produce shear, which this type
+/// cannot represent. See `concat` and `inverse_transform` for how this is
+/// handled.
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "rustc-serialize", derive(RustcEncodable, RustcDecodable))]
+#[cfg_attr(feature = "eders", derive(Serialize, Deserialize))]
+pub struct NonUniformDecomposed<S, R> {
+    pub scale: Vector3<S>,
+    pub rot: R,
+    pub disp: Vector3<S>,
+}
+
+impl<S: BaseFloat, R: Rotation3<S>> Transform<Point3<S>> for NonUniformDecomposed<S, R> {
+    #[inline]
+    fn one() -> NonUniformDecomposed<S, R> {
+        NonUniformDecomposed {
+            scale: Vector3::from_value(S::one()),
+            rot: R::one(),
+            disp: Vector3::zero(),
+        }
+    }
+
+    #[inline]
+    fn look_at(eye: Point3<S>, center: Point3<S>, up: Vector3<S>) -> NonUniformDecomposed<S, R> {
+        Decomposed::look_at(eye, center, up).into()
+    }
+
+    #[inline]
+    fn transform_vector(&self, vec: Vector3<S>) -> Vector3<S> {
+        self.rot.rotate_vector(vec.mul_element_wise(self.scale))
+    }
+
+    #[inline]
+    fn transform_point(&self, point: Point3<S>) -> Point3<S> {
+        Point3::from_vec(self.transform_vector(point.to_vec()) + self.disp)
+    }
+
+    /// Combine this transform with another, applying `other` first.
+    ///
+    /// The linear part of the combination is `R₁ S₁ R₂ S₂ = R₁ R₂ (R₂⁻¹ S₁ R₂) S₂`,
+    /// so the result has the rotation `R₁ R₂`, and the scale of `R₂⁻¹ S₁ R₂`
+    /// followed by `S₂`. This is exact when `R₂⁻¹ S₁ R₂` is diagonal, which is
+    /// the case when this transform has a uniform scale, or when the rotation
+    /// of `other` maps each axis onto an axis with the same scale.
+    ///
+    /// Otherwise the exact combination contains shear, which is dropped by
+    /// keeping only the diagonal of `R₂⁻¹ S₁ R₂`, the closest scale in the
+    /// Frobenius norm. The displacement is always exact, so the origin of
+    /// `other` is still transformed correctly. Convert both transforms to
+    /// `Matrix4` to combine them exactly.
+    fn concat(&self, other: &NonUniformDecomposed<S, R>) -> NonUniformDecomposed<S, R> {
+        let r: Matrix3<S> = other.rot.into();
+        let m = r.transpose() * Matrix3::from_diagonal(self.scale) * r;
+        NonUniformDecomposed {
+            scale: m.diagonal().mul_element_wise(other.scale),
+            rot: self.rot * other.rot,
+            disp: self.transform_vector(other.disp) + self.disp,
+        }
+    }
+
+    /// Create the inverse of this transform.
+    ///
+    /// The inverse applies the inverse rotation before the inverse scale,
+    /// which can only be rearranged into a scale followed by a rotation if
+    /// the scale is uniform, or if the rotation maps each axis onto an axis
+    /// with the same scale. Otherwise the inverse contains shear, and `None`
+    /// is returned. `None` is also returned if any of the scales are zero.
+    fn inverse_transform(&self) -> Option<NonUniformDecomposed<S, R>> {
+        if self.scale.x.is_zero() || self.scale.y.is_zero() || self.scale.z.is_zero() {
+            return None;
+        }
+
+        // The inverse of the linear part is `S⁻¹ R⁻¹ = R⁻¹ (R S⁻¹ R⁻¹)`,
+        // which has the required form when `R S⁻¹ R⁻¹` is diagonal.
+        let r: Matrix3<S> = self.rot.into();
+        let inv_scale = Vector3::from_value(S::one()).div_element_wise(self.scale);
+        let m = r * Matrix3::from_diagonal(inv_scale) * r.transpose();
+
+        let tolerance = S::default_epsilon().sqrt();
+        for i in 0..3 {
+            for j in 0..i {
+                if m[i][j].abs() > tolerance * (m[i][i].abs() + m[j][j].abs()) {
+                    return None;
+                }
+            }
+        }
+
+        let rot = self.rot.invert();
+        let scale = m.diagonal();
+        Some(NonUniformDecomposed {
+            scale: scale,
+            rot: rot,
+            disp: -rot.rotate_vector(self.disp.mul_element_wise(scale)),
+        })
+    }
+}
+
+impl<S: BaseFloat, R: Rotation3<S>> Transform3<S> for NonUniformDecomposed<S, R> {
+    #[inline]
+    fn look_at_lh(eye: Point3<S>, center: Point3<S>, up: Vector3<S>) -> NonUniformDecomposed<S, R> {
+        Decomposed::look_at_lh(eye, center, up).into()
+    }
+
+    #[inline]
+    fn look_at_rh(eye: Point3<S>, center: Point3<S>, up: Vector3<S>) -> NonUniformDecomposed<S, R> {
+        Decomposed::look_at_rh(eye, center, up).into()
+    }
+}
+
+impl<S: BaseFloat, R: Rotation3<S>> From<Decomposed<Vector3<S>, R>> for NonUniformDecomposed<S, R> {
+    fn from(dec: Decomposed<Vector3<S>, R>) -> NonUniformDecomposed<S, R> {
+        NonUniformDecomposed {
+            scale: Vector3::from_value(dec.scale),
+            rot: dec.rot,
+            disp: dec.disp,
+        }
+    }
+}
+
+impl<S: BaseFloat, R: Rotation3<S>> From<NonUniformDecomposed<S, R>> for Matrix4<S> {
+    fn from(dec: NonUniformDecomposed<S, R>) -> Matrix4<S> {
+        let m: Matrix3<_> = dec.rot.into();
+        let mut m: Matrix4<_> = (m * Matrix3::from_diagonal(dec.scale)).into();
+        m.w = dec.disp.extend(S::one());
+        m
+    }
+}
+
+impl<S: BaseFloat, R: ApproxEq<Epsilon = S>> ApproxEq for NonUniformDecomposed<S, R> {
+    type Epsilon = S;
+
+    #[inline]
+    fn default_epsilon() -> S {
+        S::default_epsilon()
+    }
+
+    #[inline]
+    fn default_max_relative() -> S {
+        S::default_max_relative()
+    }
+
+    #[inline]
+    fn default_max_ulps() -> u32 {
+        S::default_max_ulps()
+    }
+
+    #[inline]
+    fn relative_eq(&self, other: &Self, epsilon: S, max_relative: S) -> bool {
+        Vector3::relative_eq(&self.scale, &other.scale, epsilon, max_relative) &&
+        R::relative_eq(&self.rot, &other.rot, epsilon, max_relative) &&
+        Vector3::relative_eq(&self.disp, &other.disp, epsilon, max_relative)
+    }
+
+    #[inline]
+    fn ulps_eq(&self, other: &Self, epsilon: S, max_ulps: u32) -> bool {
+        Vector3::ulps_eq(&self.scale, &other.scale, epsilon, max_ulps) &&
+        R::ulps_eq(&self.rot, &other.rot, epsilon, max_ulps) &&
+        Vector3::ulps_eq(&self.disp, &other.disp, epsilon, max_ulps)
+    }
+}
 
 impl<S: VectorSpace, R, E: BaseFloat> ApproxEq for Decomposed<S, R>
     where S: ApproxEq<Epsilon = E>, S::Scalar: ApproxEq<Epsilon = E>, R: ApproxEq<Epsilon = E>
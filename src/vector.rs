@@ -23,7 +23,11 @@ use structure::*;
 
 use angle::Rad;
 use approx::ApproxEq;
+#[cfg(feature = "fixed-point")]
+use fixed::{Fixed32, Fixed64};
 use num::{BaseNum, BaseFloat, PartialOrd};
+#[cfg(feature = "simd")]
+use simd;
 
 /// A 1-dimensional vector.
 ///
@@ -96,6 +100,9 @@ macro_rules! impl_vector {
             }
         }
 
+        #[cfg(feature = "swizzle")]
+        impl_swizzle_functions!($VectorN; Vector2, Vector3, Vector4; $($field)+);
+
         /// The short constructor.
         #[inline]
         pub fn $constructor<S>($($field: S),+) -> $VectorN<S> {
@@ -127,6 +134,11 @@ macro_rules! impl_vector {
                 $VectorN { $($field: scalar),+ }
             }
 
+            #[inline]
+            fn len() -> usize {
+                $n
+            }
+
             #[inline]
             fn sum(self) -> S where S: Add<Output = S> {
                 fold_array!(add, { $(self.$field),+ })
@@ -282,6 +294,10 @@ macro_rules! impl_vector {
         impl_scalar_ops!($VectorN<i64> { $($field),+ });
         impl_scalar_ops!($VectorN<f32> { $($field),+ });
         impl_scalar_ops!($VectorN<f64> { $($field),+ });
+        #[cfg(feature = "fixed-point")]
+        impl_scalar_ops!($VectorN<Fixed32> { $($field),+ });
+        #[cfg(feature = "fixed-point")]
+        impl_scalar_ops!($VectorN<Fixed64> { $($field),+ });
 
         impl_index_operators!($VectorN<S>, $n, S, usize);
         impl_index_operators!($VectorN<S>, $n, [S], Range<usize>);
@@ -483,10 +499,46 @@ impl<S: BaseFloat> InnerSpace for Vector3<S> {
 impl<S: BaseFloat> InnerSpace for Vector4<S> {
     #[inline]
     fn dot(self, other: Vector4<S>) -> S {
+        #[cfg(feature = "simd")]
+        {
+            if let Some(dot) = simd::dot4(self.as_ref(), other.as_ref()) {
+                return dot;
+            }
+        }
         Vector4::mul_element_wise(self, other).sum()
     }
 }
 
+// The floating point scalars are one-dimensional vector spaces over
+// themselves, so they can be used wherever a generic vector is expected, such
+// as in the control points of a curve.
+macro_rules! impl_scalar_space {
+    ($S:ident) => {
+        impl VectorSpace for $S {
+            type Scalar = $S;
+        }
+
+        impl MetricSpace for $S {
+            type Metric = $S;
+
+            #[inline]
+            fn distance2(self, other: $S) -> $S {
+                (other - self) * (other - self)
+            }
+        }
+
+        impl InnerSpace for $S {
+            #[inline]
+            fn dot(self, other: $S) -> $S {
+                self * other
+            }
+        }
+    }
+}
+
+impl_scalar_space!(f32);
+impl_scalar_space!(f64);
+
 impl<S: fmt::Debug> fmt::Debug for Vector1<S> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         try!(write!(f, "Vector1 "));
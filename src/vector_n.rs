@@ -0,0 +1,339 @@
+// Copyright 2016 The CGMath Developers. For a full listing of the authors,
+// refer to the Cargo.toml file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Vectors of any dimension, using const generics.
+//!
+//! This module is only compiled with the `const-generics` feature, which
+//! requires Rust 1.63 or later.
+
+use num_traits::NumCast;
+use std::array;
+use std::fmt;
+use std::ops::*;
+
+use structure::*;
+
+use approx::ApproxEq;
+use num::{BaseNum, BaseFloat, PartialOrd};
+use vector::{Vector1, Vector2, Vector3, Vector4};
+
+/// An `N`-dimensional vector.
+///
+/// This type is marked as `#[repr(C)]`.
+#[repr(C)]
+#[derive(PartialEq, Eq, Copy, Clone, Hash)]
+pub struct VectorN<S, const N: usize> {
+    /// The components of the vector.
+    pub elements: [S; N],
+}
+
+impl<S, const N: usize> VectorN<S, N> {
+    /// Construct a new vector, using the provided values.
+    #[inline]
+    pub fn new(elements: [S; N]) -> VectorN<S, N> {
+        VectorN { elements: elements }
+    }
+}
+
+impl<S: Copy, const N: usize> VectorN<S, N> {
+    /// Apply `f` to each of the components of the vector.
+    #[inline]
+    pub fn map<T, F: FnMut(S) -> T>(self, mut f: F) -> VectorN<T, N> {
+        VectorN::new(array::from_fn(|i| f(self.elements[i])))
+    }
+
+    /// Combine the components of the vector with those of `other` using `f`.
+    #[inline]
+    fn zip<F: FnMut(S, S) -> S>(self, other: VectorN<S, N>, mut f: F) -> VectorN<S, N> {
+        VectorN::new(array::from_fn(|i| f(self.elements[i], other.elements[i])))
+    }
+
+    /// Rejects a zero-dimensional vector at compile time, when it is used.
+    const NON_EMPTY: () = assert!(N > 0, "Expected a non-empty vector");
+
+    /// Combine the components with `f`, starting from the first component.
+    /// Using this on a zero-dimensional vector fails to compile, as it has no
+    /// first component.
+    #[inline]
+    fn fold<F: FnMut(S, S) -> S>(self, f: F) -> S {
+        let () = Self::NON_EMPTY;
+        self.elements[1..].iter().cloned().fold(self.elements[0], f)
+    }
+}
+
+impl<S: NumCast + Copy, const N: usize> VectorN<S, N> {
+    /// Component-wise casting to another type
+    #[inline]
+    pub fn cast<T: NumCast>(&self) -> VectorN<T, N> {
+        self.map(|x| NumCast::from(x).unwrap())
+    }
+}
+
+impl<S: BaseNum, const N: usize> VectorN<S, N> {
+    /// A unit vector along the `i`th axis.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `i` is not less than `N`.
+    #[inline]
+    pub fn unit(i: usize) -> VectorN<S, N> {
+        let mut v = VectorN::zero();
+        v[i] = S::one();
+        v
+    }
+}
+
+impl<S: BaseFloat, const N: usize> MetricSpace for VectorN<S, N> {
+    type Metric = S;
+
+    #[inline]
+    fn distance2(self, other: Self) -> S {
+        (other - self).magnitude2()
+    }
+}
+
+impl<S: Copy, const N: usize> Array for VectorN<S, N> {
+    type Element = S;
+
+    #[inline]
+    fn from_value(scalar: S) -> VectorN<S, N> {
+        VectorN::new([scalar; N])
+    }
+
+    #[inline]
+    fn len() -> usize {
+        N
+    }
+
+    #[inline]
+    fn sum(self) -> S where S: Add<Output = S> {
+        self.fold(Add::add)
+    }
+
+    #[inline]
+    fn product(self) -> S where S: Mul<Output = S> {
+        self.fold(Mul::mul)
+    }
+
+    #[inline]
+    fn min(self) -> S where S: PartialOrd {
+        self.fold(PartialOrd::partial_min)
+    }
+
+    #[inline]
+    fn max(self) -> S where S: PartialOrd {
+        self.fold(PartialOrd::partial_max)
+    }
+}
+
+impl<S: BaseNum, const N: usize> Zero for VectorN<S, N> {
+    #[inline]
+    fn zero() -> VectorN<S, N> {
+        VectorN::from_value(S::zero())
+    }
+
+    #[inline]
+    fn is_zero(&self) -> bool {
+        *self == VectorN::zero()
+    }
+}
+
+impl<S: BaseNum, const N: usize> VectorSpace for VectorN<S, N> {
+    type Scalar = S;
+}
+
+impl<S: BaseFloat, const N: usize> InnerSpace for VectorN<S, N> {
+    #[inline]
+    fn dot(self, other: VectorN<S, N>) -> S {
+        // Start from zero, so the dot product of zero-dimensional vectors is zero.
+        self.elements.iter().zip(other.elements.iter()).fold(S::zero(), |sum, (&a, &b)| sum + a * b)
+    }
+}
+
+impl<S: BaseFloat, const N: usize> ApproxEq for VectorN<S, N> {
+    type Epsilon = S::Epsilon;
+
+    #[inline]
+    fn default_epsilon() -> S::Epsilon {
+        S::default_epsilon()
+    }
+
+    #[inline]
+    fn default_max_relative() -> S::Epsilon {
+        S::default_max_relative()
+    }
+
+    #[inline]
+    fn default_max_ulps() -> u32 {
+        S::default_max_ulps()
+    }
+
+    #[inline]
+    fn relative_eq(&self, other: &Self, epsilon: S::Epsilon, max_relative: S::Epsilon) -> bool {
+        (0..N).all(|i| S::relative_eq(&self[i], &other[i], epsilon, max_relative))
+    }
+
+    #[inline]
+    fn ulps_eq(&self, other: &Self, epsilon: S::Epsilon, max_ulps: u32) -> bool {
+        (0..N).all(|i| S::ulps_eq(&self[i], &other[i], epsilon, max_ulps))
+    }
+}
+
+impl_const_generic_operator!([S: Copy + Neg<Output = S>, const N: usize] Neg for VectorN<S, N> {
+    fn neg(vector) -> VectorN<S, N> { vector.map(|x| -x) }
+});
+
+impl_const_generic_operator!([S: BaseNum, const N: usize] Add<VectorN<S, N> > for VectorN<S, N> {
+    fn add(lhs, rhs) -> VectorN<S, N> { lhs.zip(rhs, Add::add) }
+});
+impl_const_generic_operator!([S: BaseNum, const N: usize] Sub<VectorN<S, N> > for VectorN<S, N> {
+    fn sub(lhs, rhs) -> VectorN<S, N> { lhs.zip(rhs, Sub::sub) }
+});
+impl_const_generic_operator!([S: BaseNum, const N: usize] Mul<S> for VectorN<S, N> {
+    fn mul(vector, scalar) -> VectorN<S, N> { vector.map(|x| x * scalar) }
+});
+impl_const_generic_operator!([S: BaseNum, const N: usize] Div<S> for VectorN<S, N> {
+    fn div(vector, scalar) -> VectorN<S, N> { vector.map(|x| x / scalar) }
+});
+impl_const_generic_operator!([S: BaseNum, const N: usize] Rem<S> for VectorN<S, N> {
+    fn rem(vector, scalar) -> VectorN<S, N> { vector.map(|x| x % scalar) }
+});
+
+impl<S: BaseNum, const N: usize> AddAssign<VectorN<S, N>> for VectorN<S, N> {
+    #[inline]
+    fn add_assign(&mut self, other: VectorN<S, N>) { self.add_assign_element_wise(other) }
+}
+
+impl<S: BaseNum, const N: usize> SubAssign<VectorN<S, N>> for VectorN<S, N> {
+    #[inline]
+    fn sub_assign(&mut self, other: VectorN<S, N>) { self.sub_assign_element_wise(other) }
+}
+
+impl<S: BaseNum, const N: usize> MulAssign<S> for VectorN<S, N> {
+    #[inline]
+    fn mul_assign(&mut self, scalar: S) { self.mul_assign_element_wise(scalar) }
+}
+
+impl<S: BaseNum, const N: usize> DivAssign<S> for VectorN<S, N> {
+    #[inline]
+    fn div_assign(&mut self, scalar: S) { self.div_assign_element_wise(scalar) }
+}
+
+impl<S: BaseNum, const N: usize> RemAssign<S> for VectorN<S, N> {
+    #[inline]
+    fn rem_assign(&mut self, scalar: S) { self.rem_assign_element_wise(scalar) }
+}
+
+impl<S: BaseNum, const N: usize> ElementWise for VectorN<S, N> {
+    #[inline] fn add_element_wise(self, rhs: VectorN<S, N>) -> VectorN<S, N> { self.zip(rhs, Add::add) }
+    #[inline] fn sub_element_wise(self, rhs: VectorN<S, N>) -> VectorN<S, N> { self.zip(rhs, Sub::sub) }
+    #[inline] fn mul_element_wise(self, rhs: VectorN<S, N>) -> VectorN<S, N> { self.zip(rhs, Mul::mul) }
+    #[inline] fn div_element_wise(self, rhs: VectorN<S, N>) -> VectorN<S, N> { self.zip(rhs, Div::div) }
+    #[inline] fn rem_element_wise(self, rhs: VectorN<S, N>) -> VectorN<S, N> { self.zip(rhs, Rem::rem) }
+
+    #[inline] fn add_assign_element_wise(&mut self, rhs: VectorN<S, N>) { for i in 0..N { self[i] += rhs[i]; } }
+    #[inline] fn sub_assign_element_wise(&mut self, rhs: VectorN<S, N>) { for i in 0..N { self[i] -= rhs[i]; } }
+    #[inline] fn mul_assign_element_wise(&mut self, rhs: VectorN<S, N>) { for i in 0..N { self[i] *= rhs[i]; } }
+    #[inline] fn div_assign_element_wise(&mut self, rhs: VectorN<S, N>) { for i in 0..N { self[i] /= rhs[i]; } }
+    #[inline] fn rem_assign_element_wise(&mut self, rhs: VectorN<S, N>) { for i in 0..N { self[i] %= rhs[i]; } }
+}
+
+impl<S: BaseNum, const N: usize> ElementWise<S> for VectorN<S, N> {
+    #[inline] fn add_element_wise(self, rhs: S) -> VectorN<S, N> { self.map(|x| x + rhs) }
+    #[inline] fn sub_element_wise(self, rhs: S) -> VectorN<S, N> { self.map(|x| x - rhs) }
+    #[inline] fn mul_element_wise(self, rhs: S) -> VectorN<S, N> { self.map(|x| x * rhs) }
+    #[inline] fn div_element_wise(self, rhs: S) -> VectorN<S, N> { self.map(|x| x / rhs) }
+    #[inline] fn rem_element_wise(self, rhs: S) -> VectorN<S, N> { self.map(|x| x % rhs) }
+
+    #[inline] fn add_assign_element_wise(&mut self, rhs: S) { for x in &mut self.elements { *x += rhs; } }
+    #[inline] fn sub_assign_element_wise(&mut self, rhs: S) { for x in &mut self.elements { *x -= rhs; } }
+    #[inline] fn mul_assign_element_wise(&mut self, rhs: S) { for x in &mut self.elements { *x *= rhs; } }
+    #[inline] fn div_assign_element_wise(&mut self, rhs: S) { for x in &mut self.elements { *x /= rhs; } }
+    #[inline] fn rem_assign_element_wise(&mut self, rhs: S) { for x in &mut self.elements { *x %= rhs; } }
+}
+
+impl<S, I, const N: usize> Index<I> for VectorN<S, N> where [S]: Index<I> {
+    type Output = <[S] as Index<I>>::Output;
+
+    #[inline]
+    fn index(&self, i: I) -> &Self::Output {
+        let v: &[S] = &self.elements; Index::index(v, i)
+    }
+}
+
+impl<S, I, const N: usize> IndexMut<I> for VectorN<S, N> where [S]: IndexMut<I> {
+    #[inline]
+    fn index_mut(&mut self, i: I) -> &mut Self::Output {
+        let v: &mut [S] = &mut self.elements; IndexMut::index_mut(v, i)
+    }
+}
+
+impl<S, const N: usize> Into<[S; N]> for VectorN<S, N> {
+    #[inline]
+    fn into(self) -> [S; N] {
+        self.elements
+    }
+}
+
+impl<S, const N: usize> From<[S; N]> for VectorN<S, N> {
+    #[inline]
+    fn from(v: [S; N]) -> VectorN<S, N> {
+        VectorN::new(v)
+    }
+}
+
+impl<S, const N: usize> AsRef<[S; N]> for VectorN<S, N> {
+    #[inline]
+    fn as_ref(&self) -> &[S; N] {
+        &self.elements
+    }
+}
+
+impl<S, const N: usize> AsMut<[S; N]> for VectorN<S, N> {
+    #[inline]
+    fn as_mut(&mut self) -> &mut [S; N] {
+        &mut self.elements
+    }
+}
+
+impl<S: fmt::Debug, const N: usize> fmt::Debug for VectorN<S, N> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        try!(write!(f, "VectorN "));
+        <[S; N] as fmt::Debug>::fmt(&self.elements, f)
+    }
+}
+
+macro_rules! impl_named_conversions {
+    ($VectorN:ident, $n:expr) => {
+        impl<S> From<$VectorN<S>> for VectorN<S, $n> {
+            #[inline]
+            fn from(v: $VectorN<S>) -> VectorN<S, $n> {
+                VectorN::new(v.into())
+            }
+        }
+
+        impl<S: Clone> From<VectorN<S, $n>> for $VectorN<S> {
+            #[inline]
+            fn from(v: VectorN<S, $n>) -> $VectorN<S> {
+                v.elements.into()
+            }
+        }
+    }
+}
+
+impl_named_conversions!(Vector1, 1);
+impl_named_conversions!(Vector2, 2);
+impl_named_conversions!(Vector3, 3);
+impl_named_conversions!(Vector4, 4);
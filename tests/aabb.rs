@@ -0,0 +1,108 @@
+// Copyright 2016 The CGMath Developers. For a full listing of the authors,
+// refer to the Cargo.toml file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#[macro_use]
+extern crate approx;
+extern crate cgmath;
+
+use cgmath::*;
+
+#[test]
+fn test_new() {
+    let aabb = Aabb2::new(Point2::new(3, -1), Point2::new(1, 2));
+    assert_eq!(aabb.min, Point2::new(1, -1));
+    assert_eq!(aabb.max, Point2::new(3, 2));
+}
+
+#[test]
+fn test_from_points() {
+    let points = [Point3::new(1.0, 5.0, -2.0), Point3::new(-3.0, 0.5, 4.0), Point3::new(2.0, 1.0, 0.0)];
+    let aabb = Aabb3::from_points(&points).unwrap();
+    assert_eq!(aabb, Aabb3::new(Point3::new(-3.0, 0.5, -2.0), Point3::new(2.0, 5.0, 4.0)));
+    assert!(points.iter().all(|&p| aabb.contains(p)));
+
+    assert_eq!(Aabb3::<f32>::from_points(&[]), None);
+}
+
+#[test]
+fn test_center_and_extents() {
+    let aabb = Aabb3::new(Point3::new(-1.0, 0.0, 2.0), Point3::new(3.0, 1.0, 8.0));
+    assert_eq!(aabb.center(), Point3::new(1.0, 0.5, 5.0));
+    assert_eq!(aabb.size(), Vector3::new(4.0, 1.0, 6.0));
+    assert_eq!(aabb.extents(), Vector3::new(2.0, 0.5, 3.0));
+    assert_eq!(aabb.center() + aabb.extents(), aabb.max);
+}
+
+#[test]
+fn test_contains() {
+    let aabb = Aabb2::new(Point2::new(0, 0), Point2::new(4, 4));
+    assert!(aabb.contains(Point2::new(2, 2)));
+    assert!(aabb.contains(Point2::new(4, 0)));
+    assert!(!aabb.contains(Point2::new(5, 2)));
+
+    assert!(aabb.contains_aabb(&Aabb2::new(Point2::new(1, 1), Point2::new(4, 3))));
+    assert!(!aabb.contains_aabb(&Aabb2::new(Point2::new(1, 1), Point2::new(5, 3))));
+}
+
+#[test]
+fn test_union_and_intersection() {
+    let a = Aabb2::new(Point2::new(0, 0), Point2::new(4, 4));
+    let b = Aabb2::new(Point2::new(2, -1), Point2::new(6, 3));
+    let c = Aabb2::new(Point2::new(5, 5), Point2::new(6, 6));
+
+    assert_eq!(a.union(&b), Aabb2::new(Point2::new(0, -1), Point2::new(6, 4)));
+    assert_eq!(a.intersection(&b), Some(Aabb2::new(Point2::new(2, 0), Point2::new(4, 3))));
+    assert!(!a.intersects(&c));
+    assert_eq!(a.intersection(&c), None);
+    assert_eq!(b.intersection(&c), None);
+}
+
+#[test]
+fn test_grow() {
+    let aabb = Aabb3::new(Point3::new(0, 0, 0), Point3::new(1, 1, 1));
+    assert_eq!(aabb.grow(Point3::new(2, -1, 0)), Aabb3::new(Point3::new(0, -1, 0), Point3::new(2, 1, 1)));
+    assert_eq!(aabb.grow(Point3::new(1, 0, 1)), aabb);
+}
+
+#[test]
+fn test_corners() {
+    let aabb = Aabb3::new(Point3::new(0, 0, 0), Point3::new(1, 2, 3));
+    let corners = aabb.to_corners();
+    assert_eq!(Aabb3::from_points(&corners), Some(aabb));
+    for (i, a) in corners.iter().enumerate() {
+        assert!(corners[i + 1..].iter().all(|b| a != b));
+    }
+}
+
+#[test]
+fn test_transform() {
+    let aabb = Aabb3::new(Point3::new(-1.0, -1.0, -1.0), Point3::new(1.0, 1.0, 1.0));
+
+    let matrix = Matrix4::from_translation(Vector3::new(5.0, 0.0, 0.0)) * Matrix4::from_angle_z(Deg(45.0f64));
+    let half_diagonal = 2.0f64.sqrt();
+    assert_relative_eq!(aabb.transform(&matrix),
+                        Aabb3::new(Point3::new(5.0 - half_diagonal, -half_diagonal, -1.0),
+                                   Point3::new(5.0 + half_diagonal, half_diagonal, 1.0)),
+                        epsilon = 1.0e-12);
+
+    let decomposed = Decomposed {
+        scale: 2.0,
+        rot: Quaternion::from_angle_y(Deg(90.0f64)),
+        disp: Vector3::new(0.0, 1.0, 0.0),
+    };
+    assert_relative_eq!(aabb.transform(&decomposed),
+                        Aabb3::new(Point3::new(-2.0, -1.0, -2.0), Point3::new(2.0, 3.0, 2.0)),
+                        epsilon = 1.0e-12);
+}
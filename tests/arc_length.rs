@@ -0,0 +1,89 @@
+// Copyright 2016 The CGMath Developers. For a full listing of the authors,
+// refer to the Cargo.toml file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#[macro_use]
+extern crate approx;
+extern crate cgmath;
+
+use cgmath::*;
+use std::f64;
+
+/// A straight line, traversed at an uneven speed.
+const LINE: CubicBezier<Point3<f64>> = CubicBezier { p0: Point3 { x: 0.0f64, y: 0.0f64, z: 0.0f64 },
+                                                     p1: Point3 { x: 0.5f64, y: 0.0f64, z: 0.0f64 },
+                                                     p2: Point3 { x: 0.6f64, y: 0.0f64, z: 0.0f64 },
+                                                     p3: Point3 { x: 4.0f64, y: 0.0f64, z: 0.0f64 } };
+
+/// An approximation of a quarter of the unit circle.
+const ARC: CubicBezier<Vector2<f64>> = CubicBezier { p0: Vector2 { x: 1.0f64, y: 0.0f64 },
+                                                    p1: Vector2 { x: 1.0f64, y: K },
+                                                    p2: Vector2 { x: K, y: 1.0f64 },
+                                                    p3: Vector2 { x: 0.0f64, y: 1.0f64 } };
+const K: f64 = 0.5519150244935105707435627;
+
+#[test]
+fn test_length() {
+    assert_relative_eq!(LINE.length(), 4.0, epsilon = 1.0e-9);
+    assert_relative_eq!(ARC.length(), f64::consts::FRAC_PI_2, epsilon = 1.0e-3);
+    assert_relative_eq!(QuadraticBezier::new(Vector1::new(1.0), Vector1::new(3.0), Vector1::new(2.0)).length(), 5.0 / 3.0, epsilon = 1.0e-3);
+}
+
+#[test]
+fn test_t_at_distance() {
+    let table = LINE.arc_length_table(64);
+    assert_eq!(table.intervals(), 64);
+    assert_eq!(table.t_at_distance(-1.0), 0.0);
+    assert_eq!(table.t_at_distance(5.0), 1.0);
+    for i in 0..9 {
+        let distance = i as f64 * 0.5;
+        let t = table.t_at_distance(distance);
+        assert_relative_eq!(LINE.eval(t).x, distance, epsilon = 1.0e-3);
+        assert_relative_eq!(table.distance_at(t), distance, epsilon = 1.0e-9);
+    }
+}
+
+#[test]
+fn test_sample_evenly() {
+    let points = LINE.sample_evenly(9);
+    assert_eq!(points.len(), 9);
+    assert_eq!(points[0], LINE.p0);
+    assert_relative_eq!(points[8], LINE.p3, epsilon = 1.0e-12);
+    for (i, point) in points.iter().enumerate() {
+        assert_relative_eq!(point.x, i as f64 * 0.5, epsilon = 1.0e-3);
+    }
+    assert_eq!(LINE.sample_evenly(0).len(), 0);
+}
+
+#[test]
+fn test_flatten() {
+    let curve = ARC;
+    let tolerance = 1.0e-3;
+    let points = curve.flatten(tolerance);
+    assert_eq!(points[0], curve.p0);
+    assert_eq!(*points.last().unwrap(), curve.p3);
+
+    // Each polyline vertex lies on the unit circle, and each segment is no
+    // further from it than the tolerance.
+    for pair in points.windows(2) {
+        let middle = (pair[0] + pair[1]) / 2.0;
+        assert!(1.0 - middle.magnitude() <= tolerance * 1.1);
+    }
+    assert!(points.len() < curve.flatten(tolerance / 100.0).len());
+
+    // A straight line needs no subdivision.
+    let straight = CubicBezier::new(Vector2::new(0.0, 0.0), Vector2::new(1.0, 1.0),
+                                    Vector2::new(2.0, 2.0), Vector2::new(3.0, 3.0));
+    assert_eq!(straight.flatten(1.0e-6).len(), 2);
+}
@@ -0,0 +1,165 @@
+// Copyright 2016 The CGMath Developers. For a full listing of the authors,
+// refer to the Cargo.toml file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#![cfg(feature = "const-generics")]
+
+#[macro_use]
+extern crate approx;
+extern crate cgmath;
+
+use cgmath::*;
+
+/// A well-conditioned 6x6 matrix with no zero elements.
+fn matrix6() -> MatrixRC<f64, 6, 6> {
+    let mut m = MatrixRC::identity() * 8.0;
+    for j in 0..6 {
+        for i in 0..6 {
+            m[j][i] += ((i * 7 + j * 3) % 5) as f64 - 2.5;
+        }
+    }
+    m
+}
+
+mod vector {
+    use cgmath::*;
+
+    #[test]
+    fn test_operators() {
+        let a = VectorN::new([1.0f64, 2.0, 3.0, 4.0, 5.0, 6.0]);
+        let b = VectorN::new([6.0f64, 5.0, 4.0, 3.0, 2.0, 1.0]);
+        assert_eq!(a + b, VectorN::from_value(7.0));
+        assert_eq!(a - b, VectorN::new([-5.0, -3.0, -1.0, 1.0, 3.0, 5.0]));
+        assert_eq!(a * 2.0, VectorN::new([2.0, 4.0, 6.0, 8.0, 10.0, 12.0]));
+        assert_eq!(-a, a * -1.0);
+        assert_eq!(&a + b, a + b);
+
+        let mut c = a;
+        c += b;
+        c /= 7.0;
+        assert_eq!(c, VectorN::from_value(1.0));
+    }
+
+    #[test]
+    fn test_array() {
+        let a = VectorN::new([3, -1, 4, 1, -5, 9, 2, 6, 5]);
+        assert_eq!(a.sum(), 24);
+        assert_eq!(a.min(), -5);
+        assert_eq!(a.max(), 9);
+        assert_eq!(a[2], 4);
+        assert_eq!(&a[1..3], &[-1, 4]);
+        assert_eq!(VectorN::<i32, 12>::unit(11)[11], 1);
+    }
+
+    #[test]
+    fn test_inner_space() {
+        let a = VectorN::new([1.0f64, 2.0, 2.0, 0.0, 4.0, 0.0]);
+        assert_eq!(a.dot(VectorN::unit(4)), 4.0);
+        assert_eq!(a.magnitude(), 5.0);
+        assert_relative_eq!(a.normalize().magnitude(), 1.0);
+        assert_eq!(a.distance(VectorN::zero()), 5.0);
+
+        let empty = VectorN::<f64, 0>::new([]);
+        assert_eq!(empty.dot(empty), 0.0);
+        assert_eq!(empty.magnitude(), 0.0);
+    }
+
+    #[test]
+    fn test_conversions() {
+        let v = vec4(1, 2, 3, 4);
+        let n: VectorN<i32, 4> = v.into();
+        assert_eq!(n, VectorN::new([1, 2, 3, 4]));
+        assert_eq!(Vector4::from(n), v);
+        assert_eq!(Vector2::from(VectorN::from(vec2(5, 6))), vec2(5, 6));
+    }
+}
+
+mod matrix {
+    use cgmath::*;
+
+    #[test]
+    fn test_named_conversions() {
+        let m = Matrix3::new(1.0f64, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 10.0);
+        let n = MatrixRC::from(m);
+        assert_eq!(n[1][2], m[1][2]);
+        assert_eq!(Matrix3::from(n), m);
+
+        let r = Matrix2x3::new(1.0f64, 2.0, 3.0, 4.0, 5.0, 6.0);
+        assert_eq!(Matrix2x3::from(MatrixRC::<f64, 2, 3>::from(r)), r);
+    }
+
+    #[test]
+    fn test_matches_named_matrices() {
+        let a = Matrix4::new(2.0f64, -1.0, 0.5, 0.25, 0.0, 3.0, 1.0, -2.0,
+                             1.5, 0.0, -1.0, 4.0, -3.0, 2.0, 1.0, 1.0);
+        let b = Matrix4::from_angle_x(Rad(0.3)) * Matrix4::from_translation(vec3(1.0, 2.0, 3.0));
+        let v = vec4(0.5, -2.0, 3.0, 1.0);
+        let (an, bn) = (MatrixRC::from(a), MatrixRC::from(b));
+
+        assert_relative_eq!(Matrix4::from(an * bn), a * b, epsilon = 1.0e-12);
+        assert_relative_eq!(Vector4::from(an * VectorN::from(v)), a * v, epsilon = 1.0e-12);
+        assert_relative_eq!(Matrix4::from(an.transpose()), a.transpose());
+        assert_relative_eq!(an.determinant(), a.determinant(), epsilon = 1.0e-12);
+        assert_relative_eq!(Matrix4::from(an.invert().unwrap()), a.invert().unwrap(), epsilon = 1.0e-12);
+        assert_eq!(an.row(1), VectorN::from(a.row(1)));
+    }
+
+    #[test]
+    fn test_rectangular_mul() {
+        let a = MatrixRC::from([[1.0f64, 2.0], [3.0, 4.0], [5.0, 6.0]]);
+        let b = a.transpose();
+        let ab: MatrixRC<f64, 2, 2> = a * b;
+        let ba: MatrixRC<f64, 3, 3> = b * a;
+        assert_eq!(ab, MatrixRC::from([[35.0, 44.0], [44.0, 56.0]]));
+        assert!(ba.is_symmetric());
+        assert_eq!(ba.determinant(), 0.0);
+        assert!(ba.invert().is_none());
+    }
+
+    #[test]
+    fn test_invert() {
+        let m = super::matrix6();
+        let inverse = m.invert().unwrap();
+        assert_relative_eq!(m * inverse, MatrixRC::identity(), epsilon = 1.0e-12);
+        assert_relative_eq!(inverse * m, MatrixRC::identity(), epsilon = 1.0e-12);
+        assert_relative_eq!(m.determinant() * inverse.determinant(), 1.0, epsilon = 1.0e-12);
+    }
+
+    #[test]
+    fn test_diagonal() {
+        let d = MatrixRC::<f64, 9, 9>::from_diagonal(VectorN::from_value(2.0));
+        assert!(d.is_diagonal());
+        assert_eq!(d.trace(), 18.0);
+        assert_eq!(d.determinant(), 512.0);
+        assert!(!super::matrix6().is_diagonal());
+    }
+
+    #[test]
+    fn test_transpose_self() {
+        let mut m = super::matrix6();
+        m.transpose_self();
+        assert_eq!(m, super::matrix6().transpose());
+    }
+
+    #[test]
+    fn test_covariance_update() {
+        // The prediction step of a Kalman filter over a 6-dimensional state,
+        // which keeps the covariance symmetric.
+        let f = super::matrix6();
+        let p = MatrixRC::<f64, 6, 6>::from_value(0.5);
+        let q = MatrixRC::from_diagonal(VectorN::from_value(0.01));
+        let predicted = f * p * f.transpose() + q;
+        assert!(predicted.is_symmetric());
+    }
+}
@@ -0,0 +1,174 @@
+// Copyright 2016 The CGMath Developers. For a full listing of the authors,
+// refer to the Cargo.toml file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#[macro_use]
+extern crate approx;
+extern crate cgmath;
+
+use cgmath::*;
+
+const CUBIC: CubicBezier<Point2<f64>> = CubicBezier { p0: Point2 { x: 0.0f64, y: 0.0f64 },
+                                                      p1: Point2 { x: 1.0f64, y: 2.0f64 },
+                                                      p2: Point2 { x: 3.0f64, y: -1.0f64 },
+                                                      p3: Point2 { x: 4.0f64, y: 1.0f64 } };
+const SCALAR: CubicBezier<f64> = CubicBezier { p0: 0.0f64, p1: 3.0f64, p2: -2.0f64, p3: 1.0f64 };
+
+#[test]
+fn test_cubic_bezier() {
+    let curve = CUBIC;
+    assert_relative_eq!(curve.eval(0.0), curve.p0);
+    assert_relative_eq!(curve.eval(1.0), curve.p3);
+    assert_relative_eq!(curve.eval(0.5), Point2::new(2.0, 0.5), epsilon = 1.0e-12);
+    assert_relative_eq!(curve.derivative(0.0), Vector2::new(3.0, 6.0), epsilon = 1.0e-12);
+    assert_relative_eq!(curve.derivative(1.0), Vector2::new(3.0, 6.0), epsilon = 1.0e-12);
+
+    // Compare the derivatives against finite differences.
+    let h = 1.0e-6;
+    let t = 0.3;
+    let finite = (curve.eval(t + h) - curve.eval(t - h)) / (2.0 * h);
+    assert_relative_eq!(curve.derivative(t), finite, epsilon = 1.0e-6);
+    let finite = (curve.derivative(t + h) - curve.derivative(t - h)) / (2.0 * h);
+    assert_relative_eq!(curve.second_derivative(t), finite, epsilon = 1.0e-6);
+}
+
+#[test]
+fn test_split() {
+    let curve = CUBIC;
+    let (before, after) = curve.split(0.25);
+    assert_relative_eq!(before.eval(1.0), curve.eval(0.25), epsilon = 1.0e-12);
+    assert_relative_eq!(before.eval(0.5), curve.eval(0.125), epsilon = 1.0e-12);
+    assert_relative_eq!(after.eval(0.5), curve.eval(0.625), epsilon = 1.0e-12);
+
+    let quadratic = QuadraticBezier::new(Vector3::new(0.0, 0.0, 0.0),
+                                         Vector3::new(1.0, 1.0, 0.0),
+                                         Vector3::new(2.0, 0.0, 1.0));
+    let (before, after) = quadratic.split(0.5);
+    assert_relative_eq!(before.eval(0.5), quadratic.eval(0.25), epsilon = 1.0e-12);
+    assert_relative_eq!(after.eval(0.5), quadratic.eval(0.75), epsilon = 1.0e-12);
+}
+
+#[test]
+fn test_bounding_box() {
+    let (min, max) = CUBIC.bounding_box();
+    let mut expected_min = CUBIC.eval(0.0);
+    let mut expected_max = expected_min;
+    for i in 0..1001 {
+        let p = CUBIC.eval(i as f64 / 1000.0);
+        for axis in 0..2 {
+            expected_min[axis] = expected_min[axis].min(p[axis]);
+            expected_max[axis] = expected_max[axis].max(p[axis]);
+        }
+    }
+    assert_relative_eq!(min, expected_min, epsilon = 1.0e-5);
+    assert_relative_eq!(max, expected_max, epsilon = 1.0e-5);
+
+    let quadratic = QuadraticBezier::new(Point1::new(0.0), Point1::new(2.0), Point1::new(0.0));
+    assert_eq!(quadratic.bounding_box(), (Point1::new(0.0), Point1::new(1.0)));
+
+    let (min, max) = CUBIC.bounding_box();
+    let aabb = Aabb2::new(min, max);
+    assert!(aabb.contains(CUBIC.eval(0.6)));
+}
+
+#[test]
+fn test_scalar() {
+    let curve = SCALAR;
+    assert_relative_eq!(curve.eval(0.5), 0.5, epsilon = 1.0e-12);
+    assert_relative_eq!(curve.derivative(0.0), 9.0, epsilon = 1.0e-12);
+    assert_relative_eq!(curve.second_derivative(0.0), -48.0, epsilon = 1.0e-12);
+
+    let (before, after) = curve.split(0.5);
+    assert_relative_eq!(before.eval(0.5), curve.eval(0.25), epsilon = 1.0e-12);
+    assert_relative_eq!(after.eval(0.5), curve.eval(0.75), epsilon = 1.0e-12);
+
+    let (min, max) = curve.bounding_box();
+    let samples: Vec<f64> = (0..1001).map(|i| curve.eval(i as f64 / 1000.0)).collect();
+    assert_relative_eq!(min, samples.iter().cloned().fold(0.0, f64::min), epsilon = 1.0e-5);
+    assert_relative_eq!(max, samples.iter().cloned().fold(0.0, f64::max), epsilon = 1.0e-5);
+
+    let line = CubicBezier::new(0.0f64, 1.0, 2.0, 3.0);
+    assert_eq!(line.bounding_box(), (0.0, 3.0));
+    assert_relative_eq!(line.length(), 3.0, epsilon = 1.0e-6);
+}
+
+#[test]
+fn test_vector_space() {
+    // Curves work in any vector space, even without a magnitude.
+    let a = Matrix2::new(1.0, 0.0, 0.0, 1.0);
+    let b = Matrix2::new(0.0, 2.0, -2.0, 0.0);
+    let curve = QuadraticBezier::new(a, b, a * 3.0);
+    assert_relative_eq!(curve.eval(0.5), a + b / 2.0, epsilon = 1.0e-12);
+    assert_relative_eq!(curve.derivative(0.0), (b - a) * 2.0, epsilon = 1.0e-12);
+
+    let q = UniformBSpline::new(Quaternion::new(1.0, 0.0, 0.0, 0.0), Quaternion::new(0.0, 1.0, 0.0, 0.0),
+                                Quaternion::new(0.0, 0.0, 1.0, 0.0), Quaternion::new(0.0, 0.0, 0.0, 1.0));
+    assert_relative_eq!(q.eval(0.0), Quaternion::new(1.0, 4.0, 1.0, 0.0) / 6.0, epsilon = 1.0e-12);
+}
+
+#[test]
+fn test_hermite() {
+    let curve = CubicHermite::new(Point3::new(0.0, 0.0, 0.0), Vector3::new(3.0, 0.0, 0.0),
+                                  Point3::new(1.0, 1.0, 0.0), Vector3::new(0.0, 3.0, 0.0));
+    assert_relative_eq!(curve.eval(1.0), curve.p1, epsilon = 1.0e-12);
+    assert_relative_eq!(curve.derivative(0.0), curve.m0, epsilon = 1.0e-12);
+    assert_relative_eq!(curve.derivative(1.0), curve.m1, epsilon = 1.0e-12);
+
+    let (before, _) = curve.split(0.5);
+    assert_relative_eq!(before.eval(0.5), curve.eval(0.25), epsilon = 1.0e-12);
+    assert_eq!(CubicHermite::from(CubicBezier::from(curve)), curve);
+}
+
+#[test]
+fn test_catmull_rom() {
+    let (p0, p1, p2, p3) = (Vector2::new(0.0, 0.0), Vector2::new(1.0, 1.0),
+                            Vector2::new(2.0, 1.0), Vector2::new(3.0, 0.0));
+    let uniform = CatmullRom::new(p0, p1, p2, p3, Parameterization::Uniform);
+    assert_relative_eq!(uniform.eval(0.0), p1, epsilon = 1.0e-12);
+    assert_relative_eq!(uniform.eval(1.0), p2, epsilon = 1.0e-12);
+    assert_relative_eq!(uniform.derivative(0.0), (p2 - p0) / 2.0, epsilon = 1.0e-12);
+
+    for &parameterization in &[Parameterization::Centripetal, Parameterization::Chordal] {
+        let curve = CatmullRom::new(p0, p1, p2, p3, parameterization);
+        assert_relative_eq!(curve.eval(0.0), p1, epsilon = 1.0e-12);
+        assert_relative_eq!(curve.eval(1.0), p2, epsilon = 1.0e-12);
+        // The points are symmetric about x = 1.5.
+        assert_relative_eq!(curve.eval(0.5).x, 1.5, epsilon = 1.0e-12);
+    }
+
+    // Evenly spaced points on a line give a straight, evenly parameterized curve.
+    let line = CatmullRom::new(Point1::new(0.0), Point1::new(1.0), Point1::new(2.0), Point1::new(3.0),
+                               Parameterization::Chordal);
+    assert_relative_eq!(line.eval(0.25), Point1::new(1.25), epsilon = 1.0e-12);
+    let coincident = CatmullRom::new(Point1::new(1.0f64), Point1::new(1.0), Point1::new(2.0), Point1::new(2.0),
+                                     Parameterization::Centripetal);
+    assert!(coincident.eval(0.5).x.is_finite());
+}
+
+#[test]
+fn test_uniform_b_spline() {
+    let curve = UniformBSpline::new(Point2::new(0.0, 0.0), Point2::new(1.0, 2.0),
+                                    Point2::new(3.0, 2.0), Point2::new(4.0, 0.0));
+    assert_relative_eq!(curve.eval(0.0), Point2::new(7.0 / 6.0, 5.0 / 3.0), epsilon = 1.0e-12);
+    assert_relative_eq!(curve.derivative(0.0), Vector2::new(1.5, 1.0), epsilon = 1.0e-12);
+
+    // Consecutive segments join with matching second derivatives.
+    let next = UniformBSpline::new(curve.p1, curve.p2, curve.p3, Point2::new(5.0, 3.0));
+    assert_relative_eq!(curve.eval(1.0), next.eval(0.0), epsilon = 1.0e-12);
+    assert_relative_eq!(curve.derivative(1.0), next.derivative(0.0), epsilon = 1.0e-12);
+    assert_relative_eq!(curve.second_derivative(1.0), next.second_derivative(0.0), epsilon = 1.0e-12);
+
+    let (before, after) = curve.split(0.5);
+    assert_relative_eq!(before.eval(1.0), after.eval(0.0), epsilon = 1.0e-12);
+}
@@ -0,0 +1,129 @@
+// Copyright 2016 The CGMath Developers. For a full listing of the authors,
+// refer to the Cargo.toml file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#[macro_use]
+extern crate approx;
+extern crate cgmath;
+
+use cgmath::*;
+
+/// A rotation of 0.7 radians about `(1, 2, 3)`.
+const ROTATION: Quaternion<f64> = Quaternion { s: 0.9393727128473789f64,
+                                               v: Vector3 { x: 0.0916432938695913f64,
+                                                            y: 0.1832865877391826f64,
+                                                            z: 0.2749298816087739f64 } };
+
+#[test]
+fn test_decompose_affine() {
+    let m = Matrix4::from_translation(Vector3::new(1.0, -2.0, 3.0)) *
+            Matrix4::from(ROTATION) *
+            Matrix4::from_nonuniform_scale(2.0, 3.0, 0.5);
+    let parts = m.decompose().unwrap();
+
+    assert_relative_eq!(parts.translation, Vector3::new(1.0, -2.0, 3.0), epsilon = 1.0e-12);
+    assert_relative_eq!(parts.scale, Vector3::new(2.0, 3.0, 0.5), epsilon = 1.0e-12);
+    assert_relative_eq!(parts.shear, Vector3::zero(), epsilon = 1.0e-12);
+    assert_relative_eq!(parts.perspective, Vector4::unit_w(), epsilon = 1.0e-12);
+    assert_relative_eq!(Matrix4::from(parts), m, epsilon = 1.0e-12);
+
+    let v = Vector3::new(0.3, -1.2, 2.0);
+    let nonuniform = NonUniformDecomposed::from(parts);
+    assert_relative_eq!(nonuniform.transform_vector(v), m.transform_vector(v), epsilon = 1.0e-12);
+    assert!(parts.to_decomposed().is_none());
+}
+
+#[test]
+fn test_decompose_mirror() {
+    let m = Matrix4::from_translation(Vector3::new(1.0, 0.0, 0.0)) *
+            Matrix4::from(ROTATION) *
+            Matrix4::from_scale(-2.0);
+    let parts = m.decompose().unwrap();
+    assert_relative_eq!(parts.scale, Vector3::new(-2.0, 2.0, 2.0), epsilon = 1.0e-12);
+    assert_relative_eq!(Matrix4::from(parts), m, epsilon = 1.0e-12);
+
+    let decomposed = parts.to_decomposed().unwrap();
+    assert_relative_eq!(decomposed.scale, -2.0, epsilon = 1.0e-12);
+    assert_relative_eq!(Matrix4::from(decomposed), m, epsilon = 1.0e-12);
+
+    let linear = Matrix3::from(ROTATION) * Matrix3::from_diagonal(Vector3::new(1.0, -1.0, 1.0));
+    let parts = linear.decompose().unwrap();
+    assert!(parts.scale.x < 0.0);
+    assert_relative_eq!(Matrix4::from(parts), Matrix4::from(linear), epsilon = 1.0e-12);
+}
+
+#[test]
+fn test_decompose_shear_perspective() {
+    let shear = Matrix4::new(1.0, 0.0, 0.0, 0.0,
+                             0.5, 1.0, 0.0, 0.0,
+                             0.0, -0.25, 1.0, 0.0,
+                             0.0, 0.0, 0.0, 1.0);
+    let m = Matrix4::from_translation(Vector3::new(0.0, 4.0, 0.0)) * Matrix4::from(ROTATION) * shear;
+    let parts = m.decompose().unwrap();
+    assert_relative_eq!(parts.shear, Vector3::new(0.5, 0.0, -0.25), epsilon = 1.0e-12);
+    assert_relative_eq!(Matrix4::from(parts), m, epsilon = 1.0e-12);
+
+    let projection = Matrix4::from(PerspectiveFov { fovy: Rad(1.0), aspect: 1.5, near: 0.1, far: 100.0 });
+    let view = Matrix4::look_at(Point3::new(1.0, 2.0, 3.0), Point3::new(0.0, 0.0, 0.0), Vector3::unit_y());
+    let m = projection * view;
+    let parts = m.decompose().unwrap();
+    assert_relative_eq!(Matrix4::from(parts) * m[3][3], m, epsilon = 1.0e-9);
+}
+
+#[test]
+fn test_decompose_singular() {
+    assert_eq!(Matrix4::from_nonuniform_scale(1.0, 0.0, 1.0).decompose(), None);
+    assert_eq!(Matrix3::<f64>::zero().decompose(), None);
+    assert_eq!(Matrix4::<f64>::zero().decompose(), None);
+}
+
+#[test]
+fn test_gram_schmidt() {
+    let m = Matrix3::new(2.0, 0.0, 0.0,
+                         1.0, 3.0, 0.0,
+                         1.0, 1.0, 0.5);
+    let q = m.gram_schmidt().unwrap();
+    assert_relative_eq!(q * q.transpose(), Matrix3::identity(), epsilon = 1.0e-12);
+    assert_relative_eq!(q.x, Vector3::unit_x(), epsilon = 1.0e-12);
+    assert_relative_eq!(q.y, Vector3::unit_y(), epsilon = 1.0e-12);
+
+    let m = Matrix2::new(0.0, 2.0, 1.0, 1.0);
+    assert_relative_eq!(m.gram_schmidt().unwrap(), Matrix2::new(0.0, 1.0, 1.0, 0.0), epsilon = 1.0e-12);
+
+    let degenerate = Matrix3::from_cols(Vector3::unit_x(), Vector3::unit_x() * 2.0, Vector3::unit_z());
+    assert_eq!(degenerate.gram_schmidt(), None);
+}
+
+#[test]
+fn test_polar_decompose() {
+    let stretch = Matrix3::new(2.0, 0.5, 0.0,
+                               0.5, 1.0, 0.25,
+                               0.0, 0.25, 3.0);
+    let m = Matrix3::from(ROTATION) * stretch;
+    let (q, p) = m.polar_decompose().unwrap();
+    assert_relative_eq!(q, Matrix3::from(ROTATION), epsilon = 1.0e-12);
+    assert_relative_eq!(p, stretch, epsilon = 1.0e-12);
+
+    let mirror = m * Matrix3::from_diagonal(Vector3::new(1.0, 1.0, -1.0));
+    let (q, p) = mirror.polar_decompose().unwrap();
+    assert_relative_eq!(q.determinant(), -1.0, epsilon = 1.0e-12);
+    assert_relative_eq!(q * p, mirror, epsilon = 1.0e-12);
+    assert_eq!(Matrix3::<f64>::zero().polar_decompose(), None);
+
+    let stretch = Matrix2::new(2.0, 0.5, 0.5, 1.0);
+    let m = Matrix2::from_angle(Rad(0.3)) * stretch;
+    let (q, p) = m.polar_decompose().unwrap();
+    assert_relative_eq!(q, Matrix2::from_angle(Rad(0.3)), epsilon = 1.0e-12);
+    assert_relative_eq!(p, stretch, epsilon = 1.0e-12);
+}
@@ -0,0 +1,138 @@
+// Copyright 2016 The CGMath Developers. For a full listing of the authors,
+// refer to the Cargo.toml file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#[macro_use]
+extern crate approx;
+extern crate cgmath;
+
+use cgmath::*;
+
+/// A rotation of 70 degrees about `(1, 2, 3)`, followed by a translation.
+const DECOMPOSED: Decomposed<Vector3<f64>, Quaternion<f64>> = Decomposed {
+    scale: 1.0f64,
+    rot: Quaternion { s: 0.8191520442889918f64,
+                      v: Vector3 { x: 0.15329475071088322f64, y: 0.30658950142176644f64, z: 0.45988425213264966f64 } },
+    disp: Vector3 { x: 6.0f64, y: -7.0f64, z: 8.0f64 },
+};
+
+#[test]
+fn test_transform_point() {
+    let dec = DECOMPOSED;
+    let dq = DualQuaternion::from(dec);
+    let point = Point3::new(1.0, -2.0, 0.5);
+
+    assert_relative_eq!(dq.transform_point(point), dec.transform_point(point), epsilon = 1.0e-12);
+    assert_relative_eq!(dq.transform_vector(point.to_vec()), dec.transform_vector(point.to_vec()), epsilon = 1.0e-12);
+}
+
+#[test]
+fn test_decomposed_round_trip() {
+    let dec = DECOMPOSED;
+    let dq = DualQuaternion::from(dec);
+
+    assert_relative_eq!(dq.rotation(), dec.rot);
+    assert_relative_eq!(dq.translation(), dec.disp, epsilon = 1.0e-12);
+    assert_relative_eq!(Decomposed::from(dq), dec, epsilon = 1.0e-12);
+}
+
+#[test]
+fn test_matrix4_round_trip() {
+    let dec = DECOMPOSED;
+    let dq = DualQuaternion::from(dec);
+    let mat = Matrix4::from(dq);
+
+    assert_relative_eq!(mat, Matrix4::from(dec), epsilon = 1.0e-12);
+    assert_relative_eq!(DualQuaternion::from(mat), dq, epsilon = 1.0e-12);
+}
+
+#[test]
+fn test_concat() {
+    let a = DECOMPOSED;
+    let b = Decomposed {
+        scale: 1.0,
+        rot: Quaternion::from_angle_y(Deg(-30.0)),
+        disp: Vector3::new(-1.0, 0.5, 2.0),
+    };
+    let concat = DualQuaternion::from(a).concat(&DualQuaternion::from(b));
+
+    assert_relative_eq!(concat, DualQuaternion::from(a.concat(&b)), epsilon = 1.0e-12);
+}
+
+#[test]
+fn test_inverse_transform() {
+    let dq = DualQuaternion::from(DECOMPOSED);
+    let inverse = dq.inverse_transform().expect("Expected successful inversion");
+    let point = Point3::new(1.0, -2.0, 0.5);
+
+    assert_relative_eq!(inverse.transform_point(dq.transform_point(point)), point, epsilon = 1.0e-12);
+    assert_relative_eq!(dq * inverse, DualQuaternion::one(), epsilon = 1.0e-12);
+}
+
+#[test]
+fn test_normalize() {
+    let dq = DualQuaternion::from(DECOMPOSED);
+    let scaled = DualQuaternion::new(dq.real * 3.0, dq.dual * 3.0 + dq.real * 0.5);
+
+    assert_relative_eq!(scaled.normalize(), dq, epsilon = 1.0e-12);
+}
+
+#[test]
+fn test_sclerp() {
+    // A quarter turn around the z axis, combined with a translation along it,
+    // is a screw motion. Halfway along it is half the turn and translation.
+    let start = DualQuaternion::one();
+    let end = DualQuaternion::from_rotation_translation(Quaternion::from_angle_z(Deg(90.0)), Vector3::new(0.0, 0.0, 4.0));
+    let half = DualQuaternion::from_rotation_translation(Quaternion::from_angle_z(Deg(45.0)), Vector3::new(0.0, 0.0, 2.0));
+
+    assert_relative_eq!(start.sclerp(end, 0.0), start, epsilon = 1.0e-12);
+    assert_relative_eq!(start.sclerp(end, 0.5), half, epsilon = 1.0e-12);
+    assert_relative_eq!(start.sclerp(end, 1.0), end, epsilon = 1.0e-12);
+}
+
+#[test]
+fn test_sclerp_offset_axis() {
+    // Rotating around an axis through (1, 0, 0) keeps that point fixed all the
+    // way along the interpolation.
+    let pivot = Vector3::new(1.0, 0.0, 0.0);
+    let end = DualQuaternion::from_translation(pivot) *
+              DualQuaternion::from_rotation(Quaternion::from_angle_z(Deg(120.0))) *
+              DualQuaternion::from_translation(-pivot);
+
+    let dq = DualQuaternion::one().sclerp(end, 0.3);
+    assert_relative_eq!(dq.rotation(), Quaternion::from_angle_z(Deg(36.0)), epsilon = 1.0e-12);
+    assert_relative_eq!(dq.transform_point(Point3::from_vec(pivot)), Point3::from_vec(pivot), epsilon = 1.0e-12);
+}
+
+#[test]
+fn test_sclerp_pure_translation() {
+    let start = DualQuaternion::from_translation(Vector3::new(1.0, 2.0, 3.0));
+    let end = DualQuaternion::from_translation(Vector3::new(3.0, 2.0, 1.0));
+
+    assert_relative_eq!(start.sclerp(end, 0.25).translation(), Vector3::new(1.5, 2.0, 2.5), epsilon = 1.0e-12);
+}
+
+#[test]
+fn test_blend() {
+    let a = DualQuaternion::from_rotation_translation(Quaternion::from_angle_x(Deg(20.0)), Vector3::new(1.0, 0.0, 0.0));
+    let b = DualQuaternion::from_rotation_translation(Quaternion::from_angle_x(Deg(60.0)), Vector3::new(1.0, 0.0, 0.0));
+
+    let blended = DualQuaternion::blend(&[a, -b], &[0.5, 0.5]);
+    assert_relative_eq!(blended.rotation(), Quaternion::from_angle_x(Deg(40.0)), epsilon = 1.0e-12);
+    assert_relative_eq!(blended.translation(), Vector3::new(1.0, 0.0, 0.0), epsilon = 1.0e-12);
+
+    assert_eq!(DualQuaternion::blend(&[], &[]), DualQuaternion::<f64>::one());
+    assert_eq!(DualQuaternion::blend(&[a, b], &[0.0, 0.0]), DualQuaternion::one());
+    assert_eq!(DualQuaternion::blend(&[a, b], &[]), DualQuaternion::one());
+}
@@ -0,0 +1,74 @@
+// Copyright 2016 The CGMath Developers. For a full listing of the authors,
+// refer to the Cargo.toml file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#[macro_use]
+extern crate approx;
+extern crate cgmath;
+
+use cgmath::*;
+
+#[test]
+fn test_symmetric_eigen2() {
+    let rot = Matrix2::from_angle(Rad(0.4));
+    let m = rot * Matrix2::from_diagonal(Vector2::new(-1.0, 3.0)) * rot.transpose();
+    let (values, vectors) = m.symmetric_eigen();
+    assert_relative_eq!(values, Vector2::new(3.0, -1.0), epsilon = 1.0e-12);
+
+    let vectors: Matrix2<f64> = vectors.into();
+    assert_relative_eq!(vectors * Matrix2::from_diagonal(values) * vectors.transpose(), m, epsilon = 1.0e-12);
+    assert_relative_eq!(m * vectors.x, vectors.x * values.x, epsilon = 1.0e-12);
+
+    let (values, _) = Matrix2::<f64>::identity().symmetric_eigen();
+    assert_relative_eq!(values, Vector2::new(1.0, 1.0));
+}
+
+#[test]
+fn test_symmetric_eigen3() {
+    let rot = Matrix3::from_axis_angle(Vector3::new(1.0, -2.0, 0.5).normalize(), Rad(1.1));
+    let m = rot * Matrix3::from_diagonal(Vector3::new(2.0, -5.0, 7.0)) * rot.transpose();
+    let (values, vectors) = m.symmetric_eigen();
+    assert_relative_eq!(values, Vector3::new(7.0, 2.0, -5.0), epsilon = 1.0e-12);
+
+    let vectors: Matrix3<f64> = vectors.into();
+    assert_relative_eq!(vectors.determinant(), 1.0, epsilon = 1.0e-12);
+    assert_relative_eq!(vectors * Matrix3::from_diagonal(values) * vectors.transpose(), m, epsilon = 1.0e-12);
+    for i in 0..3 {
+        assert_relative_eq!(m * vectors[i], vectors[i] * values[i], epsilon = 1.0e-12);
+    }
+}
+
+#[test]
+fn test_symmetric_eigen3_repeated() {
+    let m = Matrix3::new(2.0, 1.0, 1.0,
+                         1.0, 2.0, 1.0,
+                         1.0, 1.0, 2.0);
+    let (values, vectors) = m.symmetric_eigen();
+    assert_relative_eq!(values, Vector3::new(4.0, 1.0, 1.0), epsilon = 1.0e-12);
+    let vectors: Matrix3<f64> = vectors.into();
+    assert_relative_eq!(vectors * Matrix3::from_diagonal(values) * vectors.transpose(), m, epsilon = 1.0e-12);
+
+    let (values, vectors) = Matrix3::<f64>::from_diagonal(Vector3::new(1.0, 3.0, 2.0)).symmetric_eigen();
+    assert_relative_eq!(values, Vector3::new(3.0, 2.0, 1.0));
+    assert_relative_eq!(vectors.rotate_vector(Vector3::unit_x()).y.abs(), 1.0);
+}
+
+#[test]
+fn test_symmetric_eigen3_not_finite() {
+    // Invalid input gives invalid values rather than panicking.
+    let (values, _) = Matrix3::from_value(::std::f64::NAN).symmetric_eigen();
+    assert!(values.x.is_nan());
+    let (values, _) = Matrix3::from_diagonal(Vector3::new(1.0, ::std::f64::INFINITY, 2.0)).symmetric_eigen();
+    assert_eq!(values.x, ::std::f64::INFINITY);
+}
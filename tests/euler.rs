@@ -0,0 +1,115 @@
+// Copyright 2016 The CGMath Developers. For a full listing of the authors,
+// refer to the Cargo.toml file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#[macro_use]
+extern crate approx;
+extern crate cgmath;
+
+use std::f64;
+
+use cgmath::*;
+
+const ORDERS: [EulerOrder; 12] = [
+    EulerOrder::XYZ, EulerOrder::XZY, EulerOrder::YXZ, EulerOrder::YZX, EulerOrder::ZXY, EulerOrder::ZYX,
+    EulerOrder::XYX, EulerOrder::XZX, EulerOrder::YXY, EulerOrder::YZY, EulerOrder::ZXZ, EulerOrder::ZYZ,
+];
+
+const FRAMES: [EulerFrame; 2] = [EulerFrame::Intrinsic, EulerFrame::Extrinsic];
+
+fn sequence(order: EulerOrder, frame: EulerFrame, first: f64, second: f64, third: f64) -> EulerSequence<Rad<f64>> {
+    EulerSequence { order: order, frame: frame, first: Rad(first), second: Rad(second), third: Rad(third) }
+}
+
+/// Angles that lie within the documented output ranges for every order.
+fn check_round_trip(first: f64, second: f64, third: f64) {
+    for &order in ORDERS.iter() {
+        for &frame in FRAMES.iter() {
+            let second = if order.is_tait_bryan() { second } else { second + f64::consts::FRAC_PI_2 };
+            let angles = sequence(order, frame, first, second, third);
+
+            let quaternion = Quaternion::from(angles);
+            assert_relative_eq!(EulerSequence::from_rotation(quaternion, order, frame), angles, epsilon = 1.0e-10);
+
+            let basis = Basis3::from(angles);
+            assert_relative_eq!(EulerSequence::from_rotation(basis, order, frame), angles, epsilon = 1.0e-10);
+
+            let matrix3 = Matrix3::from(angles);
+            assert_relative_eq!(EulerSequence::from_rotation(matrix3, order, frame), angles, epsilon = 1.0e-10);
+
+            let matrix4 = Matrix4::from(angles);
+            assert_relative_eq!(EulerSequence::from_matrix4(matrix4, order, frame), angles, epsilon = 1.0e-10);
+        }
+    }
+}
+
+#[test] fn test_round_trip_zero()     { check_round_trip( 0.0,  0.0,  0.0); }
+#[test] fn test_round_trip_positive() { check_round_trip( 0.3,  0.5,  0.7); }
+#[test] fn test_round_trip_negative() { check_round_trip(-1.2, -0.4, -2.5); }
+#[test] fn test_round_trip_mixed()    { check_round_trip( 2.9, -1.1,  0.1); }
+
+#[test]
+fn test_gimbal_lock() {
+    for &order in ORDERS.iter() {
+        for &frame in FRAMES.iter() {
+            for &second in [-f64::consts::FRAC_PI_2, 0.0, f64::consts::FRAC_PI_2, f64::consts::PI].iter() {
+                let locked = if order.is_tait_bryan() {
+                    second.abs() == f64::consts::FRAC_PI_2
+                } else {
+                    second == 0.0 || second == f64::consts::PI
+                };
+                if !locked { continue; }
+
+                let matrix = Matrix3::from(sequence(order, frame, 0.4, second, 0.9));
+                let angles = EulerSequence::from_rotation(matrix, order, frame);
+
+                assert_ulps_eq!(angles.third, Rad(0.0));
+                assert_relative_eq!(Matrix3::from(angles), matrix, epsilon = 1.0e-10);
+            }
+        }
+    }
+}
+
+#[test]
+fn test_matches_euler() {
+    let euler = Euler { x: Rad(0.3f64), y: Rad(-0.6), z: Rad(1.2) };
+    let angles = EulerSequence::from(euler);
+
+    assert_eq!(angles, sequence(EulerOrder::XYZ, EulerFrame::Intrinsic, 0.3, -0.6, 1.2));
+    assert_relative_eq!(Quaternion::from(angles), Quaternion::from(euler), epsilon = 1.0e-12);
+    assert_relative_eq!(Matrix4::from(angles), Matrix4::from(euler), epsilon = 1.0e-12);
+}
+
+#[test]
+fn test_extrinsic_is_reversed_intrinsic() {
+    let extrinsic = sequence(EulerOrder::XYZ, EulerFrame::Extrinsic, 0.1, 0.2, 0.3);
+    let intrinsic = sequence(EulerOrder::ZYX, EulerFrame::Intrinsic, 0.3, 0.2, 0.1);
+
+    assert_eq!(extrinsic.to_frame(EulerFrame::Intrinsic), intrinsic);
+    assert_relative_eq!(Matrix3::from(extrinsic), Matrix3::from(intrinsic), epsilon = 1.0e-12);
+}
+
+#[test]
+fn test_order_of_application() {
+    // An intrinsic rotation of 90° around z followed by 90° around the new x
+    // axis takes the x axis to the y axis.
+    let rot = Basis3::from(EulerSequence::intrinsic(EulerOrder::ZXY, Deg(90.0), Deg(90.0), Deg(0.0)));
+    assert_ulps_eq!(rot.rotate_vector(Vector3::unit_x()), Vector3::unit_y());
+    assert_ulps_eq!(rot.rotate_vector(Vector3::unit_y()), Vector3::unit_z());
+
+    // The same angles applied extrinsically rotate around the original axes.
+    let rot = Basis3::from(EulerSequence::extrinsic(EulerOrder::ZXY, Deg(90.0), Deg(90.0), Deg(0.0)));
+    assert_ulps_eq!(rot.rotate_vector(Vector3::unit_x()), Vector3::unit_z());
+    assert_ulps_eq!(rot.rotate_vector(Vector3::unit_y()), -Vector3::unit_x());
+}
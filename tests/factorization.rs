@@ -0,0 +1,102 @@
+// Copyright 2016 The CGMath Developers. For a full listing of the authors,
+// refer to the Cargo.toml file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#[macro_use]
+extern crate approx;
+extern crate cgmath;
+
+use cgmath::*;
+
+fn matrix4() -> Matrix4<f64> {
+    Matrix4::new(0.0, 2.0, 1.0, 0.5,
+                 1.0, 1.0, 3.0, 0.0,
+                 2.0, 0.0, 1.0, 1.0,
+                 0.0, 1.0, 0.0, 2.0)
+}
+
+#[test]
+fn test_lu() {
+    let m = matrix4();
+    let lu = m.lu();
+    assert_relative_eq!(lu.p * m, lu.l * lu.u, epsilon = 1.0e-12);
+    assert_relative_eq!(lu.determinant(), m.determinant(), epsilon = 1.0e-12);
+
+    let b = Vector4::new(1.0, -2.0, 0.5, 3.0);
+    let x = lu.solve(b).unwrap();
+    assert_relative_eq!(m * x, b, epsilon = 1.0e-12);
+
+    let singular = Matrix3::new(1.0, 2.0, 3.0,
+                                2.0, 4.0, 6.0,
+                                0.0, 1.0, 1.0);
+    assert_eq!(singular.lu().solve(Vector3::unit_x()), None);
+}
+
+#[test]
+fn test_qr() {
+    let m = matrix4();
+    let qr = m.qr();
+    assert_relative_eq!(qr.q * qr.r, m, epsilon = 1.0e-12);
+    assert_relative_eq!(qr.q * qr.q.transpose(), Matrix4::identity(), epsilon = 1.0e-12);
+    for j in 0..4 {
+        for i in j + 1..4 {
+            assert_eq!(qr.r[j][i], 0.0);
+        }
+    }
+
+    let b = Vector4::new(1.0, -2.0, 0.5, 3.0);
+    assert_relative_eq!(m * qr.solve(b).unwrap(), b, epsilon = 1.0e-12);
+
+    let m = Matrix2::new(3.0, 4.0, 1.0, 2.0);
+    let qr = m.qr();
+    assert_relative_eq!(qr.q * qr.r, m, epsilon = 1.0e-12);
+}
+
+#[test]
+fn test_cholesky() {
+    let m = Matrix3::new(4.0, 2.0, -2.0,
+                         2.0, 10.0, 2.0,
+                         -2.0, 2.0, 6.0);
+    let cholesky = m.cholesky().unwrap();
+    assert_relative_eq!(cholesky.l * cholesky.l.transpose(), m, epsilon = 1.0e-12);
+    assert_eq!(cholesky.l[1][0], 0.0);
+
+    let b = Vector3::new(1.0, 2.0, 3.0);
+    assert_relative_eq!(m * cholesky.solve(b).unwrap(), b, epsilon = 1.0e-12);
+
+    let mut singular = cholesky;
+    singular.l[2][2] = 0.0;
+    assert_eq!(singular.solve(b), None);
+
+    assert_eq!(Matrix2::new(1.0, 2.0, 2.0, 1.0).cholesky(), None);
+    assert_eq!(Matrix2::<f64>::zero().cholesky(), None);
+}
+
+#[test]
+fn test_nearly_singular() {
+    // Singular in exact arithmetic, but rounding leaves tiny nonzero pivots.
+    let m = Matrix3::new(0.1, 0.4, 0.7,
+                         0.2, 0.5, 0.8,
+                         0.3, 0.6, 0.9);
+    let b = Vector3::new(1.0, 0.0, 0.0);
+    assert_eq!(m.lu().solve(b), None);
+    assert_eq!(m.qr().solve(b), None);
+
+    // A small but well-conditioned matrix is not singular.
+    let small = Matrix2::new(1.0e-10, 0.0, 0.0, 2.0e-10);
+    assert_relative_eq!(small.lu().solve(Vector2::new(1.0, 1.0)).unwrap(),
+                        Vector2::new(1.0e10, 0.5e10), max_relative = 1.0e-12);
+    assert_relative_eq!(small.qr().solve(Vector2::new(1.0, 1.0)).unwrap(),
+                        Vector2::new(1.0e10, 0.5e10), max_relative = 1.0e-12);
+}
@@ -0,0 +1,225 @@
+// Copyright 2016 The CGMath Developers. For a full listing of the authors,
+// refer to the Cargo.toml file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#![cfg(feature = "fixed-point")]
+
+#[macro_use]
+extern crate approx;
+extern crate cgmath;
+
+use cgmath::*;
+use cgmath::num_traits::{cast, Float, ToPrimitive};
+
+fn fx32(v: f64) -> Fixed32 {
+    cast(v).unwrap()
+}
+
+fn fx64(v: f64) -> Fixed64 {
+    cast(v).unwrap()
+}
+
+pub mod scalar {
+    use super::*;
+
+    #[test]
+    fn test_arithmetic() {
+        assert_eq!(fx32(1.5) + fx32(2.25), fx32(3.75));
+        assert_eq!(fx32(1.5) - fx32(2.25), fx32(-0.75));
+        assert_eq!(fx32(1.5) * fx32(-2.25), fx32(-3.375));
+        assert_eq!(fx32(1.0) / fx32(3.0), Fixed32::from_bits(21845));
+        assert_eq!(fx32(2.0) / fx32(3.0), Fixed32::from_bits(43691));
+        assert_eq!(fx32(7.5) % fx32(2.0), fx32(1.5));
+        assert_eq!(fx64(-7.5) % fx64(2.0), fx64(-1.5));
+        assert_eq!(Fixed32::from_bits(1) * fx32(0.5), Fixed32::from_bits(1));
+        assert_eq!(fx32(1.5).to_f64(), Some(1.5));
+        assert_eq!(fx64(-2.75).to_i64(), Some(-2));
+        assert_eq!(format!("{:?}", fx32(0.25)), "0.25");
+        assert_eq!(cast::<f64, Fixed32>(40000.0), None);
+
+        // Casts between the fixed-point types do not go through `f64`.
+        let precise = Fixed64::from_bits((1 << 62) + 1);
+        assert_eq!(cast::<Fixed64, Fixed64>(precise), Some(precise));
+        assert_eq!(cast::<Fixed32, Fixed64>(Fixed32::from_bits(-3)), Some(Fixed64::from_bits(-3 << 16)));
+        assert_eq!(cast::<Fixed64, Fixed32>(Fixed64::from_bits((5 << 16) + (1 << 15))), Some(Fixed32::from_bits(6)));
+        assert_eq!(cast::<Fixed64, Fixed32>(Fixed64::from_bits((5 << 16) + (1 << 15) - 1)), Some(Fixed32::from_bits(5)));
+        assert_eq!(cast::<Fixed64, Fixed32>(fx64(40000.0)), None);
+    }
+
+    #[test]
+    fn test_saturation() {
+        assert_eq!(fx32(30000.0) + fx32(30000.0), Fixed32::max_value());
+        assert_eq!(fx32(-30000.0) * fx32(2.0), Fixed32::min_value());
+        assert_eq!(-Fixed32::min_value(), Fixed32::max_value());
+        assert_eq!(fx32(1.0) / Fixed32::zero(), Fixed32::infinity());
+        assert_eq!(fx64(-1.0) / Fixed64::zero(), Fixed64::neg_infinity());
+        assert_eq!(Fixed32::zero() / Fixed32::zero(), Fixed32::zero());
+        assert_eq!(fx32(-4.0).sqrt(), Fixed32::zero());
+        assert!(Fixed32::infinity().is_infinite());
+        assert!(!Fixed32::nan().is_nan());
+    }
+
+    #[test]
+    fn test_rounding() {
+        assert_eq!(fx32(2.5).floor(), fx32(2.0));
+        assert_eq!(fx32(-2.5).floor(), fx32(-3.0));
+        assert_eq!(fx32(2.5).ceil(), fx32(3.0));
+        assert_eq!(fx32(-2.5).ceil(), fx32(-2.0));
+        assert_eq!(fx32(2.5).round(), fx32(3.0));
+        assert_eq!(fx32(-2.5).round(), fx32(-3.0));
+        assert_eq!(fx32(-2.25).round(), fx32(-2.0));
+        assert_eq!(fx32(-2.75).trunc(), fx32(-2.0));
+        assert_eq!(fx32(-2.75).fract(), fx32(-0.75));
+    }
+
+    // The expected bits are the exactly rounded results.
+
+    #[test]
+    fn test_functions_fixed32() {
+        assert_eq!(fx32(2.0).sqrt().to_bits(), 92682);
+        assert_eq!(fx32(1.0).sin().to_bits(), 55147);
+        assert_eq!(fx32(1.0).cos().to_bits(), 35409);
+        assert_eq!(fx32(0.5).tan().to_bits(), 35802);
+        assert_eq!(fx32(1000.0).sin().to_bits(), 54190);
+        assert_eq!(fx32(0.5).asin().to_bits(), 34315);
+        assert_eq!(fx32(1.0).atan2(fx32(-1.0)).to_bits(), 154416);
+        assert_eq!(fx32(1.0).exp().to_bits(), 178145);
+        assert_eq!(fx32(10.0).ln().to_bits(), 150902);
+    }
+
+    #[test]
+    fn test_functions_fixed64() {
+        assert_eq!(fx64(2.0).sqrt().to_bits(), 6074001000);
+        assert_eq!(fx64(1.0).sin().to_bits(), 3614090360);
+        assert_eq!(fx64(1.0).cos().to_bits(), 2320580734);
+        assert_eq!(fx64(0.5).tan().to_bits(), 2346351328);
+        assert_eq!(fx64(1000.0).sin().to_bits(), 3551420584);
+        assert_eq!(fx64(0.5).asin().to_bits(), 2248839617);
+        assert_eq!(fx64(1.0).atan2(fx64(-1.0)).to_bits(), 10119778278);
+        assert_eq!(fx64(1.0).exp().to_bits(), 11674931555);
+        assert_eq!(fx64(10.0).ln().to_bits(), 9889527671);
+    }
+
+    #[test]
+    fn test_functions_against_f64() {
+        for i in -40..41 {
+            let x = i as f64 * 0.37;
+            let (a, b) = (fx64(x), fx64(1.0 - x * 0.2));
+            assert_relative_eq!(a.sin().to_f64().unwrap(), x.sin(), epsilon = 1.0e-9);
+            assert_relative_eq!(a.cos().to_f64().unwrap(), x.cos(), epsilon = 1.0e-9);
+            assert_relative_eq!(a.atan().to_f64().unwrap(), x.atan(), epsilon = 1.0e-9);
+            assert_relative_eq!(a.atan2(b).to_f64().unwrap(), x.atan2(1.0 - x * 0.2), epsilon = 1.0e-9);
+            assert_relative_eq!(a.exp().to_f64().unwrap(), x.exp(), epsilon = 1.0e-9, max_relative = 1.0e-9);
+            assert_relative_eq!(a.tanh().to_f64().unwrap(), x.tanh(), epsilon = 1.0e-9);
+            assert_relative_eq!(a.asinh().to_f64().unwrap(), x.asinh(), epsilon = 1.0e-9);
+            assert_relative_eq!(a.cbrt().to_f64().unwrap(), x.cbrt(), epsilon = 1.0e-9);
+            assert_relative_eq!(a.hypot(b).to_f64().unwrap(), x.hypot(1.0 - x * 0.2), epsilon = 1.0e-9);
+            if x > 0.0 {
+                assert_relative_eq!(a.sqrt().to_f64().unwrap(), x.sqrt(), epsilon = 1.0e-9);
+                assert_relative_eq!(a.ln().to_f64().unwrap(), x.ln(), epsilon = 1.0e-9);
+                assert_relative_eq!(a.powf(fx64(1.5)).to_f64().unwrap(), x.powf(1.5), epsilon = 1.0e-8);
+            }
+            if x.abs() <= 1.0 {
+                assert_relative_eq!(a.asin().to_f64().unwrap(), x.asin(), epsilon = 1.0e-9);
+                assert_relative_eq!(a.acos().to_f64().unwrap(), x.acos(), epsilon = 1.0e-9);
+            }
+        }
+    }
+
+    #[test]
+    fn test_approx_eq() {
+        assert_ulps_eq!(fx32(1.0), Fixed32::from_bits(65540));
+        assert_ulps_ne!(fx32(1.0), Fixed32::from_bits(65541));
+        assert_relative_eq!(fx32(1000.0), fx32(1000.01), max_relative = fx32(0.0001));
+        assert_relative_ne!(fx32(1.0), fx32(1.01), max_relative = fx32(0.0001));
+    }
+}
+
+pub mod end_to_end {
+    use super::*;
+
+    #[test]
+    fn test_vector3() {
+        let v = vec3(3.0f64, -4.0, 12.0);
+        let a = v.cast::<Fixed32>();
+        assert_eq!(a.magnitude(), fx32(13.0));
+        assert_relative_eq!(a.normalize().cast::<f64>(), v.normalize(), epsilon = 1.0e-4);
+
+        let b = vec3(0.5f64, 2.0, -1.0);
+        assert_eq!(a.cross(b.cast()).cast::<f64>(), v.cross(b));
+        assert_eq!(a.dot(b.cast()), fx32(v.dot(b)));
+        assert_eq!(fx32(2.0) * a, a + a);
+    }
+
+    #[test]
+    fn test_angle() {
+        let a = Rad(fx32(1.0));
+        assert_eq!(Rad::sin(a), fx32(1.0).sin());
+        assert_relative_eq!(Deg::from(a).0.to_f64().unwrap(), 57.29578, epsilon = 1.0e-3);
+        assert_relative_eq!(Rad::acos(fx64(0.5)).0.to_f64().unwrap(), ::std::f64::consts::PI / 3.0, epsilon = 1.0e-9);
+    }
+
+    #[test]
+    fn test_quaternion() {
+        let axis = vec3(1.0f64, 2.0, 2.0).normalize();
+        let q = Quaternion::from_axis_angle(axis, Rad(0.8f64));
+        let qf = Quaternion::from_axis_angle(axis.cast::<Fixed64>(), Rad(fx64(0.8)));
+        let v = vec3(1.0f64, -3.0, 0.5);
+
+        let rotated = qf.rotate_vector(v.cast());
+        assert_relative_eq!(rotated.cast::<f64>(), q.rotate_vector(v), epsilon = 1.0e-8);
+        assert_relative_eq!((qf * qf.conjugate()).s.to_f64().unwrap(), 1.0, epsilon = 1.0e-8);
+
+        let half = qf.slerp(Quaternion::one(), fx64(0.5));
+        assert_relative_eq!(half.rotate_vector(v.cast()).cast::<f64>(),
+                            q.slerp(Quaternion::one(), 0.5).rotate_vector(v), epsilon = 1.0e-8);
+    }
+
+    #[test]
+    fn test_matrix4() {
+        let m = Matrix4::from_translation(vec3(1.0f64, -2.0, 3.0)) *
+                Matrix4::from_angle_y(Rad(0.6f64)) *
+                Matrix4::from_nonuniform_scale(2.0, 1.0, 0.5);
+        let mf = Matrix4::from_translation(vec3(fx32(1.0), fx32(-2.0), fx32(3.0))) *
+                 Matrix4::from_angle_y(Rad(fx32(0.6))) *
+                 Matrix4::from_nonuniform_scale(fx32(2.0), fx32(1.0), fx32(0.5));
+
+        let p = Point3::new(0.5f64, 1.0, -1.5);
+        assert_relative_eq!(mf.transform_point(p.cast()).cast::<f64>(), m.transform_point(p), epsilon = 1.0e-3);
+
+        let inverse = mf.invert().unwrap();
+        assert_relative_eq!(inverse.cast::<f64>(), m.invert().unwrap(), epsilon = 1.0e-3);
+        assert_relative_eq!(mf * inverse, Matrix4::identity(), epsilon = fx32(1.0e-3));
+
+        let view = Matrix4::look_at(Point3::new(fx64(1.0), fx64(2.0), fx64(3.0)), Point3::origin(), Vector3::unit_y());
+        let expected = Matrix4::look_at(Point3::new(1.0f64, 2.0, 3.0), Point3::origin(), Vector3::unit_y());
+        assert_relative_eq!(view.cast::<f64>(), expected, epsilon = 1.0e-8);
+    }
+
+    #[test]
+    fn test_determinism() {
+        // The same sequence of operations always produces the same bits.
+        let run = || {
+            let mut q = Quaternion::<Fixed32>::one();
+            let omega = vec3(fx32(0.3), fx32(-1.1), fx32(0.7));
+            for _ in 0..100 {
+                q = q.integrate(omega, fx32(1.0 / 60.0));
+            }
+            q
+        };
+        let (a, b) = (run(), run());
+        assert_eq!(a, b);
+        assert_relative_eq!(a.magnitude().to_f64().unwrap(), 1.0, epsilon = 1.0e-3);
+    }
+}
@@ -0,0 +1,114 @@
+// Copyright 2016 The CGMath Developers. For a full listing of the authors,
+// refer to the Cargo.toml file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#[macro_use]
+extern crate approx;
+extern crate cgmath;
+
+use std::f64;
+
+use cgmath::*;
+
+const PERSPECTIVE: Perspective<f64> = Perspective { left: -1.0f64, right: 1.0f64, bottom: -0.5f64,
+                                                   top: 0.5f64, near: 1.0f64, far: 10.0f64 };
+
+#[test]
+fn test_perspective_planes() {
+    let frustum = Frustum::from_projection(PERSPECTIVE).unwrap();
+    let half_sqrt2 = 0.5f64.sqrt();
+
+    assert_relative_eq!(frustum.near, Plane::new(-Vector3::unit_z(), 1.0), epsilon = 1.0e-12);
+    assert_relative_eq!(frustum.far, Plane::new(Vector3::unit_z(), -10.0), epsilon = 1.0e-12);
+    assert_relative_eq!(frustum.left, Plane::new(Vector3::new(half_sqrt2, 0.0, -half_sqrt2), 0.0), epsilon = 1.0e-12);
+    assert_relative_eq!(frustum.right, Plane::new(Vector3::new(-half_sqrt2, 0.0, -half_sqrt2), 0.0), epsilon = 1.0e-12);
+}
+
+#[test]
+fn test_corners() {
+    let corners = Frustum::from_projection(PERSPECTIVE).unwrap().to_corners().unwrap();
+    let expected = [
+        Point3::new(-1.0, -0.5, -1.0), Point3::new(1.0, -0.5, -1.0),
+        Point3::new(-1.0, 0.5, -1.0), Point3::new(1.0, 0.5, -1.0),
+        Point3::new(-10.0, -5.0, -10.0), Point3::new(10.0, -5.0, -10.0),
+        Point3::new(-10.0, 5.0, -10.0), Point3::new(10.0, 5.0, -10.0),
+    ];
+    for (corner, expected) in corners.iter().zip(expected.iter()) {
+        assert_relative_eq!(corner, expected, epsilon = 1.0e-12);
+    }
+
+    let ortho = Ortho { left: -2.0, right: 3.0, bottom: -1.0, top: 4.0, near: 0.5, far: 5.0 };
+    let corners = Frustum::from_projection(ortho).unwrap().to_corners().unwrap();
+    assert_relative_eq!(corners[0], Point3::new(-2.0, -1.0, -0.5), epsilon = 1.0e-12);
+    assert_relative_eq!(corners[7], Point3::new(3.0, 4.0, -5.0), epsilon = 1.0e-12);
+
+    // Only the near corners of a frustum with an infinite far plane are finite.
+    let infinite = Perspective { far: f64::INFINITY, ..PERSPECTIVE };
+    let frustum = Frustum::from_projection(infinite).unwrap();
+    assert_eq!(frustum.to_corners(), None);
+    for (corner, expected) in frustum.near_corners().iter().zip(expected.iter()) {
+        assert_relative_eq!(corner, expected, epsilon = 1.0e-12);
+    }
+}
+
+#[test]
+fn test_view_projection() {
+    // A camera at (0, 0, 5) looking down the negative z axis.
+    let view = Matrix4::look_at(Point3::new(0.0, 0.0, 5.0), Point3::new(0.0, 0.0, 0.0), Vector3::unit_y());
+    let projection = Matrix4::from(PERSPECTIVE);
+    let frustum = Frustum::from_matrix4(projection * view).unwrap();
+
+    assert_relative_eq!(frustum.near, Plane::new(-Vector3::unit_z(), -4.0), epsilon = 1.0e-12);
+    assert_eq!(frustum.relate_point(Point3::new(0.0, 0.0, 0.0)), Relation::In);
+    assert_eq!(frustum.relate_point(Point3::new(0.0, 0.0, 6.0)), Relation::Out);
+
+    assert_eq!(Frustum::from_matrix4(Matrix4::<f64>::zero()), None);
+
+    // A degenerate projection has no frustum.
+    let flat = Ortho { left: 1.0, right: 1.0, bottom: -1.0, top: 1.0, near: 0.5, far: 5.0 };
+    assert_eq!(Frustum::from_projection(flat), None);
+}
+
+#[test]
+fn test_relate_point() {
+    let frustum = Frustum::from_projection(PERSPECTIVE).unwrap();
+    assert_eq!(frustum.relate_point(Point3::new(0.0, 0.0, -5.0)), Relation::In);
+    assert_eq!(frustum.relate_point(Point3::new(0.0, 0.0, -0.5)), Relation::Out);
+    assert_eq!(frustum.relate_point(Point3::new(0.0, 0.0, -11.0)), Relation::Out);
+    assert_eq!(frustum.relate_point(Point3::new(6.0, 0.0, -5.0)), Relation::Out);
+
+    let ortho = Frustum::from_projection(Ortho { left: -1.0, right: 1.0, bottom: -1.0, top: 1.0, near: 1.0, far: 3.0 }).unwrap();
+    assert_eq!(ortho.relate_point(Point3::new(1.0, 0.0, -2.0)), Relation::Cross);
+}
+
+#[test]
+fn test_relate_sphere() {
+    let frustum = Frustum::from_projection(PERSPECTIVE).unwrap();
+    assert_eq!(frustum.relate_sphere(&Sphere::new(Point3::new(0.0, 0.0, -5.0), 1.0)), Relation::In);
+    assert_eq!(frustum.relate_sphere(&Sphere::new(Point3::new(0.0, 0.0, -10.0), 1.0)), Relation::Cross);
+    assert_eq!(frustum.relate_sphere(&Sphere::new(Point3::new(0.0, 0.0, -12.0), 1.0)), Relation::Out);
+    assert_eq!(frustum.relate_sphere(&Sphere::new(Point3::new(0.0, 0.0, 2.0), 1.0)), Relation::Out);
+}
+
+#[test]
+fn test_relate_aabb() {
+    let frustum = Frustum::from_projection(PERSPECTIVE).unwrap();
+    let aabb = |min: [f64; 3], max: [f64; 3]| Aabb3::new(Point3::from(min), Point3::from(max));
+
+    assert_eq!(frustum.relate_aabb(&aabb([-1.0, -1.0, -6.0], [1.0, 1.0, -4.0])), Relation::In);
+    assert_eq!(frustum.relate_aabb(&aabb([-1.0, -1.0, -12.0], [1.0, 1.0, -8.0])), Relation::Cross);
+    assert_eq!(frustum.relate_aabb(&aabb([-20.0, -20.0, -20.0], [20.0, 20.0, 20.0])), Relation::Cross);
+    assert_eq!(frustum.relate_aabb(&aabb([-1.0, -1.0, -0.9], [1.0, 1.0, 3.0])), Relation::Out);
+    assert_eq!(frustum.relate_aabb(&aabb([6.5, -1.0, -6.0], [7.0, 1.0, -4.0])), Relation::Out);
+}
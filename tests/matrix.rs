@@ -747,3 +747,210 @@ pub mod matrix4 {
         }
     }
 }
+
+pub mod matrix2x3 {
+    use cgmath::*;
+
+    const A: Matrix2x3<f64> = Matrix2x3 { x: Vector2 { x: 1.0f64, y: 4.0f64 },
+                                          y: Vector2 { x: 2.0f64, y: 5.0f64 },
+                                          z: Vector2 { x: 3.0f64, y: 6.0f64 } };
+
+    #[test]
+    fn test_row() {
+        assert_eq!(A.row(0), Vector3::new(1.0f64, 2.0f64, 3.0f64));
+        assert_eq!(A.row(1), Vector3::new(4.0f64, 5.0f64, 6.0f64));
+    }
+
+    #[test]
+    fn test_mul_vector() {
+        assert_eq!(A * Vector3::new(1.0f64, 0.0f64, -1.0f64), Vector2::new(-2.0f64, -2.0f64));
+    }
+
+    #[test]
+    fn test_transpose() {
+        assert_eq!(A.transpose(),
+                   Matrix3x2::new(1.0f64, 2.0f64, 3.0f64,
+                                  4.0f64, 5.0f64, 6.0f64));
+        assert_eq!(A.transpose().transpose(), A);
+    }
+
+    #[test]
+    fn test_mul_matrix() {
+        // (2 x 3) * (3 x 2) = (2 x 2)
+        assert_eq!(A * A.transpose(),
+                   Matrix2::new(14.0f64, 32.0f64,
+                                32.0f64, 77.0f64));
+        // (3 x 2) * (2 x 3) = (3 x 3)
+        assert_eq!(A.transpose() * A,
+                   Matrix3::new(17.0f64, 22.0f64, 27.0f64,
+                                22.0f64, 29.0f64, 36.0f64,
+                                27.0f64, 36.0f64, 45.0f64));
+        // (2 x 3) * (3 x 3) = (2 x 3)
+        assert_eq!(A * Matrix3::identity(), A);
+        assert_eq!(Matrix2::identity() * A, A);
+    }
+
+    #[test]
+    fn test_swap() {
+        let mut mut_a = A;
+        mut_a.swap_rows(0, 1);
+        assert_eq!(mut_a.row(0), A.row(1));
+        mut_a.swap_columns(0, 2);
+        assert_eq!(mut_a[0], Vector2::new(6.0f64, 3.0f64));
+    }
+
+    #[test]
+    fn test_conv() {
+        assert_eq!(conv::array2x3(A), [[1.0f64, 4.0f64], [2.0f64, 5.0f64], [3.0f64, 6.0f64]]);
+        assert_eq!(Matrix2x3::from([[1.0f64, 4.0f64], [2.0f64, 5.0f64], [3.0f64, 6.0f64]]), A);
+    }
+}
+
+pub mod matrix3x4 {
+    use cgmath::*;
+
+    const A: Matrix3x4<f64> = Matrix3x4 { x: Vector3 { x: 1.0f64, y: 5.0f64, z:  9.0f64 },
+                                          y: Vector3 { x: 2.0f64, y: 6.0f64, z: 10.0f64 },
+                                          z: Vector3 { x: 3.0f64, y: 7.0f64, z: 11.0f64 },
+                                          w: Vector3 { x: 4.0f64, y: 8.0f64, z: 12.0f64 } };
+
+    #[test]
+    fn test_ops() {
+        assert_eq!(-A + A, Matrix3x4::zero());
+        assert_eq!(A * 2.0f64, A + A);
+        assert_eq!(2.0f64 * A, A + A);
+        assert_eq!((A * 2.0f64) / 2.0f64, A);
+        assert_eq!(A - A, Matrix3x4::zero());
+    }
+
+    #[test]
+    fn test_mul_vector() {
+        assert_eq!(A * Vector4::new(1.0f64, 1.0f64, 1.0f64, 1.0f64),
+                   Vector3::new(10.0f64, 26.0f64, 42.0f64));
+    }
+
+    #[test]
+    fn test_mul_matrix4() {
+        // (3 x 4) * (4 x 4) = (3 x 4), matching the product of the full affine
+        // matrices.
+        let m = Matrix4::from_translation(Vector3::new(1.0f64, 2.0f64, 3.0f64)) *
+                Matrix4::from_angle_z(Deg(30.0f64));
+        let expected = Matrix3x4::from(Matrix4::from(A) * m);
+        assert_ulps_eq!(A * m, expected);
+        assert_ulps_eq!(&A * &m, expected);
+    }
+
+    #[test]
+    fn test_matrix4_round_trip() {
+        let m = Matrix4::from(A);
+        assert_eq!(m.row(3), Vector4::new(0.0f64, 0.0f64, 0.0f64, 1.0f64));
+        assert_eq!(Matrix3x4::from(m), A);
+    }
+
+    #[test]
+    fn test_transpose() {
+        let t: Matrix4x3<f64> = A.transpose();
+        assert_eq!(t[2], Vector4::new(9.0f64, 10.0f64, 11.0f64, 12.0f64));
+        assert_eq!(t.transpose(), A);
+        // (4 x 3) * (3 x 4) = (4 x 4)
+        assert_eq!((t * A).transpose(), t * A);
+    }
+
+    #[test]
+    fn test_conv() {
+        let arr = conv::array3x4(A);
+        assert_eq!(arr[3], [4.0f64, 8.0f64, 12.0f64]);
+        let flat: &[f64; 12] = A.as_ref();
+        assert_eq!(flat[11], 12.0f64);
+    }
+
+    #[test]
+    fn test_cast() {
+        assert_ulps_eq!(A.cast(), Matrix3x4::new(1.0f32, 5.0, 9.0,
+                                                 2.0,    6.0, 10.0,
+                                                 3.0,    7.0, 11.0,
+                                                 4.0,    8.0, 12.0));
+    }
+}
+
+pub mod integer {
+    use cgmath::*;
+
+    // Quarter turns about `z` and `x`, as exact lattice rotations.
+    const ROT_Z: Matrix3<i32> = Matrix3 { x: Vector3 { x:  0, y: 1, z: 0 },
+                                          y: Vector3 { x: -1, y: 0, z: 0 },
+                                          z: Vector3 { x:  0, y: 0, z: 1 } };
+    const ROT_X: Matrix3<i32> = Matrix3 { x: Vector3 { x: 1, y:  0, z: 0 },
+                                          y: Vector3 { x: 0, y:  0, z: 1 },
+                                          z: Vector3 { x: 0, y: -1, z: 0 } };
+
+    #[test]
+    fn test_rotation() {
+        assert_eq!(ROT_Z * Vector3::new(1, 2, 3), Vector3::new(-2, 1, 3));
+        assert_eq!(ROT_X * Vector3::new(1, 2, 3), Vector3::new(1, -3, 2));
+        assert_eq!(ROT_Z * ROT_Z * ROT_Z * ROT_Z, Matrix3::identity());
+        assert_eq!(ROT_Z * ROT_Z.transpose(), Matrix3::identity());
+        assert_eq!(ROT_Z.determinant(), 1);
+        assert_eq!((ROT_Z * ROT_X).determinant(), 1);
+        assert_eq!((&ROT_Z * &ROT_X) * Vector3::unit_y(), ROT_Z * (ROT_X * Vector3::unit_y()));
+    }
+
+    #[test]
+    fn test_ring_ops() {
+        let a = Matrix3::new(2, 0, 1,
+                             1, 3, 0,
+                             0, 1, 4);
+        assert_eq!(a.trace(), 9);
+        assert_eq!(a.diagonal(), Vector3::new(2, 3, 4));
+        assert_eq!(a.determinant(), 25);
+        assert_eq!(a.row(0), Vector3::new(2, 1, 0));
+        assert_eq!(-a + a, Matrix3::zero());
+        assert!((a - a).is_zero());
+        assert_eq!(a * 3, a + a + a);
+        assert_eq!(Matrix3::from_value(2) * a, a * 2);
+        assert_eq!(Matrix3::from_diagonal(Vector3::new(1, 2, 3)).trace(), 6);
+    }
+
+    #[test]
+    fn test_affine() {
+        let rotation = Matrix4::from(ROT_Z.cast::<i64>());
+        let translation = Matrix4::from_translation(Vector3::new(10i64, -4, 7));
+        let m = translation * rotation * Matrix4::from_scale(2);
+
+        let p = Point3::new(1i64, 2, 3);
+        assert_eq!(m * p.to_homogeneous(), Vector4::new(6, -2, 13, 1));
+        assert_eq!(m.determinant(), 8);
+        assert_eq!(m.transpose().transpose(), m);
+        assert_eq!(m.trace(), 0 + 0 + 2 + 1);
+        assert_eq!(Matrix3x4::from(m) * Vector4::new(1, 2, 3, 1), Vector3::new(6, -2, 13));
+    }
+
+    #[test]
+    fn test_unsigned() {
+        let a = Matrix2::new(1u32, 2,
+                             3, 4);
+        assert_eq!(a * Matrix2::identity(), a);
+        assert_eq!(a * a, Matrix2::new(7, 10, 15, 22));
+        assert_eq!(a.transpose() * Vector2::new(1, 1), Vector2::new(3, 7));
+    }
+
+    fn power<M: SquareMatrix>(m: M, n: u32) -> M {
+        (0..n).fold(M::identity(), |acc, _| acc * m)
+    }
+
+    #[test]
+    fn test_generic() {
+        assert_eq!(power(ROT_Z, 4), Matrix3::identity());
+        assert_eq!(power(ROT_X, 2).trace(), -1);
+        assert_eq!(power(Matrix4::from_translation(Vector3::new(1, 2, 3)), 3),
+                   Matrix4::from_translation(Vector3::new(3, 6, 9)));
+    }
+
+    #[test]
+    fn test_is_zero() {
+        assert!(!Matrix2::new(0, 1, 0, 0).is_zero());
+        // Float matrices still allow for rounding error.
+        assert!(Matrix2::new(1.0e-20, 0.0, 0.0, -1.0e-20).is_zero());
+        assert!(!Matrix2::new(1.0e-3, 0.0, 0.0, 0.0).is_zero());
+    }
+}
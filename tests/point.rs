@@ -17,7 +17,8 @@
 extern crate approx;
 extern crate cgmath;
 
-use cgmath::{Point1, Point2, Point3};
+use cgmath::{Point1, Point2, Point3, Point4};
+use cgmath::{Deg, Matrix4, SquareMatrix, Transform, Vector3};
 
 macro_rules! impl_test_mul {
     ($PointN:ident { $($field:ident),+ }, $s:expr, $v:expr) => (
@@ -80,4 +81,55 @@ fn test_cast() {
     assert_ulps_eq!(Point1::new(0.9f64).cast(), Point1::new(0.9f32));
     assert_ulps_eq!(Point2::new(0.9f64, 1.5).cast(), Point2::new(0.9f32, 1.5));
     assert_ulps_eq!(Point3::new(1.0f64, 2.4, -3.13).cast(), Point3::new(1.0f32, 2.4, -3.13));
+    assert_ulps_eq!(Point4::new(1.0f64, 2.4, -3.13, 0.5).cast(), Point4::new(1.0f32, 2.4, -3.13, 0.5));
+}
+
+#[test]
+fn test_projective() {
+    let p = Point4::new(2.0f64, 4.0, -6.0, 2.0);
+    assert!(!p.is_at_infinity());
+    assert_eq!(p.to_point3(), Some(Point3::new(1.0, 2.0, -3.0)));
+    assert_eq!(Point4::from(Point3::new(1.0f64, 2.0, -3.0)), Point4::new(1.0, 2.0, -3.0, 1.0));
+    assert_eq!(p.truncate(), Vector3::new(2.0, 4.0, -6.0));
+
+    let inf = Point4::at_infinity(Vector3::new(0.0f64, 0.0, -1.0));
+    assert!(inf.is_at_infinity());
+    assert_eq!(inf.to_point3(), None);
+
+    assert_eq!(Point4::new(3, 6, 9, 3).to_point3(), Some(Point3::new(1, 2, 3)));
+    assert_eq!(format!("{:?}", Point4::new(1, 2, 3, 0)), "Point4 [1, 2, 3, 0]");
+
+    // Equality compares the homogeneous coordinates, not the points they represent.
+    assert!(Point4::new(1, 2, 3, 1) != Point4::new(2, 4, 6, 2));
+    assert_eq!(Point4::new(1, 2, 3, 1)[3], 1);
+    assert_eq!(Point4::from([1, 2, 3, 4]), Point4::new(1, 2, 3, 4));
+}
+
+#[test]
+fn test_transform_point4() {
+    let translation = Matrix4::from_translation(Vector3::new(1.0f64, -2.0, 3.0));
+    let p = Point4::new(2.0f64, 4.0, -6.0, 2.0);
+    assert_eq!(translation.transform_point4(p), Point4::new(4.0, 0.0, 0.0, 2.0));
+    assert_eq!(translation.transform_point4(p).to_point3(),
+               Some(translation.transform_point(Point3::new(1.0, 2.0, -3.0))));
+
+    // Directions are not affected by translation.
+    let inf = Point4::at_infinity(Vector3::new(0.0f64, 1.0, -1.0));
+    assert_eq!(translation.transform_point4(inf), inf);
+
+    // Clip space coordinates, before the perspective divide.
+    let proj = cgmath::perspective(Deg(90.0f64), 1.0, 1.0, 10.0);
+    let near = proj.transform_point4(Point4::new(0.0, 0.0, -1.0, 1.0));
+    let far = proj.transform_point4(Point4::new(0.0, 0.0, -10.0, 1.0));
+    assert_ulps_eq!(near, Point4::new(0.0, 0.0, -1.0, 1.0));
+    assert_ulps_eq!(far, Point4::new(0.0, 0.0, 10.0, 10.0));
+    assert_ulps_eq!(far.to_point3().unwrap(), Point3::new(0.0, 0.0, 1.0));
+
+    // A point at infinity in front of the camera projects to a finite point.
+    let horizon = proj.transform_point4(Point4::at_infinity(Vector3::new(0.0, 0.0, -1.0)));
+    assert!(!horizon.is_at_infinity());
+    assert_ulps_eq!(horizon.to_point3().unwrap(), Point3::new(0.0, 0.0, 11.0 / 9.0));
+
+    let inverse = proj.invert().unwrap();
+    assert_relative_eq!(inverse.transform_point4(far), Point4::new(0.0, 0.0, -10.0, 1.0), epsilon = 1.0e-12);
 }
@@ -13,9 +13,17 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+#[macro_use]
+extern crate approx;
 extern crate cgmath;
 
-use cgmath::{Vector4, ortho, Matrix4};
+use std::f64;
+
+use cgmath::{Vector4, ortho, Matrix4, Zero};
+use cgmath::{Deg, DepthRange, Frustum, Ortho, Perspective, PerspectiveFov, Point3, Relation};
+use cgmath::{frustum, frustum_with_depth, ortho_with_depth, perspective, perspective_with_depth};
+use cgmath::{frustum_lh, ortho_lh, perspective_lh};
+use cgmath::{project, unproject, unproject_with_inverse, SquareMatrix, Vector3, Viewport};
 
 #[test]
 fn test_ortho_scale() {
@@ -67,3 +75,196 @@ fn test_ortho_translate() {
     let orig = o * vec_orig;
     assert_eq!(orig, Vector4::new(1., 1., 1., 1.));
 }
+
+/// The normalized device depth of a point on the view axis.
+fn ndc_depth(m: Matrix4<f64>, z: f64) -> f64 {
+    let clip = m * Vector4::new(0.0, 0.0, z, 1.0);
+    clip.z / clip.w
+}
+
+#[test]
+fn test_default_depth_range() {
+    assert_eq!(perspective_with_depth(Deg(60.0), 1.5, 0.1, 100.0, DepthRange::NegativeOneToOne),
+               perspective(Deg(60.0), 1.5, 0.1, 100.0));
+    assert_eq!(frustum_with_depth(-1.0, 2.0, -0.5, 1.0, 0.1, 100.0, DepthRange::NegativeOneToOne),
+               frustum(-1.0, 2.0, -0.5, 1.0, 0.1, 100.0));
+    assert_eq!(ortho_with_depth(-1.0, 2.0, -0.5, 1.0, 0.1, 100.0, DepthRange::NegativeOneToOne),
+               ortho(-1.0, 2.0, -0.5, 1.0, 0.1, 100.0));
+}
+
+#[test]
+fn test_perspective_depth_range() {
+    let persp = PerspectiveFov { fovy: Deg(60.0).into(), aspect: 1.5, near: 0.5, far: 50.0 };
+
+    let m = persp.to_matrix4(DepthRange::NegativeOneToOne);
+    assert_relative_eq!(ndc_depth(m, -0.5), -1.0, epsilon = 1.0e-12);
+    assert_relative_eq!(ndc_depth(m, -50.0), 1.0, epsilon = 1.0e-12);
+
+    let m = persp.to_matrix4(DepthRange::ZeroToOne);
+    assert_relative_eq!(ndc_depth(m, -0.5), 0.0, epsilon = 1.0e-12);
+    assert_relative_eq!(ndc_depth(m, -50.0), 1.0, epsilon = 1.0e-12);
+
+    let m = persp.to_matrix4(DepthRange::OneToZero);
+    assert_relative_eq!(ndc_depth(m, -0.5), 1.0, epsilon = 1.0e-12);
+    assert_relative_eq!(ndc_depth(m, -50.0), 0.0, epsilon = 1.0e-12);
+    assert!(ndc_depth(m, -5.0) > ndc_depth(m, -10.0));
+
+    // The off-center projection maps depths the same way.
+    let m = persp.to_perspective().to_matrix4(DepthRange::OneToZero);
+    assert_relative_eq!(ndc_depth(m, -0.5), 1.0, epsilon = 1.0e-12);
+    assert_relative_eq!(ndc_depth(m, -50.0), 0.0, epsilon = 1.0e-12);
+}
+
+#[test]
+fn test_infinite_perspective() {
+    let persp = Perspective { left: -1.0, right: 1.0, bottom: -1.0, top: 1.0, near: 1.0, far: f64::INFINITY };
+
+    let m = persp.to_matrix4(DepthRange::NegativeOneToOne);
+    assert_relative_eq!(ndc_depth(m, -1.0), -1.0);
+    assert_relative_eq!(ndc_depth(m, -1.0e12), 1.0, epsilon = 1.0e-9);
+
+    let m = persp.to_matrix4(DepthRange::ZeroToOne);
+    assert_relative_eq!(ndc_depth(m, -1.0), 0.0);
+    assert_relative_eq!(ndc_depth(m, -1.0e12), 1.0, epsilon = 1.0e-9);
+
+    let m = persp.to_matrix4(DepthRange::OneToZero);
+    assert_relative_eq!(ndc_depth(m, -1.0), 1.0);
+    assert_relative_eq!(ndc_depth(m, -4.0), 0.25);
+    assert_relative_eq!(ndc_depth(m, -1.0e12), 0.0, epsilon = 1.0e-9);
+
+    // Infinite projections converge on the finite ones as the far plane
+    // moves away.
+    assert_relative_eq!(perspective_with_depth(Deg(90.0), 1.0, 1.0, f64::INFINITY, DepthRange::ZeroToOne),
+                        perspective_with_depth(Deg(90.0), 1.0, 1.0, 1.0e12, DepthRange::ZeroToOne),
+                        epsilon = 1.0e-9);
+}
+
+#[test]
+fn test_ortho_depth_range() {
+    let o = Ortho { left: -1.0, right: 1.0, bottom: -1.0, top: 1.0, near: 2.0, far: 10.0 };
+
+    let m = o.to_matrix4(DepthRange::ZeroToOne);
+    assert_relative_eq!(ndc_depth(m, -2.0), 0.0);
+    assert_relative_eq!(ndc_depth(m, -6.0), 0.5);
+    assert_relative_eq!(ndc_depth(m, -10.0), 1.0);
+
+    let m = o.to_matrix4(DepthRange::OneToZero);
+    assert_relative_eq!(ndc_depth(m, -2.0), 1.0);
+    assert_relative_eq!(ndc_depth(m, -6.0), 0.5);
+    assert_relative_eq!(ndc_depth(m, -10.0), 0.0);
+}
+
+#[test]
+fn test_frustum_depth_range() {
+    let persp = Perspective { left: -1.0, right: 1.0, bottom: -1.0, top: 1.0, near: 1.0, far: 10.0 };
+    let expected = Frustum::from_projection(persp).unwrap();
+
+    for &depth in [DepthRange::ZeroToOne, DepthRange::OneToZero].iter() {
+        let frustum = Frustum::from_matrix4_with_depth(persp.to_matrix4(depth), depth).unwrap();
+        assert_relative_eq!(frustum, expected, epsilon = 1.0e-12);
+    }
+
+    let infinite = Perspective { far: f64::INFINITY, ..persp };
+    let frustum = Frustum::from_matrix4_with_depth(infinite.to_matrix4(DepthRange::OneToZero), DepthRange::OneToZero).unwrap();
+    assert_relative_eq!(frustum.near, expected.near, epsilon = 1.0e-12);
+    assert_eq!(frustum.relate_point(Point3::new(0.0, 0.0, -1.0e12)), Relation::In);
+    assert_eq!(frustum.relate_point(Point3::new(0.0, 0.0, -0.5)), Relation::Out);
+}
+
+#[test]
+fn test_left_handed() {
+    let flip = Matrix4::from_nonuniform_scale(1.0, 1.0, -1.0);
+
+    let persp = PerspectiveFov { fovy: Deg(60.0).into(), aspect: 1.5, near: 0.5, far: 50.0 };
+    assert_eq!(perspective_lh(Deg(60.0), 1.5, 0.5, 50.0, DepthRange::ZeroToOne),
+               persp.to_matrix4(DepthRange::ZeroToOne) * flip);
+
+    // Depths in front of a left-handed camera are positive.
+    let m = persp.to_matrix4_lh(DepthRange::ZeroToOne);
+    assert_relative_eq!(ndc_depth(m, 0.5), 0.0, epsilon = 1.0e-12);
+    assert_relative_eq!(ndc_depth(m, 50.0), 1.0, epsilon = 1.0e-12);
+
+    let clip = m * Vector4::new(1.0, 0.0, 5.0, 1.0);
+    assert!(clip.x > 0.0 && clip.w == 5.0);
+
+    let persp = Perspective { left: -1.0, right: 2.0, bottom: -0.5, top: 1.0, near: 0.5, far: 50.0 };
+    assert_eq!(frustum_lh(-1.0, 2.0, -0.5, 1.0, 0.5, 50.0, DepthRange::OneToZero),
+               persp.to_matrix4(DepthRange::OneToZero) * flip);
+
+    let o = Ortho { left: -1.0, right: 2.0, bottom: -0.5, top: 1.0, near: 0.5, far: 50.0 };
+    assert_eq!(ortho_lh(-1.0, 2.0, -0.5, 1.0, 0.5, 50.0, DepthRange::NegativeOneToOne),
+               o.to_matrix4(DepthRange::NegativeOneToOne) * flip);
+    let m = o.to_matrix4_lh(DepthRange::ZeroToOne);
+    assert_relative_eq!(ndc_depth(m, 0.5), 0.0, epsilon = 1.0e-12);
+    assert_relative_eq!(ndc_depth(m, 50.0), 1.0, epsilon = 1.0e-12);
+}
+
+const DEPTHS: [DepthRange; 3] = [DepthRange::NegativeOneToOne, DepthRange::ZeroToOne, DepthRange::OneToZero];
+
+#[test]
+fn test_inverse_matrix4() {
+    let fov = PerspectiveFov { fovy: Deg(70.0).into(), aspect: 1.25, near: 0.1, far: 1000.0 };
+    let persp = Perspective { left: -1.0, right: 2.0, bottom: -0.5, top: 1.0, near: 0.5, far: 50.0 };
+    let o = Ortho { left: -1.0, right: 2.0, bottom: -0.5, top: 1.0, near: 0.5, far: 50.0 };
+
+    for &depth in DEPTHS.iter() {
+        assert_relative_eq!(fov.to_inverse_matrix4(depth) * fov.to_matrix4(depth), Matrix4::identity(), epsilon = 1.0e-12);
+        assert_relative_eq!(fov.to_inverse_matrix4_lh(depth) * fov.to_matrix4_lh(depth), Matrix4::identity(), epsilon = 1.0e-12);
+        assert_relative_eq!(persp.to_inverse_matrix4(depth) * persp.to_matrix4(depth), Matrix4::identity(), epsilon = 1.0e-12);
+        assert_relative_eq!(persp.to_inverse_matrix4_lh(depth) * persp.to_matrix4_lh(depth), Matrix4::identity(), epsilon = 1.0e-12);
+        assert_relative_eq!(o.to_inverse_matrix4(depth) * o.to_matrix4(depth), Matrix4::identity(), epsilon = 1.0e-12);
+        assert_relative_eq!(o.to_inverse_matrix4_lh(depth) * o.to_matrix4_lh(depth), Matrix4::identity(), epsilon = 1.0e-12);
+    }
+
+    // Infinite projections have no finite far plane, but can still be inverted.
+    let infinite = Perspective { far: f64::INFINITY, ..persp };
+    for &depth in DEPTHS.iter() {
+        assert_relative_eq!(infinite.to_inverse_matrix4(depth) * infinite.to_matrix4(depth), Matrix4::identity(), epsilon = 1.0e-12);
+    }
+}
+
+#[test]
+fn test_project() {
+    let viewport = Viewport { x: 10.0, y: 20.0, width: 800.0, height: 600.0 };
+    let view = Matrix4::look_at(Point3::new(0.0, 0.0, 5.0), Point3::new(0.0, 0.0, 0.0), Vector3::unit_y());
+    let proj = Perspective { left: -1.0, right: 1.0, bottom: -1.0, top: 1.0, near: 1.0, far: 9.0 };
+
+    for &depth in DEPTHS.iter() {
+        let view_proj = proj.to_matrix4(depth) * view;
+
+        // The near plane is at z = 4, in the middle of the viewport.
+        let window = project(Point3::new(0.0, 0.0, 4.0), view_proj, viewport, depth).unwrap();
+        let near_depth = if depth == DepthRange::OneToZero { 1.0 } else { 0.0 };
+        assert_relative_eq!(window, Point3::new(410.0, 320.0, near_depth), epsilon = 1.0e-12);
+
+        // The top right corner of the near plane.
+        let window = project(Point3::new(1.0, 1.0, 4.0), view_proj, viewport, depth).unwrap();
+        assert_relative_eq!(window, Point3::new(810.0, 620.0, near_depth), epsilon = 1.0e-12);
+
+        let point = Point3::new(0.3, -0.7, -2.0);
+        let window = project(point, view_proj, viewport, depth).unwrap();
+        assert_relative_eq!(unproject(window, view_proj, viewport, depth).unwrap(), point, epsilon = 1.0e-12);
+
+        let inverse = view.invert().unwrap() * proj.to_inverse_matrix4(depth);
+        assert_relative_eq!(unproject_with_inverse(window, inverse, viewport, depth).unwrap(), point, epsilon = 1.0e-12);
+    }
+
+    // Points in the plane of the camera have no projection.
+    let view_proj = Matrix4::from(proj) * view;
+    assert_eq!(project(Point3::new(1.0, 0.0, 5.0), view_proj, viewport, DepthRange::NegativeOneToOne), None);
+    assert_eq!(unproject(Point3::new(0.0, 0.0, 0.0), Matrix4::zero(), viewport, DepthRange::NegativeOneToOne), None);
+}
+
+#[test]
+fn test_unproject_infinite_far_plane() {
+    // A pick ray from the near plane to the point at infinity behind a pixel.
+    let viewport = Viewport { x: 0.0, y: 0.0, width: 100.0, height: 100.0 };
+    let proj = PerspectiveFov { fovy: Deg(90.0).into(), aspect: 1.0, near: 0.01, far: f64::INFINITY };
+    let inverse = proj.to_inverse_matrix4(DepthRange::OneToZero);
+
+    let near = unproject_with_inverse(Point3::new(75.0, 50.0, 1.0), inverse, viewport, DepthRange::OneToZero).unwrap();
+    assert_relative_eq!(near, Point3::new(0.005, 0.0, -0.01), epsilon = 1.0e-12);
+
+    let far = unproject_with_inverse(Point3::new(75.0, 50.0, 1.0e-6), inverse, viewport, DepthRange::OneToZero).unwrap();
+    assert_relative_eq!(far, Point3::new(5000.0, 0.0, -10000.0), max_relative = 1.0e-9);
+}
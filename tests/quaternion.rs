@@ -258,3 +258,107 @@ mod rotate_from_axis_angle {
         assert_ulps_eq!(vec3(-2.0f32.sqrt() / 2.0, 0.0, 2.0f32.sqrt() / 2.0), rot * vec);
     }
 }
+
+mod exp_ln {
+    use cgmath::*;
+
+    #[test]
+    fn test_exp_ln() {
+        let q = Quaternion::new(2.0f64, -1.0, 0.5, 3.0);
+        assert_relative_eq!(q.ln().exp(), q, epsilon = 1.0e-12);
+    }
+
+    #[test]
+    fn test_exp_of_half_rotation_vector() {
+        let axis = vec3(1.0f64, 2.0, -2.0).normalize();
+        let q = Quaternion::from_sv(0.0, axis * 0.6).exp();
+        assert_relative_eq!(q, Quaternion::from_axis_angle(axis, Rad(1.2)), epsilon = 1.0e-12);
+    }
+
+    #[test]
+    fn test_ln_identity() {
+        assert_eq!(Quaternion::<f64>::one().ln(), Quaternion::zero());
+    }
+
+    #[test]
+    fn test_powf() {
+        let axis = vec3(0.0f64, 0.6, 0.8);
+        let q = Quaternion::from_axis_angle(axis, Rad(0.9));
+        assert_relative_eq!(q.powf(2.0), q * q, epsilon = 1.0e-12);
+        assert_relative_eq!(q.powf(0.5), Quaternion::from_axis_angle(axis, Rad(0.45)), epsilon = 1.0e-12);
+        assert_relative_eq!(q.powf(0.0), Quaternion::one(), epsilon = 1.0e-12);
+    }
+}
+
+mod axis_angle {
+    use cgmath::*;
+
+    #[test]
+    fn test_to_axis_angle() {
+        let axis = vec3(1.0f64, -1.0, 2.0).normalize();
+        let (a, angle) = Quaternion::from_axis_angle(axis, Rad(2.0)).to_axis_angle();
+        assert_relative_eq!(a, axis, epsilon = 1.0e-12);
+        assert_relative_eq!(angle, Rad(2.0), epsilon = 1.0e-12);
+    }
+
+    #[test]
+    fn test_to_axis_angle_shortest() {
+        // A rotation by 3π/2 is the same as a rotation by π/2 the other way.
+        let axis = vec3(0.0f64, 0.0, 1.0);
+        let (a, angle) = Quaternion::from_axis_angle(axis, Rad(1.5 * ::std::f64::consts::PI)).to_axis_angle();
+        assert_relative_eq!(a, -axis, epsilon = 1.0e-12);
+        assert_relative_eq!(angle, Rad(0.5 * ::std::f64::consts::PI), epsilon = 1.0e-12);
+    }
+
+    #[test]
+    fn test_to_axis_angle_identity() {
+        assert_eq!(Quaternion::<f64>::one().to_axis_angle(), (Vector3::unit_x(), Rad(0.0)));
+    }
+
+    #[test]
+    fn test_rotation_vector() {
+        let v = vec3(0.3f64, -1.2, 0.4);
+        let q = Quaternion::from_rotation_vector(v);
+        assert_relative_eq!(q, Quaternion::from_axis_angle(v.normalize(), Rad(v.magnitude())), epsilon = 1.0e-12);
+        assert_relative_eq!(q.to_rotation_vector(), v, epsilon = 1.0e-12);
+        assert_relative_eq!((-q).to_rotation_vector(), v, epsilon = 1.0e-12);
+    }
+
+    #[test]
+    fn test_rotation_vector_zero() {
+        assert_eq!(Quaternion::<f64>::from_rotation_vector(Vector3::zero()), Quaternion::one());
+        assert_eq!(Quaternion::<f64>::one().to_rotation_vector(), Vector3::zero());
+    }
+}
+
+mod angular_velocity {
+    use cgmath::*;
+
+    #[test]
+    fn test_integrate() {
+        let q = Quaternion::from_angle_x(Rad(0.4f64));
+        let omega = vec3(0.0, 0.0, 2.0);
+        let expected = Quaternion::from_angle_z(Rad(0.2)) * q;
+        assert_relative_eq!(q.integrate(omega, 0.1), expected, epsilon = 1.0e-12);
+    }
+
+    #[test]
+    fn test_integrate_steps() {
+        let omega = vec3(0.5f64, -1.0, 0.25);
+        let mut q = Quaternion::one();
+        for _ in 0..100 {
+            q = q.integrate(omega, 0.01);
+        }
+        assert_relative_eq!(q.magnitude(), 1.0, epsilon = 1.0e-12);
+        assert_relative_eq!(q, Quaternion::from_rotation_vector(omega), epsilon = 1.0e-12);
+    }
+
+    #[test]
+    fn test_angular_velocity() {
+        let a = Quaternion::from_angle_y(Rad(0.3f64));
+        let omega = vec3(1.0, 2.0, -0.5);
+        let b = a.integrate(omega, 0.5);
+        assert_relative_eq!(a.angular_velocity(b, 0.5), omega, epsilon = 1.0e-12);
+        assert_relative_eq!(a.angular_velocity(-b, 0.5), omega, epsilon = 1.0e-12);
+    }
+}
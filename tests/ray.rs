@@ -0,0 +1,147 @@
+// Copyright 2016 The CGMath Developers. For a full listing of the authors,
+// refer to the Cargo.toml file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#[macro_use]
+extern crate approx;
+extern crate cgmath;
+
+use cgmath::*;
+
+fn hit(distance: f64, normal: Vector3<f64>) -> Option<RayHit<f64>> {
+    Some(RayHit { distance: distance, normal: normal })
+}
+
+#[test]
+fn test_plane_from_points() {
+    let plane = Plane::from_points(Point3::new(0.0, 0.0, 2.0), Point3::new(1.0, 0.0, 2.0), Point3::new(0.0, 1.0, 2.0)).unwrap();
+    assert_ulps_eq!(plane, Plane::new(Vector3::unit_z(), 2.0));
+    assert_ulps_eq!(plane, Plane::from_point_normal(Point3::new(5.0, -3.0, 2.0), Vector3::new(0.0, 0.0, 4.0)));
+
+    assert_eq!(plane.signed_distance(Point3::new(1.0, 1.0, 5.0)), 3.0);
+    assert_eq!(plane.signed_distance(Point3::new(1.0, 1.0, 0.0)), -2.0);
+    assert_eq!(plane.project_point(Point3::new(1.0, 1.0, 5.0)), Point3::new(1.0, 1.0, 2.0));
+
+    assert_eq!(Plane::from_points(Point3::new(0.0, 0.0, 0.0), Point3::new(1.0, 1.0, 1.0), Point3::new(2.0, 2.0, 2.0)), None);
+}
+
+#[test]
+fn test_ray_plane() {
+    let plane = Plane::new(Vector3::unit_y(), 1.0);
+
+    let down = Ray3::new(Point3::new(3.0, 5.0, 0.0), -Vector3::unit_y());
+    assert_eq!(down.intersect_plane(&plane), hit(4.0, Vector3::unit_y()));
+
+    // Rays from below see the plane's normal flipped towards them.
+    let up = Ray3::new(Point3::new(3.0, -1.0, 0.0), Vector3::new(0.0, 2.0, 0.0));
+    assert_eq!(up.intersect_plane(&plane), hit(1.0, -Vector3::unit_y()));
+
+    let away = Ray3::new(Point3::new(0.0, 5.0, 0.0), Vector3::unit_y());
+    assert_eq!(away.intersect_plane(&plane), None);
+    let parallel = Ray3::new(Point3::new(0.0, 5.0, 0.0), Vector3::unit_x());
+    assert_eq!(parallel.intersect_plane(&plane), None);
+}
+
+#[test]
+fn test_ray_sphere() {
+    let sphere = Sphere::new(Point3::new(0.0, 0.0, 10.0), 2.0);
+
+    let ray = Ray3::new(Point3::new(0.0, 0.0, 0.0), Vector3::unit_z());
+    assert_eq!(ray.intersect_sphere(&sphere), hit(8.0, -Vector3::unit_z()));
+
+    let inside = Ray3::new(Point3::new(0.0, 0.0, 10.0), Vector3::unit_x());
+    assert_eq!(inside.intersect_sphere(&sphere), hit(2.0, Vector3::unit_x()));
+
+    let behind = Ray3::new(Point3::new(0.0, 0.0, 20.0), Vector3::unit_z());
+    assert_eq!(behind.intersect_sphere(&sphere), None);
+    let miss = Ray3::new(Point3::new(0.0, 3.0, 0.0), Vector3::unit_z());
+    assert_eq!(miss.intersect_sphere(&sphere), None);
+
+    let grazing = Ray3::new(Point3::new(0.0, 2.0, 0.0), Vector3::unit_z());
+    assert_eq!(grazing.intersect_sphere(&sphere), hit(10.0, Vector3::unit_y()));
+}
+
+#[test]
+fn test_ray_aabb() {
+    let aabb = Aabb3::new(Point3::new(-1.0, -1.0, -1.0), Point3::new(1.0, 1.0, 1.0));
+
+    let ray = Ray3::new(Point3::new(-5.0, 0.5, 0.0), Vector3::unit_x());
+    assert_eq!(ray.intersect_aabb(&aabb), hit(4.0, -Vector3::unit_x()));
+
+    let diagonal = Ray3::new(Point3::new(0.5, 3.0, 0.5), Vector3::new(0.0, -1.0, 0.0));
+    assert_eq!(diagonal.intersect_aabb(&aabb), hit(2.0, Vector3::unit_y()));
+
+    let inside = Ray3::new(Point3::new(0.0, 0.0, 0.0), Vector3::new(0.0, 0.0, 0.5));
+    assert_eq!(inside.intersect_aabb(&aabb), hit(2.0, Vector3::unit_z()));
+
+    let miss = Ray3::new(Point3::new(-5.0, 2.0, 0.0), Vector3::unit_x());
+    assert_eq!(miss.intersect_aabb(&aabb), None);
+    let behind = Ray3::new(Point3::new(5.0, 0.0, 0.0), Vector3::unit_x());
+    assert_eq!(behind.intersect_aabb(&aabb), None);
+    let skew = Ray3::new(Point3::new(-5.0, 0.0, 0.0), Vector3::new(1.0, 1.0, 0.0));
+    assert_eq!(skew.intersect_aabb(&aabb), None);
+}
+
+#[test]
+fn test_ray_triangle() {
+    let (a, b, c) = (Point3::new(0.0, 0.0, 0.0), Point3::new(2.0, 0.0, 0.0), Point3::new(0.0, 2.0, 0.0));
+
+    let front = Ray3::new(Point3::new(0.5, 0.5, 3.0), -Vector3::unit_z());
+    assert_eq!(front.intersect_triangle(a, b, c), hit(3.0, Vector3::unit_z()));
+
+    let back = Ray3::new(Point3::new(0.5, 0.5, -3.0), Vector3::unit_z());
+    assert_eq!(back.intersect_triangle(a, b, c), hit(3.0, -Vector3::unit_z()));
+
+    let outside = Ray3::new(Point3::new(1.5, 1.5, 3.0), -Vector3::unit_z());
+    assert_eq!(outside.intersect_triangle(a, b, c), None);
+    let parallel = Ray3::new(Point3::new(0.5, 0.5, 0.0), Vector3::unit_x());
+    assert_eq!(parallel.intersect_triangle(a, b, c), None);
+    assert_eq!(front.intersect_triangle(a, b, Point3::new(4.0, 0.0, 0.0)), None);
+
+    let oblique = Ray3::new(Point3::new(0.0, 0.0, 1.0), Vector3::new(1.0, 1.0, -2.0));
+    let expected = hit(0.5, Vector3::unit_z()).unwrap();
+    assert_ulps_eq!(oblique.intersect_triangle(a, b, c).unwrap(), expected);
+    assert_ulps_eq!(oblique.at(expected.distance), Point3::new(0.5, 0.5, 0.0));
+}
+
+#[test]
+fn test_segment() {
+    let segment = Segment3::new(Point3::new(0.0, 0.0, 0.0), Point3::new(0.0, 0.0, 4.0));
+    assert_eq!(segment.length(), 4.0);
+    assert_eq!(segment.midpoint(), Point3::new(0.0, 0.0, 2.0));
+    assert_eq!(segment.closest_point(Point3::new(1.0, 0.0, 1.0)), Point3::new(0.0, 0.0, 1.0));
+    assert_eq!(segment.closest_point(Point3::new(1.0, 0.0, -1.0)), segment.start);
+    assert_eq!(segment.closest_point(Point3::new(1.0, 0.0, 9.0)), segment.end);
+
+    let near = Sphere::new(Point3::new(0.0, 0.0, 3.0), 0.5);
+    let far = Sphere::new(Point3::new(0.0, 0.0, 5.0), 0.5);
+    assert_eq!(segment.intersect_sphere(&near), hit(2.5, -Vector3::unit_z()));
+    assert_eq!(segment.intersect_sphere(&far), None);
+
+    assert_eq!(segment.intersect_plane(&Plane::new(Vector3::unit_z(), 1.0)), hit(1.0, -Vector3::unit_z()));
+    assert_eq!(segment.intersect_plane(&Plane::new(Vector3::unit_z(), 6.0)), None);
+}
+
+#[test]
+fn test_sphere() {
+    let sphere = Sphere::new(Point3::new(1.0, 0.0, 0.0), 1.0);
+    assert!(sphere.contains(Point3::new(2.0, 0.0, 0.0)));
+    assert!(!sphere.contains(Point3::new(2.0, 0.1, 0.0)));
+    assert!(sphere.intersects(&Sphere::new(Point3::new(-1.0, 0.0, 0.0), 1.0)));
+    assert!(!sphere.intersects(&Sphere::new(Point3::new(-1.0, 0.0, 0.0), 0.9)));
+
+    assert_eq!(sphere.aabb(), Aabb3::new(Point3::new(0.0, -1.0, -1.0), Point3::new(2.0, 1.0, 1.0)));
+    assert!(sphere.intersects_aabb(&Aabb3::new(Point3::new(1.5, 0.5, -1.0), Point3::new(3.0, 3.0, 1.0))));
+    assert!(!sphere.intersects_aabb(&Aabb3::new(Point3::new(1.8, 0.8, -1.0), Point3::new(3.0, 3.0, 1.0))));
+}
@@ -13,6 +13,8 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+#[macro_use]
+extern crate approx;
 extern crate cgmath;
 
 use cgmath::*;
@@ -45,3 +47,47 @@ fn test_invert_basis3() {
     let a: &Matrix3<_> = a.as_ref();
     assert!(a.is_identity());
 }
+
+#[test]
+fn test_orthonormalize_basis3() {
+    let a: Basis3<f64> = rotation::a3();
+    let mut b = a;
+    let step: Basis3<f64> = Rotation3::from_angle_z(Deg(1.0e-3));
+    for _ in 0..100000 {
+        b = b * step;
+    }
+    let b = b.orthonormalize().unwrap();
+    let m: &Matrix3<_> = b.as_ref();
+    assert_relative_eq!(*m * m.transpose(), Matrix3::identity(), epsilon = 1.0e-14);
+
+    let skewed = Matrix3::from(a) + Matrix3::from_value(1.0e-3);
+    let b = Basis3::from_matrix(skewed).unwrap();
+    assert_relative_eq!(b, a, epsilon = 1.0e-2);
+    assert_relative_eq!(b.gram_schmidt().unwrap(), b, epsilon = 1.0e-12);
+
+    // The closest rotation to a reflection flips the axis with the smallest
+    // singular value.
+    let reflected = Matrix3::from(a) * Matrix3::from_diagonal(Vector3::new(2.0, 1.5, -0.5));
+    assert_relative_eq!(Basis3::from_matrix(reflected).unwrap(), a, epsilon = 1.0e-12);
+    let b = Basis3::from_matrix(-Matrix3::from(a)).unwrap();
+    assert_relative_eq!(Matrix3::from(b).determinant(), 1.0, epsilon = 1.0e-12);
+
+    let flat = Matrix3::from(a) * Matrix3::from_diagonal(Vector3::new(2.0, 1.0, 0.0));
+    assert!(Basis3::from_matrix(flat).is_none());
+    assert!(Basis3::from_matrix(Matrix3::from_value(::std::f64::NAN)).is_none());
+
+    // A quaternion far from unit length gives a basis with parallel axes.
+    let degenerate = Basis3::from(Quaternion::new(0.0f64, 0.5, 0.5, 0.0));
+    assert_eq!(degenerate.orthonormalize(), None);
+    assert_eq!(degenerate.gram_schmidt(), None);
+}
+
+#[test]
+fn test_orthonormalize_basis2() {
+    let a: Basis2<f64> = rotation::a2();
+    let b = Basis2::from_matrix(Matrix2::from(a) * 2.0).unwrap();
+    assert_relative_eq!(b, a, epsilon = 1.0e-12);
+    assert_relative_eq!(b.orthonormalize().unwrap(), a, epsilon = 1.0e-12);
+    assert_relative_eq!(b.gram_schmidt().unwrap(), a, epsilon = 1.0e-12);
+    assert!(Basis2::from_matrix(Matrix2::new(1.0, 0.0, 0.0, -1.0)).is_none());
+}
@@ -0,0 +1,86 @@
+// Copyright 2016 The CGMath Developers. For a full listing of the authors,
+// refer to the Cargo.toml file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#[macro_use]
+extern crate approx;
+extern crate cgmath;
+
+use cgmath::*;
+
+/// The identity, then rotations of 0.8 radians about `y`, 1.2 radians about
+/// `(1, 1, 0)` and 0.5 radians about `z`.
+const KEYS: [Quaternion<f64>; 4] = [
+    Quaternion { s: 1.0f64, v: Vector3 { x: 0.0f64, y: 0.0f64, z: 0.0f64 } },
+    Quaternion { s: 0.9210609940028851f64, v: Vector3 { x: 0.0f64, y: 0.3894183423086505f64, z: 0.0f64 } },
+    // The same rotation as the negated quaternion, to test sign handling.
+    Quaternion { s: -0.8253356149096783f64,
+                 v: Vector3 { x: -0.39926252188357425f64, y: -0.39926252188357425f64, z: -0.0f64 } },
+    Quaternion { s: 0.9689124217106447f64, v: Vector3 { x: 0.0f64, y: 0.0f64, z: 0.24740395925452294f64 } },
+];
+
+const TIMES: [f64; 4] = [0.0, 1.0, 1.5, 4.0];
+
+/// The angular velocity at `time`, estimated with a one-sided difference.
+fn angular_velocity<F: Fn(f64) -> Quaternion<f64>>(f: F, time: f64, h: f64) -> Vector3<f64> {
+    let (a, b) = if h > 0.0 { (f(time), f(time + h)) } else { (f(time + h), f(time)) };
+    let mut delta = b * a.conjugate();
+    if delta.s < 0.0 {
+        delta = -delta;
+    }
+    delta.v * (2.0 / h.abs())
+}
+
+fn check_spline<F: Fn(f64) -> Quaternion<f64>>(f: F) {
+    for (key, &time) in KEYS.iter().zip(TIMES.iter()) {
+        let q = f(time);
+        assert!(relative_eq!(q, *key, epsilon = 1.0e-9) || relative_eq!(q, -*key, epsilon = 1.0e-9));
+    }
+    assert_relative_eq!(f(-1.0), f(0.0));
+    assert_relative_eq!(f(5.0), f(4.0));
+
+    // The angular velocity is continuous across the interior keyframes.
+    for &time in &TIMES[1..3] {
+        let before = angular_velocity(&f, time, -1.0e-6);
+        let after = angular_velocity(&f, time, 1.0e-6);
+        assert_relative_eq!(before, after, epsilon = 1.0e-4);
+    }
+}
+
+#[test]
+fn test_squad_keyframes() {
+    check_spline(|time| squad_keyframes(&KEYS, &TIMES, time));
+}
+
+#[test]
+fn test_bezier_keyframes() {
+    check_spline(|time| bezier_keyframes(&KEYS, &TIMES, time));
+}
+
+#[test]
+fn test_two_keyframes() {
+    let (a, b) = (KEYS[0], KEYS[1]);
+    let expected = a.slerp(b, 0.3);
+    assert_relative_eq!(squad_keyframes(&[a, b], &[0.0, 2.0], 0.6), expected, epsilon = 1.0e-12);
+    assert_relative_eq!(bezier_keyframes(&[a, b], &[0.0, 2.0], 0.6), expected, epsilon = 1.0e-12);
+    assert_eq!(squad_keyframes(&[a], &[1.0], 0.0), a);
+}
+
+#[test]
+fn test_squad_segment() {
+    let (q0, q1) = (KEYS[0], KEYS[1]);
+    assert_relative_eq!(q0.squad(q0, q1, q1, 0.25), q0.slerp(q1, 0.25), epsilon = 1.0e-12);
+    assert_relative_eq!(q0.bezier(q0, q1, q1, 0.0), q0);
+    assert_relative_eq!(q0.bezier(q0, q1, q1, 1.0), q1, epsilon = 1.0e-12);
+}
@@ -0,0 +1,98 @@
+// Copyright 2016 The CGMath Developers. For a full listing of the authors,
+// refer to the Cargo.toml file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! The `f32` operations that have SIMD implementations behind the `simd`
+//! feature, checked against the generic implementations used for `f64`.
+
+#[macro_use]
+extern crate approx;
+extern crate cgmath;
+
+use cgmath::*;
+
+const A: Matrix4<f64> = Matrix4 {
+    x: Vector4 { x: 2.0, y: -1.0, z: 0.5, w: 0.25 },
+    y: Vector4 { x: 0.0, y: 3.0, z: 1.0, w: -2.0 },
+    z: Vector4 { x: 1.5, y: 0.0, z: -1.0, w: 4.0 },
+    w: Vector4 { x: -3.0, y: 2.0, z: 1.0, w: 1.0 },
+};
+
+const B: Matrix4<f64> = Matrix4 {
+    x: Vector4 { x: 1.0, y: 2.0, z: 3.0, w: 4.0 },
+    y: Vector4 { x: -2.0, y: 0.5, z: 0.0, w: 1.0 },
+    z: Vector4 { x: 0.0, y: 1.0, z: -1.5, w: 2.0 },
+    w: Vector4 { x: 5.0, y: -1.0, z: 2.0, w: -0.5 },
+};
+
+fn quaternion_f32(q: Quaternion<f64>) -> Quaternion<f32> {
+    Quaternion::from_sv(q.s as f32, q.v.cast())
+}
+
+#[test]
+fn test_matrix4_mul() {
+    assert_relative_eq!(A.cast::<f32>() * B.cast::<f32>(), (A * B).cast::<f32>(), epsilon = 1.0e-5);
+    assert_relative_eq!(&A.cast::<f32>() * &B.cast::<f32>(), (A * B).cast::<f32>(), epsilon = 1.0e-5);
+}
+
+#[test]
+fn test_matrix4_mul_vector4() {
+    let v = vec4(0.5f64, -2.0, 3.0, 1.0);
+    assert_relative_eq!(A.cast::<f32>() * v.cast::<f32>(), (A * v).cast::<f32>(), epsilon = 1.0e-5);
+}
+
+#[test]
+fn test_matrix4_invert() {
+    let a = A.cast::<f32>();
+    let inverse = a.invert().unwrap();
+    assert_relative_eq!(inverse, A.invert().unwrap().cast::<f32>(), epsilon = 1.0e-5);
+    assert_relative_eq!(a * inverse, Matrix4::identity(), epsilon = 1.0e-5);
+    assert_relative_eq!(Matrix4::<f32>::identity().invert().unwrap(), Matrix4::identity());
+}
+
+#[test]
+fn test_matrix4_determinant() {
+    let a = A.cast::<f32>();
+    assert_relative_eq!(a.determinant(), A.determinant() as f32, max_relative = 1.0e-6);
+    assert_eq!(a.determinant(), SquareMatrix::determinant(&a));
+    assert_relative_eq!(a.determinant() * a.invert().unwrap().determinant(), 1.0, epsilon = 1.0e-5);
+
+    let mut singular = a;
+    singular.w = singular.x * 2.0 - singular.y;
+    assert_ulps_eq!(singular.determinant(), 0.0);
+}
+
+#[test]
+fn test_matrix4_invert_singular() {
+    let mut a = A.cast::<f32>();
+    a.w = a.x * 2.0 - a.y;
+    assert!(a.invert().is_none());
+    assert!(Matrix4::<f32>::zero().invert().is_none());
+}
+
+#[test]
+fn test_quaternion_mul() {
+    let p = Quaternion::new(0.5f64, -1.0, 2.0, 0.25);
+    let q = Quaternion::new(-1.5f64, 0.5, 1.0, 3.0);
+    assert_relative_eq!(quaternion_f32(p) * quaternion_f32(q), quaternion_f32(p * q), epsilon = 1.0e-5);
+}
+
+#[test]
+fn test_dot() {
+    let (a, b) = (vec4(1.0f64, -2.0, 3.5, 0.5), vec4(4.0f64, 0.25, -1.0, 2.0));
+    assert_relative_eq!(a.cast::<f32>().dot(b.cast::<f32>()), a.dot(b) as f32);
+
+    let (p, q) = (Quaternion::from_sv(a.x, a.truncate()), Quaternion::from_sv(b.w, b.truncate()));
+    assert_relative_eq!(quaternion_f32(p).dot(quaternion_f32(q)), p.dot(q) as f32);
+}
@@ -0,0 +1,91 @@
+// Copyright 2016 The CGMath Developers. For a full listing of the authors,
+// refer to the Cargo.toml file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#[macro_use]
+extern crate approx;
+extern crate cgmath;
+
+use cgmath::*;
+use std::f64;
+
+fn matrix3() -> Matrix3<f64> {
+    Matrix3::new(2.0, -1.0, 0.5,
+                 0.0, 3.0, 1.0,
+                 4.0, 1.0, -2.0)
+}
+
+#[test]
+fn test_svd3() {
+    let m = matrix3();
+    let (u, sigma, v_t) = m.svd();
+    assert_relative_eq!(u * sigma * v_t, m, epsilon = 1.0e-12);
+    assert_relative_eq!(u * u.transpose(), Matrix3::identity(), epsilon = 1.0e-12);
+    assert_relative_eq!(v_t * v_t.transpose(), Matrix3::identity(), epsilon = 1.0e-12);
+    assert!(sigma.is_diagonal());
+
+    let values = m.singular_values();
+    assert!(values.x >= values.y && values.y >= values.z);
+    assert_relative_eq!(values.product(), m.determinant().abs(), epsilon = 1.0e-12);
+}
+
+#[test]
+fn test_svd_rank_deficient() {
+    let m = Matrix3::from_cols(Vector3::new(1.0, 2.0, 3.0),
+                               Vector3::new(2.0, 4.0, 6.0),
+                               Vector3::new(0.0, 1.0, 1.0));
+    let (u, sigma, v_t) = m.svd();
+    assert_relative_eq!(u * sigma * v_t, m, epsilon = 1.0e-12);
+    assert_relative_eq!(u * u.transpose(), Matrix3::identity(), epsilon = 1.0e-12);
+    assert_eq!(m.rank(), 2);
+    assert_eq!(m.condition_number(), f64::INFINITY);
+    assert_eq!(Matrix4::<f64>::zero().rank(), 0);
+
+    // The defining properties of the pseudo-inverse.
+    let p = m.pseudo_inverse();
+    assert_relative_eq!(m * p * m, m, epsilon = 1.0e-12);
+    assert_relative_eq!(p * m * p, p, epsilon = 1.0e-12);
+    assert_relative_eq!(m * p, (m * p).transpose(), epsilon = 1.0e-12);
+}
+
+#[test]
+fn test_pseudo_inverse_invertible() {
+    let m = Matrix4::new(1.0, 2.0, 0.0, 0.5,
+                         0.0, 1.0, 3.0, 0.0,
+                         2.0, 0.0, 1.0, 1.0,
+                         0.0, 1.0, 0.0, 2.0);
+    assert_relative_eq!(m.pseudo_inverse(), m.invert().unwrap(), epsilon = 1.0e-12);
+    assert_eq!(m.rank(), 4);
+
+    let m = Matrix2::new(3.0, 0.0, 0.0, -0.5);
+    assert_relative_eq!(m.singular_values(), Vector2::new(3.0, 0.5), epsilon = 1.0e-12);
+    assert_relative_eq!(m.condition_number(), 6.0, epsilon = 1.0e-12);
+}
+
+#[test]
+fn test_kabsch() {
+    // Recover a rotation from the covariance of matching point pairs.
+    let rot = Matrix3::from_axis_angle(Vector3::new(0.3, -1.0, 0.2).normalize(), Rad(0.9));
+    let points = [Vector3::new(1.0, 0.0, 0.0), Vector3::new(0.0, 2.0, 0.0),
+                  Vector3::new(0.0, 0.0, 3.0), Vector3::new(1.0, 1.0, -1.0)];
+    let mut covariance = Matrix3::<f64>::zero();
+    for &p in &points {
+        let q = rot * p;
+        covariance = covariance + Matrix3::from_cols(q * p.x, q * p.y, q * p.z);
+    }
+    let (u, _, v_t) = covariance.svd();
+    let d: f64 = (u * v_t).determinant().signum();
+    let recovered = u * Matrix3::from_diagonal(Vector3::new(1.0, 1.0, d)) * v_t;
+    assert_relative_eq!(recovered, rot, epsilon = 1.0e-12);
+}
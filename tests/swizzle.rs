@@ -0,0 +1,54 @@
+// Copyright 2016 The CGMath Developers. For a full listing of the authors,
+// refer to the Cargo.toml file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#![cfg(feature = "swizzle")]
+
+extern crate cgmath;
+
+use cgmath::{Point2, Point3, Vector1, Vector2, Vector3, Vector4};
+
+#[test]
+fn test_vector_swizzle() {
+    let v = Vector4::new(1, 2, 3, 4);
+    assert_eq!(v.xy(), Vector2::new(1, 2));
+    assert_eq!(v.wz(), Vector2::new(4, 3));
+    assert_eq!(v.zyx(), Vector3::new(3, 2, 1));
+    assert_eq!(v.xxyy(), Vector4::new(1, 1, 2, 2));
+    assert_eq!(v.wzyx(), Vector4::new(4, 3, 2, 1));
+
+    let v = Vector3::new(1.0, 2.0, 3.0);
+    assert_eq!(v.xz(), Vector2::new(1.0, 3.0));
+    assert_eq!(v.zyx(), Vector3::new(3.0, 2.0, 1.0));
+    assert_eq!(v.xyzz(), Vector4::new(1.0, 2.0, 3.0, 3.0));
+
+    let v = Vector2::new(1, 2);
+    assert_eq!(v.yx(), Vector2::new(2, 1));
+    assert_eq!(v.yyx(), Vector3::new(2, 2, 1));
+    assert_eq!(v.xyxy(), Vector4::new(1, 2, 1, 2));
+
+    assert_eq!(Vector1::new(5).xxx(), Vector3::new(5, 5, 5));
+}
+
+#[test]
+fn test_point_swizzle() {
+    let p = Point3::new(1, 2, 3);
+    assert_eq!(p.xy(), Point2::new(1, 2));
+    assert_eq!(p.zyx(), Point3::new(3, 2, 1));
+    assert_eq!(p.xzx(), Point3::new(1, 3, 1));
+
+    let p = Point2::new(1, 2);
+    assert_eq!(p.yx(), Point2::new(2, 1));
+    assert_eq!(p.xyy(), Point3::new(1, 2, 2));
+}
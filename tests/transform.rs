@@ -46,6 +46,199 @@ fn test_look_at() {
     assert_ulps_eq!(&t.transform_point(point), &view_point);
 }
 
+#[test]
+fn test_look_at_lh() {
+    let eye = Point3::new(1.0f64, 2.0, 3.0);
+    let center = Point3::new(1.0f64, 2.0, 8.0);
+    let up = Vector3::unit_y();
+
+    // The camera looks down the positive z axis of the view space.
+    let m = Matrix4::look_at_lh(eye, center, up);
+    assert_ulps_eq!(m.transform_point(center), Point3::new(0.0, 0.0, 5.0));
+    assert_ulps_eq!(m.transform_point(Point3::new(2.0, 2.0, 3.0)), Point3::new(1.0, 0.0, 0.0));
+    assert_ulps_eq!(m.transform_point(Point3::new(1.0, 3.0, 3.0)), Point3::new(0.0, 1.0, 0.0));
+
+    let d: Decomposed<Vector3<f64>, Quaternion<f64>> = Transform3::look_at_lh(eye, center, up);
+    assert_relative_eq!(Matrix4::from(d), m, epsilon = 1.0e-12);
+    let d: Decomposed<Vector3<f64>, Basis3<f64>> = Transform3::look_at_lh(eye, center, up);
+    assert_relative_eq!(Matrix4::from(d), m, epsilon = 1.0e-12);
+    let dq: DualQuaternion<f64> = Transform3::look_at_lh(eye, center, up);
+    assert_relative_eq!(Matrix4::from(dq), m, epsilon = 1.0e-12);
+
+    let rot: Matrix3<f64> = Transform3::look_at_lh(eye, center, up);
+    assert_ulps_eq!(rot, Matrix3::look_at(center - eye, up));
+}
+
+#[test]
+fn test_look_at_rh() {
+    let eye = Point3::new(1.0f64, 2.0, 3.0);
+    let center = Point3::new(1.0f64, 2.0, 8.0);
+    let up = Vector3::unit_y();
+
+    // The camera looks down the negative z axis of the view space.
+    let m = Matrix4::look_at_rh(eye, center, up);
+    assert_eq!(m, Matrix4::look_at(eye, center, up));
+    assert_ulps_eq!(m.transform_point(center), Point3::new(0.0, 0.0, -5.0));
+    assert_ulps_eq!(m.transform_point(Point3::new(0.0, 2.0, 3.0)), Point3::new(1.0, 0.0, 0.0));
+    assert_ulps_eq!(m.transform_point(Point3::new(1.0, 3.0, 3.0)), Point3::new(0.0, 1.0, 0.0));
+
+    let d: Decomposed<Vector3<f64>, Quaternion<f64>> = Transform3::look_at_rh(eye, center, up);
+    assert_relative_eq!(Matrix4::from(d), m, epsilon = 1.0e-12);
+    let d: Decomposed<Vector3<f64>, Basis3<f64>> = Transform3::look_at_rh(eye, center, up);
+    assert_relative_eq!(Matrix4::from(d), m, epsilon = 1.0e-12);
+    let dq: DualQuaternion<f64> = Transform3::look_at_rh(eye, center, up);
+    assert_relative_eq!(Matrix4::from(dq), m, epsilon = 1.0e-12);
+
+    let rot: Matrix3<f64> = Transform3::look_at_rh(eye, center, up);
+    assert_relative_eq!(Matrix4::from(rot), m * Matrix4::from_translation(eye.to_vec()), epsilon = 1.0e-12);
+}
+
+/// A transform that only provides the required methods of `Transform3`, with
+/// a right-handed `look_at`, like `Matrix4`.
+#[derive(Copy, Clone)]
+struct View(Decomposed<Vector3<f64>, Quaternion<f64>>);
+
+impl Transform<Point3<f64>> for View {
+    fn one() -> View { View(Transform::one()) }
+    fn look_at(eye: Point3<f64>, center: Point3<f64>, up: Vector3<f64>) -> View { View(Transform3::look_at_rh(eye, center, up)) }
+    fn transform_vector(&self, vec: Vector3<f64>) -> Vector3<f64> { self.0.transform_vector(vec) }
+    fn transform_point(&self, point: Point3<f64>) -> Point3<f64> { self.0.transform_point(point) }
+    fn concat(&self, other: &View) -> View { View(self.0.concat(&other.0)) }
+    fn inverse_transform(&self) -> Option<View> { self.0.inverse_transform().map(View) }
+}
+
+impl From<View> for Matrix4<f64> {
+    fn from(view: View) -> Matrix4<f64> { view.0.into() }
+}
+
+impl Transform3<f64> for View {
+    fn look_at_lh(eye: Point3<f64>, center: Point3<f64>, up: Vector3<f64>) -> View {
+        View(Transform3::look_at_lh(eye, center, up))
+    }
+}
+
+#[test]
+fn test_look_at_default() {
+    let eye = Point3::new(1.0f64, 2.0, 3.0);
+    let center = Point3::new(-2.0f64, 0.5, 8.0);
+    let up = Vector3::unit_y();
+
+    let lh: View = Transform3::look_at_lh(eye, center, up);
+    assert_relative_eq!(Matrix4::from(lh), Matrix4::look_at_lh(eye, center, up), epsilon = 1.0e-12);
+    let rh: View = Transform3::look_at_rh(eye, center, up);
+    assert_relative_eq!(Matrix4::from(rh), Matrix4::look_at_rh(eye, center, up), epsilon = 1.0e-12);
+}
+
+/// A non-uniform scale, then a rotation of 70 degrees about `(1, 2, 3)`, then a
+/// translation.
+const NON_UNIFORM: NonUniformDecomposed<f64, Quaternion<f64>> = NonUniformDecomposed {
+    scale: Vector3 { x: 2.0f64, y: 0.5f64, z: 3.0f64 },
+    rot: Quaternion { s: 0.8191520442889918f64,
+                      v: Vector3 { x: 0.15329475071088322f64, y: 0.30658950142176644f64, z: 0.45988425213264966f64 } },
+    disp: Vector3 { x: 6.0f64, y: -7.0f64, z: 8.0f64 },
+};
+
+#[test]
+fn test_non_uniform_transform() {
+    let t = NON_UNIFORM;
+    let m = Matrix4::from(t);
+    let p = Point3::new(1.0, -2.0, 0.5);
+
+    assert_relative_eq!(t.transform_point(p), m.transform_point(p), epsilon = 1.0e-12);
+    assert_relative_eq!(t.transform_vector(p.to_vec()), m.transform_vector(p.to_vec()), epsilon = 1.0e-12);
+    assert_relative_eq!(t.transform_point(p),
+                        Point3::from_vec(t.rot.rotate_vector(Vector3::new(2.0, -1.0, 1.5)) + t.disp),
+                        epsilon = 1.0e-12);
+
+    let uniform = Decomposed { scale: 1.5, rot: t.rot, disp: t.disp };
+    assert_relative_eq!(Matrix4::from(NonUniformDecomposed::from(uniform)), Matrix4::from(uniform), epsilon = 1.0e-12);
+}
+
+#[test]
+fn test_non_uniform_concat() {
+    let a = NON_UNIFORM;
+    let m = Matrix4::from(a);
+
+    // Without shear the combination is exact.
+    let b = NonUniformDecomposed {
+        scale: Vector3::new(1.5, -1.0, 4.0),
+        rot: Quaternion::one(),
+        disp: Vector3::new(1.0, 2.0, 3.0),
+    };
+    assert_relative_eq!(Matrix4::from(a.concat(&b)), m * Matrix4::from(b), epsilon = 1.0e-12);
+
+    let c = NonUniformDecomposed {
+        scale: Vector3::from_value(1.5),
+        rot: Quaternion::from_angle_y(Deg(30.0)),
+        disp: Vector3::new(1.0, 2.0, 3.0),
+    };
+    let d = Decomposed { scale: 2.0, rot: Quaternion::from_angle_x(Deg(-20.0)), disp: Vector3::new(0.5, 0.0, -1.0) };
+    assert_relative_eq!(Matrix4::from(NonUniformDecomposed::from(d).concat(&c)),
+                        Matrix4::from(d) * Matrix4::from(c),
+                        epsilon = 1.0e-12);
+
+    // A rotation that permutes the axes moves the scale between them.
+    let s = NonUniformDecomposed {
+        scale: Vector3::new(2.0, 4.0, 5.0),
+        rot: Quaternion::one(),
+        disp: Vector3::new(1.0, 2.0, 3.0),
+    };
+    let r = NonUniformDecomposed {
+        scale: Vector3::new(1.0, 0.5, 2.0),
+        rot: Quaternion::from_angle_z(Deg(90.0)),
+        disp: Vector3::new(-1.0, 0.0, 1.0),
+    };
+    let sr = s.concat(&r);
+    assert_relative_eq!(sr.scale, Vector3::new(4.0, 1.0, 10.0), epsilon = 1.0e-12);
+    assert_relative_eq!(Matrix4::from(sr), Matrix4::from(s) * Matrix4::from(r), epsilon = 1.0e-12);
+    assert_relative_eq!(sr.transform_vector(Vector3::unit_x()), Vector3::new(0.0, 4.0, 0.0), epsilon = 1.0e-12);
+
+    let r = NonUniformDecomposed { rot: Quaternion::from_angle_x(Deg(-90.0)), ..r };
+    assert_relative_eq!(Matrix4::from(a.concat(&r).concat(&s)),
+                        m * Matrix4::from(r) * Matrix4::from(s),
+                        epsilon = 1.0e-12);
+
+    // With shear, only the displacement is exact, and the scale is the
+    // diagonal of the rotated scale.
+    let sheared = a.concat(&c);
+    assert_relative_eq!(sheared.transform_point(Point3::origin()),
+                        m.transform_point(c.transform_point(Point3::origin())),
+                        epsilon = 1.0e-12);
+    assert_relative_eq!(sheared.scale, Vector3::new(3.375, 0.75, 4.125), epsilon = 1.0e-12);
+}
+
+#[test]
+fn test_non_uniform_inverse() {
+    let p = Point3::new(1.0, -2.0, 0.5);
+
+    // A non-uniform scale around the rotation axis commutes with the
+    // rotation.
+    let t = NonUniformDecomposed {
+        scale: Vector3::new(2.0, 2.0, 5.0),
+        rot: Quaternion::from_angle_z(Deg(40.0)),
+        disp: Vector3::new(6.0, -7.0, 8.0),
+    };
+    let inv = t.inverse_transform().expect("Expected successful inversion");
+    assert_relative_eq!(inv.transform_point(t.transform_point(p)), p, epsilon = 1.0e-12);
+    assert_relative_eq!(Matrix4::from(inv), Matrix4::from(t).invert().unwrap(), epsilon = 1.0e-12);
+
+    // A quarter turn swaps the scales of the axes it exchanges.
+    let t = NonUniformDecomposed {
+        scale: Vector3::new(2.0, 4.0, 5.0),
+        rot: Quaternion::from_angle_z(Deg(90.0)),
+        disp: Vector3::new(6.0, -7.0, 8.0),
+    };
+    let inv = t.inverse_transform().expect("Expected successful inversion");
+    assert_relative_eq!(inv.scale, Vector3::new(0.25, 0.5, 0.2), epsilon = 1.0e-12);
+    assert_relative_eq!(inv.transform_point(t.transform_point(p)), p, epsilon = 1.0e-12);
+
+    // A general rotation of a non-uniform scale leaves shear in the inverse.
+    assert_eq!(NON_UNIFORM.inverse_transform(), None);
+
+    let flat = NonUniformDecomposed { scale: Vector3::new(1.0, 0.0, 1.0), ..t };
+    assert_eq!(flat.inverse_transform(), None);
+}
+
 #[cfg(feature = "eders")]
 #[test]
 fn test_serialize() {